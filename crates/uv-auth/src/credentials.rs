@@ -69,14 +69,14 @@ impl From<Option<String>> for Username {
 }
 
 impl Credentials {
-    pub(crate) fn new(username: Option<String>, password: Option<String>) -> Self {
+    pub fn new(username: Option<String>, password: Option<String>) -> Self {
         Self {
             username: Username::new(username),
             password,
         }
     }
 
-    pub(crate) fn username(&self) -> Option<&str> {
+    pub fn username(&self) -> Option<&str> {
         self.username.as_deref()
     }
 
@@ -84,7 +84,7 @@ impl Credentials {
         self.username.clone()
     }
 
-    pub(crate) fn password(&self) -> Option<&str> {
+    pub fn password(&self) -> Option<&str> {
         self.password.as_deref()
     }
 
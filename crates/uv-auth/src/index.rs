@@ -0,0 +1,126 @@
+use std::fmt::{Display, Formatter};
+
+use rustc_hash::FxHashSet;
+use url::Url;
+
+/// When to use authentication for requests to an index.
+#[derive(
+    Copy, Clone, Debug, Default, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum AuthPolicy {
+    /// Authenticate when necessary.
+    ///
+    /// If credentials are provided, they will be used. Otherwise, an unauthenticated request will
+    /// be attempted first. If the request fails, uv will search for credentials. If credentials are
+    /// found, an authenticated request will be attempted.
+    #[default]
+    Auto,
+    /// Always authenticate.
+    ///
+    /// If credentials are not provided, uv will eagerly search for credentials. If credentials
+    /// cannot be found, uv will error instead of attempting an unauthenticated request.
+    Always,
+    /// Never authenticate.
+    ///
+    /// If credentials are provided, uv will error. uv will not search for credentials, and will not
+    /// attach credentials discovered for other URLs.
+    Never,
+}
+
+impl Display for AuthPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Always => write!(f, "always"),
+            Self::Never => write!(f, "never"),
+        }
+    }
+}
+
+/// An index with an associated authentication policy.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Index {
+    /// The URL of the index.
+    pub url: Url,
+    /// The root endpoint of the index, e.g., `https://example.com` for
+    /// `https://example.com/simple`, which is used to match requests for distributions hosted
+    /// alongside the index.
+    pub root_url: Url,
+    /// The authentication policy for the index.
+    pub auth_policy: AuthPolicy,
+}
+
+impl Index {
+    /// Create an [`Index`] for the given URL and authentication policy.
+    pub fn new(url: Url, auth_policy: AuthPolicy) -> Self {
+        let root_url = root_url(&url);
+        Self {
+            url,
+            root_url,
+            auth_policy,
+        }
+    }
+
+    /// Returns `true` if the given URL is served by the index.
+    fn contains(&self, url: &Url) -> bool {
+        is_prefix(&self.root_url, url)
+    }
+}
+
+/// The set of indexes for which an authentication policy has been configured.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Indexes(FxHashSet<Index>);
+
+impl Indexes {
+    /// Create a set of [`Indexes`] from an iterator of [`Index`] entries.
+    pub fn from_indexes(indexes: impl IntoIterator<Item = Index>) -> Self {
+        Self(indexes.into_iter().collect())
+    }
+
+    /// Return the authentication policy for a URL.
+    ///
+    /// If the URL is served by multiple indexes, the most specific index takes precedence.
+    pub fn auth_policy_for(&self, url: &Url) -> AuthPolicy {
+        self.0
+            .iter()
+            .filter(|index| index.contains(url))
+            .max_by_key(|index| index.root_url.path().len())
+            .map(|index| index.auth_policy)
+            .unwrap_or_default()
+    }
+}
+
+/// Returns `true` if `url` has the same scheme and authority as `prefix`, and its path is nested
+/// within that of `prefix`.
+fn is_prefix(prefix: &Url, url: &Url) -> bool {
+    if prefix.scheme() != url.scheme()
+        || prefix.host_str() != url.host_str()
+        || prefix.port_or_known_default() != url.port_or_known_default()
+    {
+        return false;
+    }
+    let prefix = prefix.path().trim_end_matches('/');
+    let path = url.path();
+    path.strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Return the root of an index URL, i.e., the URL without a trailing `simple` path segment.
+fn root_url(url: &Url) -> Url {
+    let mut root = url.clone();
+    root.set_query(None);
+    root.set_fragment(None);
+    let _ = root.set_username("");
+    let _ = root.set_password(None);
+    let path = root.path().trim_end_matches('/');
+    if let Some(parent) = path.strip_suffix("/simple") {
+        let parent = parent.to_string();
+        root.set_path(&parent);
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,47 @@
+use super::*;
+
+#[test]
+fn auth_policy_for() {
+    let indexes = Indexes::from_indexes([
+        Index::new(
+            Url::parse("https://example.com/simple").unwrap(),
+            AuthPolicy::Always,
+        ),
+        Index::new(
+            Url::parse("https://example.com/public/simple/").unwrap(),
+            AuthPolicy::Never,
+        ),
+    ]);
+
+    // Requests to the index itself, and to distributions hosted alongside it.
+    assert_eq!(
+        indexes.auth_policy_for(&Url::parse("https://example.com/simple/anyio/").unwrap()),
+        AuthPolicy::Always
+    );
+    assert_eq!(
+        indexes.auth_policy_for(
+            &Url::parse("https://example.com/packages/anyio-4.0.0.tar.gz").unwrap()
+        ),
+        AuthPolicy::Always
+    );
+
+    // The most specific index takes precedence.
+    assert_eq!(
+        indexes.auth_policy_for(&Url::parse("https://example.com/public/simple/anyio/").unwrap()),
+        AuthPolicy::Never
+    );
+
+    // Other hosts, schemes, and ports are unaffected.
+    assert_eq!(
+        indexes.auth_policy_for(&Url::parse("https://example.org/simple/anyio/").unwrap()),
+        AuthPolicy::Auto
+    );
+    assert_eq!(
+        indexes.auth_policy_for(&Url::parse("http://example.com/simple/anyio/").unwrap()),
+        AuthPolicy::Auto
+    );
+    assert_eq!(
+        indexes.auth_policy_for(&Url::parse("https://example.com:8080/simple/").unwrap()),
+        AuthPolicy::Auto
+    );
+}
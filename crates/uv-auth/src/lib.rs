@@ -5,15 +5,19 @@ use url::Url;
 
 use cache::CredentialsCache;
 pub use credentials::Credentials;
+pub use index::{AuthPolicy, Index, Indexes};
 pub use keyring::KeyringProvider;
 pub use middleware::AuthMiddleware;
 use realm::Realm;
+pub use store::{CredentialStore, CredentialStoreError};
 
 mod cache;
 mod credentials;
+mod index;
 mod keyring;
 mod middleware;
 mod realm;
+mod store;
 
 // TODO(zanieb): Consider passing a cache explicitly throughout
 
@@ -6,7 +6,7 @@ use url::Url;
 use crate::{
     credentials::{Credentials, Username},
     realm::Realm,
-    CredentialsCache, KeyringProvider, CREDENTIALS_CACHE,
+    AuthPolicy, CredentialStore, CredentialsCache, Indexes, KeyringProvider, CREDENTIALS_CACHE,
 };
 use anyhow::{anyhow, format_err};
 use netrc::Netrc;
@@ -48,14 +48,47 @@ impl NetrcMode {
     }
 }
 
+/// Strategy for loading the uv credential store.
+enum StoreMode {
+    Automatic(LazyLock<Option<CredentialStore>>),
+    Enabled(CredentialStore),
+    Disabled,
+}
+
+impl Default for StoreMode {
+    fn default() -> Self {
+        StoreMode::Automatic(LazyLock::new(|| match CredentialStore::from_settings() {
+            Ok(store) => Some(store),
+            Err(err) => {
+                warn!("Error reading credential store: {err}");
+                None
+            }
+        }))
+    }
+}
+
+impl StoreMode {
+    /// Get the credential store if enabled.
+    fn get(&self) -> Option<&CredentialStore> {
+        match self {
+            StoreMode::Automatic(lock) => lock.as_ref(),
+            StoreMode::Enabled(store) => Some(store),
+            StoreMode::Disabled => None,
+        }
+    }
+}
+
 /// A middleware that adds basic authentication to requests.
 ///
 /// Uses a cache to propagate credentials from previously seen requests and
-/// fetches credentials from a netrc file and the keyring.
+/// fetches credentials from the uv credential store, a netrc file, and the keyring.
 pub struct AuthMiddleware {
     netrc: NetrcMode,
+    store: StoreMode,
     keyring: Option<KeyringProvider>,
     cache: Option<CredentialsCache>,
+    /// The indexes for which an authentication policy has been configured.
+    indexes: Indexes,
     /// We know that the endpoint needs authentication, so we don't try to send an unauthenticated
     /// request, avoiding cloning an uncloneable request.
     only_authenticated: bool,
@@ -65,8 +98,10 @@ impl AuthMiddleware {
     pub fn new() -> Self {
         Self {
             netrc: NetrcMode::default(),
+            store: StoreMode::default(),
             keyring: None,
             cache: None,
+            indexes: Indexes::default(),
             only_authenticated: false,
         }
     }
@@ -84,6 +119,19 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the [`CredentialStore`] to use.
+    ///
+    /// `None` disables authentication via the credential store.
+    #[must_use]
+    pub fn with_store(mut self, store: Option<CredentialStore>) -> Self {
+        self.store = if let Some(store) = store {
+            StoreMode::Enabled(store)
+        } else {
+            StoreMode::Disabled
+        };
+        self
+    }
+
     /// Configure the [`KeyringProvider`] to use.
     #[must_use]
     pub fn with_keyring(mut self, keyring: Option<KeyringProvider>) -> Self {
@@ -98,6 +146,13 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the [`Indexes`] to use, along with their authentication policies.
+    #[must_use]
+    pub fn with_indexes(mut self, indexes: Indexes) -> Self {
+        self.indexes = indexes;
+        self
+    }
+
     /// We know that the endpoint needs authentication, so we don't try to send an unauthenticated
     /// request, avoiding cloning an uncloneable request.
     #[must_use]
@@ -138,6 +193,7 @@ impl Middleware for AuthMiddleware {
     /// The discovered credentials must have the requested username to be used.
     ///
     /// - Check the cache (realm key) for a password
+    /// - Check the credential store for a password
     /// - Check the netrc for a password
     /// - Check the keyring for a password
     /// - Perform the request
@@ -155,9 +211,19 @@ impl Middleware for AuthMiddleware {
     /// - Perform the request
     /// - On 401, 403, or 404 check for authentication if there was a cache miss
     ///     - Check the cache (realm key) for the username and password
+    ///     - Check the credential store for a username and password
     ///     - Check the netrc for a username and password
     ///     - Perform the request again if found
     ///     - Add the username and password to the cache if successful
+    ///
+    /// ## If the request is for an index with an authentication policy
+    ///
+    /// If the index is configured with `authenticate = "never"`, the request is sent as-is, without
+    /// consulting the cache, the credential store, the netrc file, or the keyring. If the request
+    /// includes credentials, we error instead.
+    ///
+    /// If the index is configured with `authenticate = "always"`, we never send an unauthenticated
+    /// request, and error if no credentials can be found.
     async fn handle(
         &self,
         mut request: Request,
@@ -172,6 +238,17 @@ impl Middleware for AuthMiddleware {
         let url = tracing_url(&request, credentials.as_ref());
         trace!("Handling request for {url}");
 
+        let auth_policy = self.indexes.auth_policy_for(request.url());
+        if auth_policy == AuthPolicy::Never {
+            if credentials.is_some() {
+                return Err(Error::Middleware(format_err!(
+                    "Credentials were provided for {url}, but the index is configured with `authenticate = \"never\"`"
+                )));
+            }
+            trace!("Request for {url} is configured to never use authentication");
+            return next.run(request, extensions).await;
+        }
+
         if let Some(credentials) = credentials {
             let credentials = Arc::new(credentials);
 
@@ -237,7 +314,8 @@ impl Middleware for AuthMiddleware {
             .as_ref()
             .is_some_and(|credentials| credentials.username().is_some());
 
-        let (mut retry_request, response) = if self.only_authenticated {
+        let only_authenticated = self.only_authenticated || auth_policy == AuthPolicy::Always;
+        let (mut retry_request, response) = if only_authenticated {
             // For endpoints where we require the user to provide credentials, we don't try the
             // unauthenticated request first.
             trace!("Checking for credentials for {url}");
@@ -324,6 +402,10 @@ impl Middleware for AuthMiddleware {
 
         if let Some(response) = response {
             Ok(response)
+        } else if auth_policy == AuthPolicy::Always {
+            Err(Error::Middleware(format_err!(
+                "Missing credentials for {url}, but the index is configured with `authenticate = \"always\"`"
+            )))
         } else {
             Err(Error::Middleware(format_err!(
                 "Missing credentials for {url}"
@@ -365,7 +447,7 @@ impl AuthMiddleware {
 
     /// Fetch credentials for a URL.
     ///
-    /// Supports netrc file and keyring lookups.
+    /// Supports credential store, netrc file, and keyring lookups.
     async fn fetch_credentials(
         &self,
         credentials: Option<&Credentials>,
@@ -398,8 +480,19 @@ impl AuthMiddleware {
             return credentials;
         }
 
+        let credentials = if let Some(credentials) = self.store.get().and_then(|store| {
+            debug!("Checking credential store for credentials for {url}");
+            store.get(
+                url,
+                credentials
+                    .as_ref()
+                    .and_then(|credentials| credentials.username()),
+            )
+        }) {
+            debug!("Found credentials in credential store for {url}");
+            Some(credentials)
         // Netrc support based on: <https://github.com/gribouille/netrc>.
-        let credentials = if let Some(credentials) = self.netrc.get().and_then(|netrc| {
+        } else if let Some(credentials) = self.netrc.get().and_then(|netrc| {
             debug!("Checking netrc for credentials for {url}");
             Credentials::from_netrc(
                 netrc,
@@ -264,6 +264,50 @@ async fn test_netrc_file_default_host() -> Result<(), Error> {
     Ok(())
 }
 
+#[test(tokio::test)]
+async fn test_credential_store() -> Result<(), Error> {
+    let username = "user";
+    let password = "password";
+    let server = start_test_server(username, password).await;
+    let base_url = Url::parse(&server.uri())?;
+
+    let dir = tempfile::tempdir()?;
+    let mut store = CredentialStore::read(dir.path().join("credentials.toml"))?;
+    store.insert(
+        &base_url,
+        &Credentials::new(Some(username.to_string()), Some(password.to_string())),
+    );
+
+    // The credential store should take precedence over the netrc file.
+    let mut netrc_file = NamedTempFile::new()?;
+    writeln!(netrc_file, "default login {username} password invalid")?;
+
+    let client = test_client_builder()
+        .with(
+            AuthMiddleware::new()
+                .with_cache(CredentialsCache::new())
+                .with_store(Some(store))
+                .with_netrc(Netrc::from_file(netrc_file.path()).ok()),
+        )
+        .build();
+
+    assert_eq!(
+        client.get(server.uri()).send().await?.status(),
+        200,
+        "Credentials should be pulled from the credential store"
+    );
+
+    let mut url = base_url.clone();
+    url.set_username("other").unwrap();
+    assert_eq!(
+        client.get(url).send().await?.status(),
+        401,
+        "Credentials should not be used for a different username"
+    );
+
+    Ok(())
+}
+
 #[test(tokio::test)]
 async fn test_netrc_file_matching_host() -> Result<(), Error> {
     let username = "user";
@@ -1077,3 +1121,85 @@ async fn test_credentials_from_keyring_mixed_authentication_in_realm_same_userna
 
     Ok(())
 }
+
+fn indexes_for(url: &Url, auth_policy: AuthPolicy) -> Indexes {
+    Indexes::from_indexes([crate::Index::new(url.clone(), auth_policy)])
+}
+
+#[test(tokio::test)]
+async fn test_auth_policy_never() -> Result<(), Error> {
+    let username = "user";
+    let password = "password";
+
+    let mut netrc_file = NamedTempFile::new()?;
+    writeln!(netrc_file, "default login {username} password {password}")?;
+
+    let server = start_test_server(username, password).await;
+    let base_url = Url::parse(&server.uri())?;
+    let client = test_client_builder()
+        .with(
+            AuthMiddleware::new()
+                .with_cache(CredentialsCache::new())
+                .with_netrc(Netrc::from_file(netrc_file.path()).ok())
+                .with_indexes(indexes_for(&base_url, AuthPolicy::Never)),
+        )
+        .build();
+
+    assert_eq!(
+        client.get(server.uri()).send().await?.status(),
+        401,
+        "Credentials should not be pulled from the netrc file"
+    );
+
+    let mut url = base_url.clone();
+    url.set_username(username).unwrap();
+    url.set_password(Some(password)).unwrap();
+    assert!(
+        client.get(url).send().await.is_err(),
+        "Credentials in the URL should be rejected"
+    );
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn test_auth_policy_always() -> Result<(), Error> {
+    let username = "user";
+    let password = "password";
+
+    let server = start_test_server(username, password).await;
+    let base_url = Url::parse(&server.uri())?;
+    let client = test_client_builder()
+        .with(
+            AuthMiddleware::new()
+                .with_cache(CredentialsCache::new())
+                .with_netrc(None)
+                .with_indexes(indexes_for(&base_url, AuthPolicy::Always)),
+        )
+        .build();
+
+    assert!(
+        client.get(server.uri()).send().await.is_err(),
+        "Requests without credentials should fail before reaching the server"
+    );
+
+    let mut netrc_file = NamedTempFile::new()?;
+    writeln!(netrc_file, "default login {username} password {password}")?;
+
+    let client = test_client_builder()
+        .with(
+            AuthMiddleware::new()
+                .with_cache(CredentialsCache::new())
+                .with_netrc(Netrc::from_file(netrc_file.path()).ok())
+                .with_indexes(indexes_for(&base_url, AuthPolicy::Always)),
+        )
+        .build();
+
+    assert_eq!(
+        client.get(server.uri()).send().await?.status(),
+        200,
+        "Credentials should be pulled from the netrc file"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use uv_fs::Simplified;
+use uv_state::{StateBucket, StateStore};
+use uv_static::EnvVars;
+
+use crate::credentials::Credentials;
+use crate::realm::Realm;
+
+/// The name of the credentials file within the credentials directory.
+const CREDENTIALS_FILE: &str = "credentials.toml";
+
+#[derive(Debug, thiserror::Error)]
+pub enum CredentialStoreError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("Failed to read credentials file at: `{}`", _0.user_display())]
+    Read(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Failed to write credentials file at: `{}`", _0.user_display())]
+    Write(PathBuf, #[source] Box<toml::ser::Error>),
+    #[error("Failed to persist credentials file at: `{}`", _0.user_display())]
+    Persist(PathBuf, #[source] tempfile::PersistError),
+}
+
+/// The credentials stored for a single realm.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct StoredCredentials {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+}
+
+/// The on-disk representation of the credential store.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct CredentialsFile {
+    /// The stored credentials, keyed by realm, e.g., `https://example.com`.
+    #[serde(default)]
+    credentials: BTreeMap<String, StoredCredentials>,
+}
+
+/// A uv-native store for credentials, as managed by `uv auth login` and `uv auth logout`.
+///
+/// Credentials are stored per realm (i.e., scheme, host, and port) in a TOML file that, on Unix,
+/// is only readable and writable by the current user.
+#[derive(Debug, Clone)]
+pub struct CredentialStore {
+    path: PathBuf,
+    file: CredentialsFile,
+}
+
+impl CredentialStore {
+    /// Return the path to the user's credentials file.
+    ///
+    /// Uses `$UV_CREDENTIALS_DIR` if set, or the `credentials` directory in uv's state directory
+    /// otherwise.
+    pub fn default_path() -> Result<PathBuf, io::Error> {
+        let directory = if let Some(directory) = std::env::var_os(EnvVars::UV_CREDENTIALS_DIR) {
+            PathBuf::from(directory)
+        } else {
+            StateStore::from_settings(None)?.bucket(StateBucket::Credentials)
+        };
+        Ok(directory.join(CREDENTIALS_FILE))
+    }
+
+    /// Read the user's credential store.
+    pub fn from_settings() -> Result<Self, CredentialStoreError> {
+        Self::read(Self::default_path()?)
+    }
+
+    /// Read the credential store at the given path.
+    ///
+    /// If the file does not exist, an empty store is returned.
+    pub fn read(path: impl Into<PathBuf>) -> Result<Self, CredentialStoreError> {
+        let path = path.into();
+        let file = match fs_err::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|err| CredentialStoreError::Read(path.clone(), Box::new(err)))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => CredentialsFile::default(),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Self { path, file })
+    }
+
+    /// Return the path to the credentials file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Return the stored [`Credentials`] for a URL, if any.
+    ///
+    /// If a username is provided, it must match the stored username or [`None`] is returned.
+    pub fn get(&self, url: &Url, username: Option<&str>) -> Option<Credentials> {
+        let entry = self.file.credentials.get(&Realm::from(url).to_string())?;
+        if username.is_some_and(|username| entry.username.as_deref() != Some(username)) {
+            return None;
+        }
+        Some(Credentials::new(
+            entry.username.clone(),
+            entry.password.clone(),
+        ))
+    }
+
+    /// Store [`Credentials`] for the realm of a URL, returning the previous credentials, if any.
+    pub fn insert(&mut self, url: &Url, credentials: &Credentials) -> Option<Credentials> {
+        self.file
+            .credentials
+            .insert(
+                Realm::from(url).to_string(),
+                StoredCredentials {
+                    username: credentials.username().map(ToString::to_string),
+                    password: credentials.password().map(ToString::to_string),
+                },
+            )
+            .map(|entry| Credentials::new(entry.username, entry.password))
+    }
+
+    /// Remove the [`Credentials`] for the realm of a URL, returning them if present.
+    pub fn remove(&mut self, url: &Url) -> Option<Credentials> {
+        self.file
+            .credentials
+            .remove(&Realm::from(url).to_string())
+            .map(|entry| Credentials::new(entry.username, entry.password))
+    }
+
+    /// Write the credential store to disk.
+    pub fn write(&self) -> Result<(), CredentialStoreError> {
+        let contents = toml::to_string(&self.file)
+            .map_err(|err| CredentialStoreError::Write(self.path.clone(), Box::new(err)))?;
+
+        let parent = self
+            .path
+            .parent()
+            .expect("Credentials file must have a parent");
+        fs_err::create_dir_all(parent)?;
+
+        // Unlike `uv_fs::tempfile_in`, `NamedTempFile` is created with `0o600` permissions on
+        // Unix, such that the credentials are only accessible to the current user.
+        let mut temp_file = tempfile::NamedTempFile::new_in(parent)?;
+        temp_file.write_all(contents.as_bytes())?;
+        temp_file
+            .persist(&self.path)
+            .map_err(|err| CredentialStoreError::Persist(self.path.clone(), err))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,86 @@
+use url::Url;
+
+use crate::credentials::Credentials;
+
+use super::CredentialStore;
+
+#[test]
+fn test_missing_store() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = CredentialStore::read(dir.path().join("credentials.toml")).unwrap();
+
+    let url = Url::parse("https://example.com/simple").unwrap();
+    assert_eq!(store.get(&url, None), None);
+}
+
+#[test]
+fn test_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("credentials").join("credentials.toml");
+
+    let url = Url::parse("https://example.com/simple").unwrap();
+    let credentials = Credentials::new(Some("user".to_string()), Some("password".to_string()));
+
+    let mut store = CredentialStore::read(&path).unwrap();
+    assert_eq!(store.insert(&url, &credentials), None);
+    store.write().unwrap();
+
+    // Credentials are shared across the realm.
+    let store = CredentialStore::read(&path).unwrap();
+    let other = Url::parse("https://example.com/files/foo.whl").unwrap();
+    assert_eq!(store.get(&other, None), Some(credentials.clone()));
+    assert_eq!(store.get(&other, Some("user")), Some(credentials.clone()));
+    assert_eq!(store.get(&other, Some("other")), None);
+
+    // But not across realms.
+    let other = Url::parse("http://example.com/simple").unwrap();
+    assert_eq!(store.get(&other, None), None);
+    let other = Url::parse("https://example.com:8080/simple").unwrap();
+    assert_eq!(store.get(&other, None), None);
+
+    insta::assert_snapshot!(fs_err::read_to_string(&path).unwrap(), @r###"
+    [credentials."https://example.com"]
+    username = "user"
+    password = "password"
+    "###);
+}
+
+#[test]
+fn test_remove() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("credentials.toml");
+
+    let url = Url::parse("https://example.com/simple").unwrap();
+    let credentials = Credentials::new(Some("__token__".to_string()), Some("token".to_string()));
+
+    let mut store = CredentialStore::read(&path).unwrap();
+    store.insert(&url, &credentials);
+    store.write().unwrap();
+
+    let mut store = CredentialStore::read(&path).unwrap();
+    assert_eq!(store.remove(&url), Some(credentials));
+    assert_eq!(store.remove(&url), None);
+    store.write().unwrap();
+
+    let store = CredentialStore::read(&path).unwrap();
+    assert_eq!(store.get(&url, None), None);
+}
+
+#[test]
+#[cfg(unix)]
+fn test_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("credentials.toml");
+
+    let url = Url::parse("https://example.com/simple").unwrap();
+    let credentials = Credentials::new(Some("user".to_string()), Some("password".to_string()));
+
+    let mut store = CredentialStore::read(&path).unwrap();
+    store.insert(&url, &credentials);
+    store.write().unwrap();
+
+    let mode = fs_err::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+}
@@ -4,7 +4,7 @@ use uv_bench::criterion::black_box;
 use uv_bench::criterion::{criterion_group, criterion_main, measurement::WallTime, Criterion};
 use uv_cache::Cache;
 use uv_client::RegistryClientBuilder;
-use uv_pypi_types::Requirement;
+use uv_pypi_types::{Conflicts, Requirement};
 use uv_python::PythonEnvironment;
 use uv_resolver::Manifest;
 
@@ -208,6 +208,7 @@ mod resolver {
             options,
             &python_requirement,
             markers,
+            Conflicts::empty(),
             Some(&TAGS),
             &flat_index,
             &index,
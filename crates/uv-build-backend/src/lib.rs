@@ -7,7 +7,7 @@ use flate2::write::GzEncoder;
 use flate2::Compression;
 use fs_err::File;
 use glob::{GlobError, PatternError};
-use globset::{Glob, GlobSetBuilder};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
 use itertools::Itertools;
 use sha2::{Digest, Sha256};
 use std::fs::FileType;
@@ -19,6 +19,8 @@ use thiserror::Error;
 use tracing::{debug, trace};
 use uv_distribution_filename::{SourceDistExtension, SourceDistFilename, WheelFilename};
 use uv_fs::Simplified;
+use uv_pep508::Requirement;
+use uv_pypi_types::VerbatimParsedUrl;
 use walkdir::WalkDir;
 use zip::{CompressionMethod, ZipWriter};
 
@@ -58,6 +60,10 @@ pub enum Error {
     Csv(#[from] csv::Error),
     #[error("Expected a Python module with an `__init__.py` at: `{}`", _0.user_display())]
     MissingModule(PathBuf),
+    #[error("Expected a Python namespace module directory at: `{}`", _0.user_display())]
+    MissingNamespaceModule(PathBuf),
+    #[error("The `tool.uv.build-backend.data.{0}` directory does not exist: `{}`", _1.user_display())]
+    MissingDataDirectory(&'static str, PathBuf),
     #[error("Inconsistent metadata between prepare and build step: `{0}`")]
     InconsistentSteps(&'static str),
     #[error("Failed to write to {}", _0.user_display())]
@@ -108,7 +114,6 @@ impl ZipDirectoryWriter {
     /// A wheel writer with no (stored) compression.
     ///
     /// Since editables are temporary, we save time be skipping compression and decompression.
-    #[expect(dead_code)]
     fn new_editable(file: File) -> Self {
         Self {
             writer: ZipWriter::new(file),
@@ -311,36 +316,110 @@ pub fn build_wheel(
     let mut wheel_writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?);
 
     debug!("Adding content files to {}", wheel_path.user_display());
-    let strip_root = source_tree.join("src");
-    let module_root = strip_root.join(pyproject_toml.name().as_dist_info_name().as_ref());
-    if !module_root.join("__init__.py").is_file() {
-        return Err(Error::MissingModule(module_root));
-    }
-    for entry in WalkDir::new(module_root) {
-        let entry = entry.map_err(|err| Error::WalkDir {
-            root: source_tree.to_path_buf(),
-            err,
-        })?;
+    let settings = pyproject_toml.settings();
+    let strip_root = source_tree.join(&settings.module_root);
+    let module_root = find_module_root(source_tree, &pyproject_toml)?;
+    let exclude_matcher =
+        build_exclude_matcher(settings.default_excludes, &settings.wheel_exclude)?;
+    write_tree(
+        &mut wheel_writer,
+        source_tree,
+        &module_root,
+        &strip_root,
+        "",
+        &exclude_matcher,
+        None,
+    )?;
 
-        let relative_path = entry.path().strip_prefix(&strip_root)?;
-        let relative_path_str = relative_path
-            .to_str()
-            .ok_or_else(|| Error::NotUtf8Path(relative_path.to_path_buf()))?;
-        if entry.file_type().is_dir() {
-            wheel_writer.write_directory(relative_path_str)?;
-        } else if entry.file_type().is_file() {
-            wheel_writer.write_file(relative_path_str, entry.path())?;
-        } else {
-            // TODO(konsti): We may want to support symlinks, there is support for installing them.
-            return Err(Error::UnsupportedFileType(
-                entry.path().to_path_buf(),
-                entry.file_type(),
-            ));
+    if !settings.wheel_include.is_empty() {
+        debug!("Adding included files to {}", wheel_path.user_display());
+        let mut include_builder = GlobSetBuilder::new();
+        for include in &settings.wheel_include {
+            include_builder.add(GlobBuilder::new(include).literal_separator(true).build()?);
         }
-
-        entry.path();
+        let include_matcher = include_builder.build()?;
+        write_tree(
+            &mut wheel_writer,
+            source_tree,
+            &strip_root,
+            &strip_root,
+            "",
+            &exclude_matcher,
+            Some((&include_matcher, &module_root)),
+        )?;
     }
 
+    write_data_directories(
+        &mut wheel_writer,
+        &pyproject_toml,
+        source_tree,
+        &exclude_matcher,
+    )?;
+
+    debug!("Adding metadata files to {}", wheel_path.user_display());
+    let dist_info_dir = write_dist_info(
+        &mut wheel_writer,
+        &pyproject_toml,
+        &filename,
+        source_tree,
+        uv_version,
+    )?;
+    wheel_writer.close(&dist_info_dir)?;
+
+    Ok(filename)
+}
+
+/// Build an editable wheel from the source tree and place it in the output directory.
+///
+/// See <https://peps.python.org/pep-0660/>. Instead of the module files, the wheel contains a `.pth`
+/// file that adds the source root to `sys.path`.
+pub fn build_editable(
+    source_tree: &Path,
+    wheel_dir: &Path,
+    metadata_directory: Option<&Path>,
+    uv_version: &str,
+) -> Result<WheelFilename, Error> {
+    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
+    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    pyproject_toml.check_build_system(uv_version);
+
+    check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
+
+    let filename = WheelFilename {
+        name: pyproject_toml.name().clone(),
+        version: pyproject_toml.version().clone(),
+        build_tag: None,
+        python_tag: vec!["py3".to_string()],
+        abi_tag: vec!["none".to_string()],
+        platform_tag: vec!["any".to_string()],
+    };
+
+    let wheel_path = wheel_dir.join(filename.to_string());
+    debug!("Writing editable at {}", wheel_path.user_display());
+    let mut wheel_writer = ZipDirectoryWriter::new_editable(File::create(&wheel_path)?);
+
+    debug!("Adding pth file to {}", wheel_path.user_display());
+    let settings = pyproject_toml.settings();
+    // Check that the module exists, even though we're not adding its files.
+    find_module_root(source_tree, &pyproject_toml)?;
+    let src_root = std::path::absolute(source_tree.join(&settings.module_root))?;
+    let src_root_str = src_root
+        .to_str()
+        .ok_or_else(|| Error::NotUtf8Path(src_root.clone()))?;
+    wheel_writer.write_bytes(
+        &format!("{}.pth", pyproject_toml.name().as_dist_info_name()),
+        src_root_str.as_bytes(),
+    )?;
+
+    let exclude_matcher =
+        build_exclude_matcher(settings.default_excludes, &settings.wheel_exclude)?;
+    write_data_directories(
+        &mut wheel_writer,
+        &pyproject_toml,
+        source_tree,
+        &exclude_matcher,
+    )?;
+
     debug!("Adding metadata files to {}", wheel_path.user_display());
     let dist_info_dir = write_dist_info(
         &mut wheel_writer,
@@ -392,19 +471,27 @@ pub fn build_source_dist(
     )
     .map_err(|err| Error::TarWrite(source_dist_path.clone(), err))?;
 
-    let includes = ["src/**/*", "pyproject.toml"];
+    let settings = pyproject_toml.settings();
+    let mut includes = vec![
+        format!("{}**", glob_prefix(&pyproject_toml.module_path()?)?),
+        "pyproject.toml".to_string(),
+    ];
+    for (_, directory) in settings.data.iter() {
+        includes.push(format!("{}**", glob_prefix(directory)?));
+    }
+    let module_root_prefix = glob_prefix(&settings.module_root)?;
+    for include in &settings.wheel_include {
+        includes.push(format!("{module_root_prefix}{include}"));
+    }
+    includes.extend(settings.source_include.iter().cloned());
     let mut include_builder = GlobSetBuilder::new();
     for include in includes {
-        include_builder.add(Glob::new(include)?);
+        include_builder.add(GlobBuilder::new(&include).literal_separator(true).build()?);
     }
     let include_matcher = include_builder.build()?;
 
-    let excludes = ["__pycache__", "*.pyc", "*.pyo"];
-    let mut exclude_builder = GlobSetBuilder::new();
-    for exclude in excludes {
-        exclude_builder.add(Glob::new(exclude)?);
-    }
-    let exclude_matcher = exclude_builder.build()?;
+    let exclude_matcher =
+        build_exclude_matcher(settings.default_excludes, &settings.source_exclude)?;
 
     // TODO(konsti): Add files linked by pyproject.toml
 
@@ -474,6 +561,199 @@ pub fn build_source_dist(
     Ok(filename)
 }
 
+/// The files that are excluded from source distributions and wheels unless
+/// `tool.uv.build-backend.default-excludes` is disabled.
+///
+/// Besides bytecode, this skips version control and virtual environment directories, which
+/// would otherwise be walked with a flat layout.
+const DEFAULT_EXCLUDES: [&str; 8] = [
+    "__pycache__",
+    "*.pyc",
+    "*.pyo",
+    ".git",
+    ".hg",
+    ".svn",
+    ".venv",
+    ".tox",
+];
+
+/// Resolve the module directory from the `tool.uv.build-backend` settings and check that it
+/// exists.
+fn find_module_root(source_tree: &Path, pyproject_toml: &PyProjectToml) -> Result<PathBuf, Error> {
+    let module_root = source_tree.join(pyproject_toml.module_path()?);
+    if pyproject_toml.settings().namespace {
+        if !module_root.is_dir() {
+            return Err(Error::MissingNamespaceModule(module_root));
+        }
+    } else if !module_root.join("__init__.py").is_file() {
+        return Err(Error::MissingModule(module_root));
+    }
+    Ok(module_root)
+}
+
+/// Build a matcher for the exclude globs.
+///
+/// Excludes are unanchored, i.e., they match at any depth, unless they start with a slash, in
+/// which case they are relative to the project root.
+fn build_exclude_matcher(default_excludes: bool, excludes: &[String]) -> Result<GlobSet, Error> {
+    let defaults: &[&str] = if default_excludes {
+        &DEFAULT_EXCLUDES
+    } else {
+        &[]
+    };
+    let mut exclude_builder = GlobSetBuilder::new();
+    for exclude in defaults
+        .iter()
+        .copied()
+        .chain(excludes.iter().map(String::as_str))
+    {
+        let exclude = if let Some(anchored) = exclude.strip_prefix('/') {
+            anchored.to_string()
+        } else {
+            format!("**/{exclude}")
+        };
+        exclude_builder.add(GlobBuilder::new(&exclude).literal_separator(true).build()?);
+    }
+    Ok(exclude_builder.build()?)
+}
+
+/// Convert a relative path into an escaped glob prefix with a trailing slash, or an empty string
+/// for an empty path.
+fn glob_prefix(path: &Path) -> Result<String, Error> {
+    let path_str = path
+        .to_str()
+        .ok_or_else(|| Error::NotUtf8Path(path.to_path_buf()))?
+        .replace('\\', "/");
+    let path_str = path_str.trim_end_matches('/');
+    if path_str.is_empty() {
+        Ok(String::new())
+    } else {
+        Ok(format!("{}/", globset::escape(path_str)))
+    }
+}
+
+/// Add the files and directories in `root` to the wheel, with their path relative to `strip_root`
+/// and prefixed by `prefix`.
+///
+/// Entries matching the exclude matcher (relative to the source tree) are skipped. If `include` is
+/// set, only files matching the include matcher (relative to `strip_root`) are added, and the
+/// given module directory is skipped since it was already added.
+fn write_tree(
+    writer: &mut dyn DirectoryWriter,
+    source_tree: &Path,
+    root: &Path,
+    strip_root: &Path,
+    prefix: &str,
+    exclude_matcher: &GlobSet,
+    include: Option<(&GlobSet, &Path)>,
+) -> Result<(), Error> {
+    for entry in WalkDir::new(root).into_iter().filter_entry(|entry| {
+        if let Some((_, module_root)) = include {
+            if entry.path() == module_root {
+                return false;
+            }
+        }
+        let relative = entry
+            .path()
+            .strip_prefix(source_tree)
+            .expect("walkdir starts with root");
+        !exclude_matcher.is_match(relative)
+    }) {
+        let entry = entry.map_err(|err| Error::WalkDir {
+            root: source_tree.to_path_buf(),
+            err,
+        })?;
+
+        let relative_path = entry.path().strip_prefix(strip_root)?;
+        let relative_path_str = relative_path
+            .to_str()
+            .ok_or_else(|| Error::NotUtf8Path(relative_path.to_path_buf()))?;
+        if relative_path_str.is_empty() {
+            // The root of the data directory.
+            continue;
+        }
+        let archive_path = if prefix.is_empty() {
+            relative_path_str.to_string()
+        } else {
+            format!("{prefix}/{relative_path_str}")
+        };
+        if let Some((include_matcher, _)) = include {
+            if !entry.file_type().is_file() || !include_matcher.is_match(relative_path) {
+                trace!("Excluding {}", relative_path.user_display());
+                continue;
+            }
+        }
+        if entry.file_type().is_dir() {
+            writer.write_directory(&archive_path)?;
+        } else if entry.file_type().is_file() {
+            writer.write_file(&archive_path, entry.path())?;
+        } else {
+            // TODO(konsti): We may want to support symlinks, there is support for installing them.
+            return Err(Error::UnsupportedFileType(
+                entry.path().to_path_buf(),
+                entry.file_type(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Add the `tool.uv.build-backend.data` directories to the `<name>-<version>.data` directory.
+fn write_data_directories(
+    writer: &mut dyn DirectoryWriter,
+    pyproject_toml: &PyProjectToml,
+    source_tree: &Path,
+    exclude_matcher: &GlobSet,
+) -> Result<(), Error> {
+    let settings = pyproject_toml.settings();
+    if settings.data.iter().next().is_none() {
+        return Ok(());
+    }
+
+    let data_dir = format!(
+        "{}-{}.data",
+        pyproject_toml.name().as_dist_info_name(),
+        pyproject_toml.version()
+    );
+    writer.write_directory(&data_dir)?;
+    for (name, directory) in settings.data.iter() {
+        let data_root = source_tree.join(directory);
+        if !data_root.is_dir() {
+            return Err(Error::MissingDataDirectory(name, data_root));
+        }
+        debug!("Adding {name} data files from {}", data_root.user_display());
+        let prefix = format!("{data_dir}/{name}");
+        writer.write_directory(&prefix)?;
+        write_tree(
+            writer,
+            source_tree,
+            &data_root,
+            &data_root,
+            &prefix,
+            exclude_matcher,
+            None,
+        )?;
+    }
+    Ok(())
+}
+
+/// PEP 517 `get_requires_for_build_*` hooks: Return the requirements for building a source
+/// distribution, a wheel or an editable in addition to `build-system.requires`.
+///
+/// The uv build backend doesn't need any additional packages, so the list is always empty, but we
+/// still validate the `pyproject.toml` to fail early.
+pub fn get_requires_for_build(
+    source_tree: &Path,
+    uv_version: &str,
+) -> Result<Vec<Requirement<VerbatimParsedUrl>>, Error> {
+    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
+    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    pyproject_toml.check_build_system(uv_version);
+    pyproject_toml.module_path()?;
+
+    Ok(Vec::new())
+}
+
 /// Write the dist-info directory to the output directory without building the wheel.
 pub fn metadata(
     source_tree: &Path,
@@ -4,7 +4,7 @@ use itertools::Itertools;
 use serde::Deserialize;
 use std::collections::{BTreeMap, Bound};
 use std::ffi::OsStr;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::str::FromStr;
 use tracing::debug;
 use uv_fs::Simplified;
@@ -45,6 +45,10 @@ pub enum ValidationError {
     ReservedGuiScripts,
     #[error("`project.license` is not a valid SPDX expression: `{0}`")]
     InvalidSpdx(String, #[source] spdx::error::ParseError),
+    #[error("`tool.uv.build-backend.module-name` must consist of Python identifiers separated by dots, invalid name: `{0}`")]
+    InvalidModuleName(String),
+    #[error("`tool.uv.build-backend.{0}` must be a normalized path relative to the project root, without `.` or `..` components: `{}`", _1.user_display())]
+    InvalidSettingsPath(String, PathBuf),
 }
 
 /// A `pyproject.toml` as specified in PEP 517.
@@ -59,6 +63,8 @@ pub(crate) struct PyProjectToml {
     project: Project,
     /// Build-related data
     build_system: BuildSystem,
+    /// Tool-specific configuration
+    tool: Option<Tool>,
 }
 
 impl PyProjectToml {
@@ -71,7 +77,40 @@ impl PyProjectToml {
     }
 
     pub(crate) fn parse(contents: &str) -> Result<Self, Error> {
-        Ok(toml::from_str(contents)?)
+        let pyproject_toml: Self = toml::from_str(contents)?;
+        pyproject_toml.settings().validate()?;
+        Ok(pyproject_toml)
+    }
+
+    /// The `[tool.uv.build-backend]` settings, or the defaults if the table is missing.
+    pub(crate) fn settings(&self) -> BuildBackendSettings {
+        self.tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.build_backend.clone())
+            .unwrap_or_default()
+    }
+
+    /// The path of the module directory relative to the project root, such as `src/foo` or
+    /// `src/foo/bar` for the namespace module `foo.bar`.
+    pub(crate) fn module_path(&self) -> Result<PathBuf, ValidationError> {
+        let settings = self.settings();
+        let module_name = settings
+            .module_name
+            .clone()
+            .unwrap_or_else(|| self.name().as_dist_info_name().to_string());
+
+        let mut module_path = settings.module_root.clone();
+        for part in module_name.split('.') {
+            let mut chars = part.chars();
+            let is_identifier = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+                && chars.all(|c| c.is_alphanumeric() || c == '_');
+            if !is_identifier {
+                return Err(ValidationError::InvalidModuleName(module_name));
+            }
+            module_path.push(part);
+        }
+        Ok(module_path)
     }
 
     /// Warn if the `[build-system]` table looks suspicious.
@@ -632,5 +671,131 @@ struct BuildSystem {
     backend_path: Option<Vec<String>>,
 }
 
+/// The `[tool]` section of a pyproject.toml, of which we only read `[tool.uv]`.
+#[derive(Deserialize, Debug, Clone)]
+struct Tool {
+    /// uv-specific configuration
+    uv: Option<ToolUv>,
+}
+
+/// The `[tool.uv]` section of a pyproject.toml, of which we only read `[tool.uv.build-backend]`.
+///
+/// Unlike the settings in `uv-settings`, this doesn't reject unknown fields since we only read a
+/// subset.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+struct ToolUv {
+    /// Configuration for the uv build backend
+    build_backend: Option<BuildBackendSettings>,
+}
+
+/// The `[tool.uv.build-backend]` section of a pyproject.toml.
+///
+/// All paths and globs are relative to the project root and use forward slashes.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub(crate) struct BuildBackendSettings {
+    /// The directory that contains the module directory, usually `src`, or an empty path when
+    /// using the flat layout.
+    pub(crate) module_root: PathBuf,
+    /// The name of the module directory inside `module-root`.
+    ///
+    /// Defaults to the package name with dashes replaced by underscores. For namespace packages,
+    /// use dots to select a submodule, e.g., `foo.bar` for `src/foo/bar`.
+    pub(crate) module_name: Option<String>,
+    /// Whether the module is part of a namespace package, i.e., whether the `__init__.py` in the
+    /// module directory is optional.
+    pub(crate) namespace: bool,
+    /// Glob expressions for additional files and directories to include in the source
+    /// distribution.
+    ///
+    /// `pyproject.toml`, the module directory and the data directories are always included.
+    pub(crate) source_include: Vec<String>,
+    /// Glob expressions for files and directories to exclude from the source distribution.
+    pub(crate) source_exclude: Vec<String>,
+    /// Glob expressions, relative to `module-root`, for additional files and directories to
+    /// include in the wheel, such as single-file modules or type stubs.
+    pub(crate) wheel_include: Vec<String>,
+    /// Glob expressions for files and directories to exclude from the wheel.
+    pub(crate) wheel_exclude: Vec<String>,
+    /// Whether to exclude `__pycache__`, `*.pyc`, `*.pyo`, version control directories (`.git`,
+    /// `.hg`, `.svn`) and virtual environments (`.venv`, `.tox`) from both the source
+    /// distribution and the wheel.
+    pub(crate) default_excludes: bool,
+    /// Directories to install into the wheel data directories, such as `scripts` or `headers`.
+    pub(crate) data: WheelDataIncludes,
+}
+
+impl Default for BuildBackendSettings {
+    fn default() -> Self {
+        Self {
+            module_root: PathBuf::from("src"),
+            module_name: None,
+            namespace: false,
+            source_include: Vec::new(),
+            source_exclude: Vec::new(),
+            wheel_include: Vec::new(),
+            wheel_exclude: Vec::new(),
+            default_excludes: true,
+            data: WheelDataIncludes::default(),
+        }
+    }
+}
+
+impl BuildBackendSettings {
+    /// Check that `module-root` and the data directories stay inside the project root.
+    ///
+    /// Absolute paths and `..` would escape the project, and we reject `.` to keep the paths
+    /// used for matching the include globs unambiguous.
+    fn validate(&self) -> Result<(), ValidationError> {
+        let paths = std::iter::once(("module-root".to_string(), self.module_root.as_path())).chain(
+            self.data
+                .iter()
+                .map(|(name, directory)| (format!("data.{name}"), directory)),
+        );
+        for (name, path) in paths {
+            if !path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+            {
+                return Err(ValidationError::InvalidSettingsPath(
+                    name,
+                    path.to_path_buf(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Directories, relative to the project root, whose contents are copied into the
+/// `<name>-<version>.data/<key>` directory of the wheel.
+///
+/// <https://packaging.python.org/en/latest/specifications/binary-distribution-format/#the-data-directory>
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub(crate) struct WheelDataIncludes {
+    purelib: Option<PathBuf>,
+    platlib: Option<PathBuf>,
+    headers: Option<PathBuf>,
+    scripts: Option<PathBuf>,
+    data: Option<PathBuf>,
+}
+
+impl WheelDataIncludes {
+    /// Yield all data directories with their wheel data directory name.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&'static str, &Path)> {
+        [
+            ("purelib", self.purelib.as_deref()),
+            ("platlib", self.platlib.as_deref()),
+            ("headers", self.headers.as_deref()),
+            ("scripts", self.scripts.as_deref()),
+            ("data", self.data.as_deref()),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| Some((name, value?)))
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -401,3 +401,86 @@ fn invalid_entry_point_conflict_gui_scripts() {
     });
     assert_snapshot!(script_error(&contents), @"Use `project.gui-scripts` instead of `project.entry-points.gui_scripts`");
 }
+
+#[test]
+fn build_backend_settings_default() {
+    let contents = extend_project("");
+    let pyproject_toml = PyProjectToml::parse(&contents).unwrap();
+    assert_eq!(pyproject_toml.settings(), BuildBackendSettings::default());
+    assert_eq!(
+        pyproject_toml.module_path().unwrap(),
+        Path::new("src").join("hello_world")
+    );
+}
+
+#[test]
+fn build_backend_settings_namespace() {
+    let contents = extend_project(indoc! {r#"
+            [tool.uv.build-backend]
+            module-root = ""
+            module-name = "hello.world"
+            namespace = true
+            data = { scripts = "scripts" }
+        "#
+    });
+    let pyproject_toml = PyProjectToml::parse(&contents).unwrap();
+    assert!(pyproject_toml.settings().namespace);
+    assert_eq!(
+        pyproject_toml.module_path().unwrap(),
+        Path::new("hello").join("world")
+    );
+    assert_eq!(
+        pyproject_toml.settings().data.iter().collect::<Vec<_>>(),
+        vec![("scripts", Path::new("scripts"))]
+    );
+}
+
+#[test]
+fn build_backend_settings_invalid_module_name() {
+    let contents = extend_project(indoc! {r#"
+            [tool.uv.build-backend]
+            module-name = "hello-world"
+        "#
+    });
+    let err = PyProjectToml::parse(&contents)
+        .unwrap()
+        .module_path()
+        .unwrap_err();
+    assert_snapshot!(format_err(err), @"`tool.uv.build-backend.module-name` must consist of Python identifiers separated by dots, invalid name: `hello-world`");
+}
+
+#[test]
+fn build_backend_settings_unknown_field() {
+    let contents = extend_project(indoc! {r#"
+            [tool.uv.build-backend]
+            module-dir = "lib"
+        "#
+    });
+    let err = PyProjectToml::parse(&contents).unwrap_err();
+    assert!(format_err(err).contains("unknown field `module-dir`"));
+}
+
+#[test]
+fn build_backend_settings_escaping_paths() {
+    let contents = extend_project(indoc! {r#"
+            [tool.uv.build-backend]
+            module-root = "../src"
+        "#
+    });
+    let err = PyProjectToml::parse(&contents).unwrap_err();
+    assert_snapshot!(format_err(err), @r"
+    Invalid pyproject.toml
+      Caused by: `tool.uv.build-backend.module-root` must be a normalized path relative to the project root, without `.` or `..` components: `../src`
+    ");
+
+    let contents = extend_project(indoc! {r#"
+            [tool.uv.build-backend]
+            data = { scripts = "/usr/bin" }
+        "#
+    });
+    let err = PyProjectToml::parse(&contents).unwrap_err();
+    assert_snapshot!(format_err(err), @r"
+    Invalid pyproject.toml
+      Caused by: `tool.uv.build-backend.data.scripts` must be a normalized path relative to the project root, without `.` or `..` components: `/usr/bin`
+    ");
+}
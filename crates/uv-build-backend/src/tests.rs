@@ -1,4 +1,5 @@
 use super::*;
+use indoc::indoc;
 use insta::assert_snapshot;
 use std::str::FromStr;
 use tempfile::TempDir;
@@ -126,3 +127,125 @@ fn test_prepare_metadata() {
         Tag: py3-none-any
     "###);
 }
+
+/// Check that the editable contains only the `.pth` file and the metadata.
+#[test]
+fn test_build_editable() {
+    let temp = TempDir::new().unwrap();
+    let uv_backend = Path::new("../../scripts/packages/uv_backend");
+    let filename = build_editable(uv_backend, temp.path(), None, "1.0.0+test").unwrap();
+    assert_eq!(filename.to_string(), "uv_backend-0.1.0-py3-none-any.whl");
+
+    let mut archive =
+        zip::ZipArchive::new(File::open(temp.path().join(filename.to_string())).unwrap()).unwrap();
+    let mut files: Vec<_> = archive.file_names().map(ToString::to_string).collect();
+    files.sort();
+    assert_snapshot!(files.join("\n"), @r"
+        uv_backend-0.1.0.dist-info/
+        uv_backend-0.1.0.dist-info/METADATA
+        uv_backend-0.1.0.dist-info/RECORD
+        uv_backend-0.1.0.dist-info/WHEEL
+        uv_backend.pth
+        ");
+
+    let mut pth = String::new();
+    archive
+        .by_name("uv_backend.pth")
+        .unwrap()
+        .read_to_string(&mut pth)
+        .unwrap();
+    assert_eq!(
+        Path::new(&pth),
+        std::path::absolute(uv_backend.join("src")).unwrap()
+    );
+}
+
+/// Check that the `tool.uv.build-backend` settings select the files in the wheel and the source
+/// distribution.
+#[test]
+fn test_build_backend_settings() {
+    let src = TempDir::new().unwrap();
+    fs_err::write(
+        src.path().join("pyproject.toml"),
+        indoc! {r#"
+            [project]
+            name = "flat-project"
+            version = "1.0.0"
+
+            [build-system]
+            requires = ["uv>=0.4.15,<5"]
+            build-backend = "uv"
+
+            [tool.uv.build-backend]
+            module-root = ""
+            module-name = "flat"
+            wheel-include = ["flat_stubs.pyi"]
+            wheel-exclude = ["*.txt"]
+            source-include = ["tests/**"]
+            data = { scripts = "scripts" }
+        "#},
+    )
+    .unwrap();
+    fs_err::create_dir_all(src.path().join("flat").join("__pycache__")).unwrap();
+    fs_err::write(src.path().join("flat").join("__init__.py"), "").unwrap();
+    fs_err::write(src.path().join("flat").join("notes.txt"), "").unwrap();
+    fs_err::write(
+        src.path()
+            .join("flat")
+            .join("__pycache__")
+            .join("__init__.pyc"),
+        "",
+    )
+    .unwrap();
+    fs_err::write(src.path().join("flat_stubs.pyi"), "").unwrap();
+    fs_err::create_dir_all(src.path().join("scripts")).unwrap();
+    fs_err::write(src.path().join("scripts").join("run.sh"), "").unwrap();
+    fs_err::create_dir_all(src.path().join("tests")).unwrap();
+    fs_err::write(src.path().join("tests").join("test_flat.py"), "").unwrap();
+    fs_err::write(src.path().join("unrelated.py"), "").unwrap();
+
+    let dist = TempDir::new().unwrap();
+    let filename = build_wheel(src.path(), dist.path(), None, "1.0.0+test").unwrap();
+    let archive =
+        zip::ZipArchive::new(File::open(dist.path().join(filename.to_string())).unwrap()).unwrap();
+    let mut files: Vec<_> = archive.file_names().map(ToString::to_string).collect();
+    files.sort();
+    assert_snapshot!(files.join("\n"), @r"
+        flat/
+        flat/__init__.py
+        flat_project-1.0.0.data/
+        flat_project-1.0.0.data/scripts/
+        flat_project-1.0.0.data/scripts/run.sh
+        flat_project-1.0.0.dist-info/
+        flat_project-1.0.0.dist-info/METADATA
+        flat_project-1.0.0.dist-info/RECORD
+        flat_project-1.0.0.dist-info/WHEEL
+        flat_stubs.pyi
+        ");
+
+    let filename = build_source_dist(src.path(), dist.path(), "1.0.0+test").unwrap();
+    let tar_gz = File::open(dist.path().join(filename.to_string())).unwrap();
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(tar_gz));
+    let mut files: Vec<_> = archive
+        .entries()
+        .unwrap()
+        .map(|entry| {
+            entry
+                .unwrap()
+                .path()
+                .unwrap()
+                .portable_display()
+                .to_string()
+        })
+        .collect();
+    files.sort();
+    assert_snapshot!(files.join("\n"), @r"
+        flat-project-1.0.0/PKG-INFO
+        flat-project-1.0.0/flat/__init__.py
+        flat-project-1.0.0/flat/notes.txt
+        flat-project-1.0.0/flat_stubs.pyi
+        flat-project-1.0.0/pyproject.toml
+        flat-project-1.0.0/scripts/run.sh
+        flat-project-1.0.0/tests/test_flat.py
+        ");
+}
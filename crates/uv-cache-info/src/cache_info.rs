@@ -3,9 +3,12 @@ use crate::timestamp::Timestamp;
 
 use serde::Deserialize;
 use std::cmp::max;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use tracing::{debug, warn};
 
+use uv_cache_key::cache_digest;
+
 #[derive(Debug, thiserror::Error)]
 pub enum CacheInfoError {
     #[error("Failed to parse glob patterns for `cache-keys`: {0}")]
@@ -28,6 +31,11 @@ pub struct CacheInfo {
     commit: Option<Commit>,
     /// The Git tags present at the time of the build.
     tags: Option<Tags>,
+    /// A digest of the values of the environment variables present at the time of the build, as
+    /// requested via the `cache-keys` field. The raw values are never persisted, since they may
+    /// contain secrets.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    env: BTreeMap<String, String>,
 }
 
 impl CacheInfo {
@@ -54,6 +62,7 @@ impl CacheInfo {
         let mut commit = None;
         let mut tags = None;
         let mut timestamp = None;
+        let mut env = BTreeMap::new();
 
         // Read the cache keys.
         let cache_keys =
@@ -142,6 +151,10 @@ impl CacheInfo {
                 CacheKey::Git {
                     git: GitPattern::Bool(false),
                 } => {}
+                CacheKey::Environment { env: var } => {
+                    let value = std::env::var(var).ok();
+                    env.insert(var.clone(), cache_digest(&value));
+                }
             }
         }
 
@@ -180,6 +193,7 @@ impl CacheInfo {
             timestamp,
             commit,
             tags,
+            env,
         })
     }
 
@@ -195,7 +209,10 @@ impl CacheInfo {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.timestamp.is_none() && self.commit.is_none() && self.tags.is_none()
+        self.timestamp.is_none()
+            && self.commit.is_none()
+            && self.tags.is_none()
+            && self.env.is_empty()
     }
 }
 
@@ -228,6 +245,8 @@ pub enum CacheKey {
     File { file: String },
     /// Ex) `{ git = true }` or `{ git = { commit = true, tags = false } }`
     Git { git: GitPattern },
+    /// Ex) `{ env = "CMAKE_ARGS" }`
+    Environment { env: String },
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -0,0 +1,90 @@
+use std::collections::BTreeSet;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// The criteria for evicting archives from the cache.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Eviction {
+    /// Evict the least-recently-used archives until the cache is at most this many bytes.
+    pub max_size: Option<u64>,
+    /// Evict any archives that haven't been accessed within this duration.
+    pub older_than: Option<Duration>,
+}
+
+impl Eviction {
+    /// Returns `true` if no eviction criteria are set.
+    pub fn is_empty(&self) -> bool {
+        self.max_size.is_none() && self.older_than.is_none()
+    }
+}
+
+/// A record of the accesses to an archive in the cache.
+///
+/// The modification time of the record reflects the last time the archive was installed, while its
+/// contents list the environments into which the archive was installed via symlinks.
+#[derive(Debug)]
+pub(crate) struct AccessRecord(PathBuf);
+
+impl AccessRecord {
+    /// Create an [`AccessRecord`] at the given path.
+    pub(crate) fn new(path: impl Into<PathBuf>) -> Self {
+        Self(path.into())
+    }
+
+    /// Return the path to the record.
+    pub(crate) fn path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Return the time of the last recorded access to the archive, if any.
+    pub(crate) fn last_access(&self) -> io::Result<Option<SystemTime>> {
+        match fs_err::metadata(&self.0) {
+            Ok(metadata) => Ok(Some(metadata.modified()?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Return the environments into which the archive was installed via symlinks.
+    pub(crate) fn environments(&self) -> io::Result<BTreeSet<PathBuf>> {
+        match fs_err::read_to_string(&self.0) {
+            Ok(contents) => Ok(contents
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(PathBuf::from)
+                .collect()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(BTreeSet::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Record an access to the archive, along with the environment that references it via
+    /// symlinks, if any.
+    pub(crate) fn touch(&self, environment: Option<&Path>) -> io::Result<()> {
+        if let Some(parent) = self.0.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+
+        // If the environment is new, rewrite the record (which also updates its modification time).
+        if let Some(environment) = environment {
+            let mut environments = self.environments()?;
+            if environments.insert(environment.to_path_buf()) {
+                let contents = environments
+                    .iter()
+                    .map(|environment| environment.to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                uv_fs::write_atomic_sync(&self.0, contents)?;
+                return Ok(());
+            }
+        }
+
+        let file = fs_err::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.0)?;
+        file.file().set_modified(SystemTime::now())?;
+        Ok(())
+    }
+}
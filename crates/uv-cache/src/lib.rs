@@ -5,10 +5,12 @@ use std::io::Write;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::SystemTime;
 
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::debug;
 
+pub use access::Eviction;
 pub use archive::ArchiveId;
 use uv_cache_info::Timestamp;
 use uv_distribution_types::InstalledDist;
@@ -16,19 +18,25 @@ use uv_fs::{cachedir, directories};
 use uv_normalize::PackageName;
 use uv_pypi_types::ResolutionMetadata;
 
+use crate::access::AccessRecord;
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::remote::RemoteCache;
 use crate::removal::Remover;
 pub use crate::removal::{rm_rf, Removal};
+pub use crate::stats::{ArchiveEntry, ArchiveStats, BucketStats, CacheStats, PackageStats};
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 
+mod access;
 mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod remote;
 mod removal;
+mod stats;
 mod wheel;
 
 /// A [`CacheEntry`] which may or may not exist yet.
@@ -125,6 +133,8 @@ pub struct Cache {
     /// Included to ensure that the temporary directory exists for the length of the operation, but
     /// is dropped at the end as appropriate.
     temp_dir: Option<Arc<tempfile::TempDir>>,
+    /// A remote cache for wheels built from source distributions, if any.
+    remote: Option<RemoteCache>,
 }
 
 impl Cache {
@@ -134,6 +144,7 @@ impl Cache {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: None,
+            remote: None,
         }
     }
 
@@ -144,6 +155,7 @@ impl Cache {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: Some(Arc::new(temp_dir)),
+            remote: None,
         })
     }
 
@@ -153,6 +165,17 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the [`RemoteCache`] to use for wheels built from source distributions.
+    #[must_use]
+    pub fn with_remote(self, remote: Option<RemoteCache>) -> Self {
+        Self { remote, ..self }
+    }
+
+    /// Return the [`RemoteCache`] for wheels built from source distributions, if any.
+    pub fn remote(&self) -> Option<&RemoteCache> {
+        self.remote.as_ref()
+    }
+
     /// Return the root of the cache.
     pub fn root(&self) -> &Path {
         &self.root
@@ -188,6 +211,32 @@ impl Cache {
         self.bucket(CacheBucket::Archive).join(id)
     }
 
+    /// Record an access to an archive in the cache, e.g., when installing it into an environment.
+    ///
+    /// If the archive was installed via symlinks, the environment should be provided, such that
+    /// the archive is retained by [`Cache::evict`] for as long as the environment exists.
+    pub fn record_access(
+        &self,
+        archive: &Path,
+        environment: Option<&Path>,
+    ) -> Result<(), io::Error> {
+        if self.is_temporary() {
+            return Ok(());
+        }
+
+        // Ignore any paths that don't point into the archive bucket.
+        let archive = fs_err::canonicalize(archive)?;
+        let bucket = fs_err::canonicalize(self.bucket(CacheBucket::Archive))?;
+        if archive.parent() != Some(bucket.as_path()) {
+            return Ok(());
+        }
+        let Some(id) = archive.file_name() else {
+            return Ok(());
+        };
+
+        AccessRecord::new(self.bucket(CacheBucket::Access).join(id)).touch(environment)
+    }
+
     /// Create a temporary directory to be used as a Python virtual environment.
     pub fn venv_dir(&self) -> io::Result<tempfile::TempDir> {
         fs_err::create_dir_all(self.bucket(CacheBucket::Builds))?;
@@ -520,8 +569,182 @@ impl Cache {
             Err(err) => return Err(err),
         }
 
+        // Fifth, remove any access records for archives that no longer exist.
+        match fs_err::read_dir(self.bucket(CacheBucket::Access)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    if !self
+                        .bucket(CacheBucket::Archive)
+                        .join(entry.file_name())
+                        .exists()
+                    {
+                        let path = entry.path();
+                        debug!("Removing dangling access record: {}", path.display());
+                        summary += rm_rf(path)?;
+                    }
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(err),
+        }
+
         Ok(summary)
     }
+
+    /// Collect statistics on the contents of the cache.
+    ///
+    /// The `pointers` map any wheel pointers in the cache to the paths of the archives that they
+    /// reference, which are considered referenced in addition to those targeted by symlinks.
+    pub fn stats(&self, pointers: &FxHashMap<PathBuf, PathBuf>) -> Result<CacheStats, io::Error> {
+        CacheStats::from_cache(self, pointers)
+    }
+
+    /// Evict archives from the cache based on when they were last accessed.
+    ///
+    /// Archives that haven't been accessed within [`Eviction::older_than`] are removed, along with
+    /// the least-recently-used archives until the cache is within [`Eviction::max_size`]. Archives
+    /// that are still referenced by an environment, via hardlinks or symlinks, are always retained.
+    pub fn evict(&self, eviction: Eviction) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+
+        if eviction.is_empty() {
+            return Ok(summary);
+        }
+
+        // Collect the archives that are eligible for eviction, along with their last access.
+        let mut archives = Vec::new();
+        match fs_err::read_dir(self.bucket(CacheBucket::Archive)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let record =
+                        AccessRecord::new(self.bucket(CacheBucket::Access).join(entry.file_name()));
+
+                    if is_referenced(&path, &record)? {
+                        debug!("Retaining referenced cache archive: {}", path.display());
+                        continue;
+                    }
+
+                    // If the archive was never installed, fall back to the time it was created.
+                    let last_access = match record.last_access()? {
+                        Some(last_access) => last_access,
+                        None => entry.metadata()?.modified()?,
+                    };
+
+                    archives.push((last_access, path, record));
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(summary),
+            Err(err) => return Err(err),
+        }
+
+        // Evict the least-recently-used archives first.
+        archives.sort_by_key(|(last_access, ..)| *last_access);
+
+        let now = SystemTime::now();
+        let mut size = if eviction.max_size.is_some() {
+            directory_size(&self.root)?
+        } else {
+            0
+        };
+
+        for (last_access, path, record) in archives {
+            let expired = eviction.older_than.is_some_and(|older_than| {
+                now.duration_since(last_access)
+                    .is_ok_and(|age| age > older_than)
+            });
+            let oversized = eviction.max_size.is_some_and(|max_size| size > max_size);
+
+            // Since the archives are sorted by last access, none of the remaining archives can be
+            // expired either.
+            if !expired && !oversized {
+                break;
+            }
+
+            debug!("Evicting cache archive: {}", path.display());
+            let removal = rm_rf(&path)?;
+            size = size.saturating_sub(removal.total_bytes);
+            summary += removal;
+            summary += rm_rf(record.path())?;
+        }
+
+        // Remove any symlinks that pointed into the evicted archives (e.g., built wheels).
+        if summary.num_dirs > 0 {
+            for bucket in CacheBucket::iter() {
+                if bucket == CacheBucket::Archive {
+                    continue;
+                }
+                let bucket = self.bucket(bucket);
+                if bucket.is_dir() {
+                    for entry in walkdir::WalkDir::new(bucket) {
+                        let entry = entry?;
+                        if entry.file_type().is_symlink() && !entry.path().exists() {
+                            debug!(
+                                "Removing dangling cache symlink: {}",
+                                entry.path().display()
+                            );
+                            summary += rm_rf(entry.path())?;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+/// Returns `true` if the archive is still referenced by an environment.
+///
+/// An archive is referenced if any environment into which it was symlinked still exists, or, on
+/// Unix, if any of its files are hardlinked elsewhere (in which case evicting it wouldn't free any
+/// space).
+fn is_referenced(archive: &Path, record: &AccessRecord) -> Result<bool, io::Error> {
+    if record
+        .environments()?
+        .iter()
+        .any(|environment| environment.exists())
+    {
+        return Ok(true);
+    }
+    is_hardlinked(archive)
+}
+
+/// Returns `true` if any of the files in the archive are hardlinked elsewhere.
+#[cfg(unix)]
+fn is_hardlinked(archive: &Path) -> Result<bool, io::Error> {
+    use std::os::unix::fs::MetadataExt;
+
+    for entry in walkdir::WalkDir::new(archive) {
+        let entry = entry?;
+        if entry.file_type().is_file() && entry.metadata()?.nlink() > 1 {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Returns `true` if any of the files in the archive are hardlinked elsewhere.
+///
+/// Link counts are not available on this platform, so hardlinks are never detected.
+#[cfg(not(unix))]
+#[allow(clippy::unnecessary_wraps)]
+fn is_hardlinked(_archive: &Path) -> Result<bool, io::Error> {
+    Ok(false)
+}
+
+/// Return the total size of the files in a directory, in bytes.
+pub(crate) fn directory_size(path: &Path) -> Result<u64, io::Error> {
+    let mut size = 0;
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            size += entry.metadata()?.len();
+        }
+    }
+    Ok(size)
 }
 
 pub trait CleanReporter: Send + Sync {
@@ -774,6 +997,19 @@ pub enum CacheBucket {
     Builds,
     /// Reusable virtual environments used to invoke Python tools.
     Environments,
+    /// Security advisories in the [OSV](https://ossf.github.io/osv-schema/) format, as used by
+    /// `uv audit`.
+    ///
+    /// Cache structure: `advisories-v0/pypi/<advisory-id>.json`
+    Advisories,
+    /// Records of the accesses to each entry in [`CacheBucket::Archive`], used to evict the
+    /// least-recently-used archives.
+    ///
+    /// The modification time of each record reflects the last time the archive was installed, and
+    /// its contents list any environments into which the archive was installed via symlinks.
+    ///
+    /// Cache structure: `access-v0/<archive-id>`
+    Access,
 }
 
 impl CacheBucket {
@@ -794,6 +1030,8 @@ impl CacheBucket {
             Self::Archive => "archive-v0",
             Self::Builds => "builds-v0",
             Self::Environments => "environments-v1",
+            Self::Advisories => "advisories-v0",
+            Self::Access => "access-v0",
         }
     }
 
@@ -910,6 +1148,12 @@ impl CacheBucket {
             Self::Environments => {
                 // Nothing to do.
             }
+            Self::Advisories => {
+                // Nothing to do.
+            }
+            Self::Access => {
+                // Nothing to do.
+            }
         }
         Ok(summary)
     }
@@ -926,6 +1170,8 @@ impl CacheBucket {
             Self::Archive,
             Self::Builds,
             Self::Environments,
+            Self::Advisories,
+            Self::Access,
         ]
         .iter()
         .copied()
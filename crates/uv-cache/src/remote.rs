@@ -0,0 +1,34 @@
+use url::Url;
+
+/// A remote cache for wheels built from source distributions.
+///
+/// The remote cache is an HTTP server that stores opaque entries by key, supporting `GET` requests
+/// to read an entry and `PUT` requests to write one, e.g., `GET https://cache.example.com/<key>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteCache {
+    url: Url,
+}
+
+impl RemoteCache {
+    /// Create a [`RemoteCache`] rooted at the given URL.
+    pub fn new(mut url: Url) -> Self {
+        // Ensure that the URL is treated as a directory, such that keys are nested within it.
+        if !url.path().ends_with('/') {
+            let path = format!("{}/", url.path());
+            url.set_path(&path);
+        }
+        Self { url }
+    }
+
+    /// Return the root URL of the remote cache.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Return the URL for the entry with the given key.
+    pub fn entry(&self, key: &str) -> Url {
+        self.url
+            .join(key)
+            .expect("cache keys should be valid URL path segments")
+    }
+}
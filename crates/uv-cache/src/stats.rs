@@ -0,0 +1,218 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use uv_fs::directories;
+use uv_normalize::PackageName;
+
+use crate::wheel::WheelCacheKind;
+use crate::{directory_size, Cache, CacheBucket};
+
+/// Statistics on the contents of the cache.
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct CacheStats {
+    /// The total size of the cache, in bytes.
+    pub total_bytes: u64,
+    /// The statistics for each bucket in the cache.
+    pub buckets: Vec<BucketStats>,
+    /// The statistics for the unzipped wheels in the cache.
+    pub archives: ArchiveStats,
+    /// The size of each package in the cache, sorted from largest to smallest.
+    pub packages: Vec<PackageStats>,
+}
+
+/// Statistics on a single cache bucket.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BucketStats {
+    /// The name of the bucket, e.g., `wheels-v3`.
+    pub name: String,
+    /// The number of files in the bucket.
+    pub files: u64,
+    /// The total size of the bucket, in bytes.
+    pub bytes: u64,
+}
+
+/// Statistics on the unzipped wheels in [`CacheBucket::Archive`].
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct ArchiveStats {
+    /// The number of archives.
+    pub count: u64,
+    /// The total size of the archives, in bytes.
+    pub bytes: u64,
+    /// The archives that aren't referenced by any wheel in the cache.
+    pub unreferenced: Vec<ArchiveEntry>,
+}
+
+/// A single archive in [`CacheBucket::Archive`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ArchiveEntry {
+    /// The unique ID of the archive.
+    pub id: String,
+    /// The size of the archive, in bytes.
+    pub bytes: u64,
+}
+
+/// The size of the cache entries for a single package.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PackageStats {
+    /// The name of the package.
+    pub name: PackageName,
+    /// The total size of the package's wheels, source distributions, and unzipped wheels, in
+    /// bytes.
+    pub bytes: u64,
+}
+
+impl CacheStats {
+    /// Collect statistics on the contents of the [`Cache`].
+    ///
+    /// Archives are considered referenced if they're the target of a symlink in the cache, or if
+    /// they're referenced by any of the given `pointers` (i.e., a mapping from wheel pointer files,
+    /// which are opaque to this crate, to the paths of the archives that they reference).
+    pub(crate) fn from_cache(
+        cache: &Cache,
+        pointers: &FxHashMap<PathBuf, PathBuf>,
+    ) -> io::Result<Self> {
+        let mut stats = Self {
+            total_bytes: directory_size(cache.root())?,
+            ..Self::default()
+        };
+
+        // Collect the size of each bucket, along with the targets of any symlinks.
+        let mut references = FxHashSet::default();
+        for bucket in CacheBucket::iter() {
+            let path = cache.bucket(bucket);
+            if !path.is_dir() {
+                continue;
+            }
+
+            let mut files = 0;
+            let mut bytes = 0;
+            for entry in walkdir::WalkDir::new(&path) {
+                let entry = entry?;
+                if entry.file_type().is_symlink() {
+                    files += 1;
+                    if let Ok(target) = fs_err::canonicalize(entry.path()) {
+                        references.insert(target);
+                    }
+                } else if entry.file_type().is_file() {
+                    files += 1;
+                    bytes += entry.metadata()?.len();
+                }
+            }
+
+            stats.buckets.push(BucketStats {
+                name: bucket.to_string(),
+                files,
+                bytes,
+            });
+        }
+
+        // Collect the size of each archive, and whether it's referenced.
+        let pointed: FxHashSet<&PathBuf> = pointers.values().collect();
+        let mut archives = FxHashMap::default();
+        match fs_err::read_dir(cache.bucket(CacheBucket::Archive)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let bytes = directory_size(&path)?;
+
+                    stats.archives.count += 1;
+                    stats.archives.bytes += bytes;
+
+                    let canonical = fs_err::canonicalize(&path)?;
+                    if !references.contains(&canonical) && !pointed.contains(&path) {
+                        stats.archives.unreferenced.push(ArchiveEntry {
+                            id: entry.file_name().to_string_lossy().into_owned(),
+                            bytes,
+                        });
+                    }
+
+                    archives.insert(canonical, bytes);
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(err),
+        }
+        stats
+            .archives
+            .unreferenced
+            .sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.id.cmp(&b.id)));
+
+        // Attribute the wheels, source distributions, and unzipped wheels to each package, for
+        // those entries that are keyed by package name.
+        let mut packages = FxHashMap::default();
+        for directory in package_directories(cache) {
+            let Some(name) = directory
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| PackageName::from_str(name).ok())
+            else {
+                continue;
+            };
+            *packages.entry(name).or_insert(0) += package_size(&directory, pointers, &archives)?;
+        }
+        stats.packages = packages
+            .into_iter()
+            .map(|(name, bytes)| PackageStats { name, bytes })
+            .collect();
+        stats
+            .packages
+            .sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.name.cmp(&b.name)));
+
+        Ok(stats)
+    }
+}
+
+/// Return the per-package directories in the cache, i.e., those that are keyed by package name.
+fn package_directories(cache: &Cache) -> impl Iterator<Item = PathBuf> {
+    let wheels = cache.bucket(CacheBucket::Wheels);
+    let sdists = cache.bucket(CacheBucket::SourceDistributions);
+    directories(wheels.join(WheelCacheKind::Pypi))
+        .chain(directories(sdists.join(WheelCacheKind::Pypi)))
+        .chain(
+            [
+                wheels.join(WheelCacheKind::Index),
+                wheels.join(WheelCacheKind::Url),
+                wheels.join(WheelCacheKind::Path),
+                sdists.join(WheelCacheKind::Index),
+            ]
+            .into_iter()
+            .flat_map(directories)
+            .flat_map(directories),
+        )
+}
+
+/// Return the size of a package directory, including the size of any archives that it links to,
+/// either via a symlink or via a wheel pointer.
+fn package_size(
+    path: &Path,
+    pointers: &FxHashMap<PathBuf, PathBuf>,
+    archives: &FxHashMap<PathBuf, u64>,
+) -> io::Result<u64> {
+    let mut bytes = 0;
+    let mut linked = FxHashSet::default();
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = entry?;
+        let target = if entry.file_type().is_symlink() {
+            fs_err::canonicalize(entry.path()).ok()
+        } else if entry.file_type().is_file() {
+            bytes += entry.metadata()?.len();
+            pointers
+                .get(entry.path())
+                .and_then(|archive| fs_err::canonicalize(archive).ok())
+        } else {
+            None
+        };
+        if let Some(target) = target {
+            if let Some(size) = archives.get(&target) {
+                if linked.insert(target) {
+                    bytes += size;
+                }
+            }
+        }
+    }
+    Ok(bytes)
+}
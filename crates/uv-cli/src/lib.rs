@@ -2,6 +2,7 @@ use std::ffi::OsString;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::{anyhow, Result};
 use clap::builder::styling::{AnsiColor, Effects, Style};
@@ -47,6 +48,42 @@ pub enum ListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum TreeFormat {
+    /// Display the dependency tree in a human-readable format.
+    #[default]
+    Text,
+    /// Display the dependency graph in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum CacheStatsFormat {
+    /// Display the cache statistics in a human-readable format.
+    #[default]
+    Text,
+    /// Display the cache statistics in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum ToolListFormat {
+    /// Display the list of tools in a human-readable format.
+    #[default]
+    Text,
+    /// Display the list of tools in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum LockDiffFormat {
+    /// Display the changes to the lockfile in a human-readable format.
+    #[default]
+    Text,
+    /// Display the changes to the lockfile in a machine-readable JSON format.
+    Json,
+}
+
 fn extra_name_with_clap_error(arg: &str) -> Result<ExtraName> {
     ExtraName::from_str(arg).map_err(|_err| {
         anyhow!(
@@ -412,6 +449,12 @@ pub enum Commands {
     Build(BuildArgs),
     /// Upload distributions to an index.
     Publish(PublishArgs),
+    /// Manage authentication.
+    #[command(
+        after_help = "Use `uv help auth` for more details.",
+        after_long_help = ""
+    )]
+    Auth(AuthNamespace),
     /// The implementation of the build backend.
     ///
     /// These commands are not directly exposed to the user, instead users invoke their build
@@ -491,6 +534,72 @@ pub struct SelfUpdateArgs {
     pub token: Option<String>,
 }
 
+#[derive(Args)]
+pub struct AuthNamespace {
+    #[command(subcommand)]
+    pub command: AuthCommand,
+}
+
+#[derive(Subcommand)]
+pub enum AuthCommand {
+    /// Store credentials for a service.
+    ///
+    /// Credentials are stored per realm (i.e., scheme, host, and port) in uv's credential store,
+    /// which is used to authenticate requests to the service in subsequent invocations.
+    ///
+    /// By default, the credential store is located at `$XDG_DATA_HOME/uv/credentials` or
+    /// `$HOME/.local/share/uv/credentials` on Unix and `%APPDATA%\uv\data\credentials` on
+    /// Windows. An alternative directory may be specified via the `$UV_CREDENTIALS_DIR`
+    /// environment variable.
+    Login(AuthLoginArgs),
+    /// Remove stored credentials for a service.
+    Logout(AuthLogoutArgs),
+    /// Show the stored token (or password) for a service.
+    Token(AuthTokenArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct AuthLoginArgs {
+    /// The service to store credentials for.
+    ///
+    /// Either the name of an index configured in `[[tool.uv.index]]`, or a URL, e.g.,
+    /// `https://pypi.example.com/simple`. If no scheme is provided, `https` is assumed.
+    pub service: String,
+
+    /// The username to store.
+    #[arg(short, long)]
+    pub username: Option<String>,
+
+    /// The password to store.
+    ///
+    /// If not provided, uv will prompt for the password.
+    #[arg(short, long)]
+    pub password: Option<String>,
+
+    /// The token to store.
+    ///
+    /// Using a token is equivalent to passing `__token__` as `--username` and the token as
+    /// `--password`.
+    #[arg(short, long, conflicts_with = "username", conflicts_with = "password")]
+    pub token: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct AuthLogoutArgs {
+    /// The service to remove credentials for.
+    ///
+    /// Either the name of an index configured in `[[tool.uv.index]]`, or a URL.
+    pub service: String,
+}
+
+#[derive(Args, Debug)]
+pub struct AuthTokenArgs {
+    /// The service to show the token for.
+    ///
+    /// Either the name of an index configured in `[[tool.uv.index]]`, or a URL.
+    pub service: String,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct CacheNamespace {
@@ -504,6 +613,12 @@ pub enum CacheCommand {
     Clean(CleanArgs),
     /// Prune all unreachable objects from the cache.
     Prune(PruneArgs),
+    /// Show statistics on the contents of the cache.
+    ///
+    /// Reports the size and number of files in each cache bucket, the number and size of the
+    /// unzipped wheels (including any that are no longer referenced by a cached wheel), and the
+    /// packages that take up the most space.
+    Stats(StatsArgs),
     /// Show the cache directory.
     ///
     ///
@@ -546,6 +661,32 @@ pub struct PruneArgs {
     /// that were built from source.
     #[arg(long)]
     pub ci: bool,
+
+    /// Evict the least-recently-used entries until the cache is at most the given size.
+    ///
+    /// Accepts a number of bytes, optionally followed by a binary unit (e.g., `500M` or `20G`).
+    ///
+    /// Unzipped wheels that are still linked into an existing environment (via symlinks or
+    /// hardlinks) are never evicted.
+    #[arg(long, value_parser = parse_size)]
+    pub max_size: Option<u64>,
+
+    /// Evict any entries that haven't been used within the given duration.
+    ///
+    /// Accepts a number followed by a unit of seconds (`s`), minutes (`m`), hours (`h`), days
+    /// (`d`), or weeks (`w`), e.g., `30d`.
+    ///
+    /// Unzipped wheels that are still linked into an existing environment (via symlinks or
+    /// hardlinks) are never evicted.
+    #[arg(long, value_parser = parse_duration)]
+    pub older_than: Option<Duration>,
+}
+
+#[derive(Args, Debug)]
+pub struct StatsArgs {
+    /// The format in which to display the cache statistics.
+    #[arg(long, value_enum, default_value_t = CacheStatsFormat::default())]
+    pub format: CacheStatsFormat,
 }
 
 #[derive(Args)]
@@ -751,7 +892,8 @@ pub enum ProjectCommand {
     Lock(LockArgs),
     /// Export the project's lockfile to an alternate format.
     ///
-    /// At present, only `requirements-txt` is supported.
+    /// At present, `requirements-txt`, `pylock.toml` (PEP 751), and `cyclonedx1.5` (a CycloneDX
+    /// JSON SBOM) are supported.
     ///
     /// The project is re-locked before exporting unless the `--locked` or `--frozen` flag is
     /// provided.
@@ -768,6 +910,24 @@ pub enum ProjectCommand {
     Export(ExportArgs),
     /// Display the project's dependency tree.
     Tree(TreeArgs),
+    /// Audit the project's lockfile for known vulnerabilities.
+    ///
+    /// Each package in the `uv.lock` is checked against a database of security advisories in the
+    /// OSV format. If any package is affected by an advisory, uv will exit with a non-zero status.
+    ///
+    /// By default, the PyPI advisory database is downloaded from the OSV project and cached. Use
+    /// `--database` to audit against a pre-fetched copy of the database, e.g., when running
+    /// offline.
+    ///
+    /// The lockfile is audited as-is: the project is not re-locked before auditing.
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help audit` for more details.",
+        after_long_help = ""
+    )]
+    Audit(AuditArgs),
 }
 
 /// A re-implementation of `Option`, used to avoid Clap's automatic `Option` flattening in
@@ -920,6 +1080,77 @@ fn parse_maybe_string(input: &str) -> Result<Maybe<String>, String> {
     }
 }
 
+/// Parse a size in bytes, with an optional binary unit (e.g., `500M`, `20G`, or `1TiB`).
+fn parse_size(input: &str) -> Result<u64, String> {
+    let (number, unit) = input.split_at(
+        input
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len()),
+    );
+    let number = number.parse::<u64>().map_err(|_| {
+        format!(
+            "expected a number, optionally followed by a unit (e.g., `20G`), but found `{input}`"
+        )
+    })?;
+
+    let unit = unit.trim().to_ascii_uppercase();
+    let unit = unit
+        .strip_suffix("IB")
+        .or_else(|| unit.strip_suffix('B'))
+        .unwrap_or(&unit);
+    let multiplier: u64 = match unit {
+        "" => 1,
+        "K" => 1 << 10,
+        "M" => 1 << 20,
+        "G" => 1 << 30,
+        "T" => 1 << 40,
+        _ => {
+            return Err(format!(
+                "unknown size unit `{unit}`; expected one of `K`, `M`, `G`, or `T`"
+            ))
+        }
+    };
+
+    number
+        .checked_mul(multiplier)
+        .ok_or_else(|| format!("size is too large: `{input}`"))
+}
+
+/// Parse a duration with a unit of seconds, minutes, hours, days, or weeks (e.g., `30d`).
+fn parse_duration(input: &str) -> Result<Duration, String> {
+    let (number, unit) = input.split_at(
+        input
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len()),
+    );
+    let number = number.parse::<u64>().map_err(|_| {
+        format!("expected a number followed by a unit (e.g., `30d`), but found `{input}`")
+    })?;
+
+    let multiplier: u64 = match unit.trim() {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 60 * 60 * 24,
+        "w" => 60 * 60 * 24 * 7,
+        "" => {
+            return Err(format!(
+                "missing duration unit in `{input}`; expected one of `s`, `m`, `h`, `d`, or `w`"
+            ))
+        }
+        unit => {
+            return Err(format!(
+                "unknown duration unit `{unit}`; expected one of `s`, `m`, `h`, `d`, or `w`"
+            ))
+        }
+    };
+
+    number
+        .checked_mul(multiplier)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("duration is too large: `{input}`"))
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PipCompileArgs {
@@ -1232,6 +1463,9 @@ pub struct PipSyncArgs {
     /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will
     /// extract the requirements for the relevant project.
     ///
+    /// If a `uv.lock` file is provided, uv will install the locked packages for the
+    /// current platform, without performing a resolution.
+    ///
     /// If `-` is provided, then requirements will be read from stdin.
     #[arg(required(true), value_parser = parse_file_path)]
     pub src_file: Vec<PathBuf>,
@@ -1255,6 +1489,48 @@ pub struct PipSyncArgs {
     #[arg(long, short, env = EnvVars::UV_BUILD_CONSTRAINT, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
     pub build_constraint: Vec<Maybe<PathBuf>>,
 
+    /// Include optional dependencies from the specified extra name; may be provided more than once.
+    ///
+    /// Only applies to `uv.lock` sources.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    ///
+    /// Only applies to `uv.lock` sources.
+    #[arg(long, conflicts_with = "extra", overrides_with = "no_all_extras")]
+    pub all_extras: bool,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// Include dependencies from the specified dependency group; may be provided more than once.
+    ///
+    /// Only applies to `uv.lock` sources.
+    #[arg(long)]
+    pub group: Vec<GroupName>,
+
+    /// Do not install the root project of the `uv.lock`.
+    ///
+    /// Only applies to `uv.lock` sources.
+    #[arg(long)]
+    pub no_install_project: bool,
+
+    /// Do not install any workspace members of the `uv.lock`, including the root project.
+    ///
+    /// This is particularly useful when building Docker images from a `uv.lock` alone, as the
+    /// workspace sources aren't required to install the remaining dependencies.
+    ///
+    /// Only applies to `uv.lock` sources.
+    #[arg(long)]
+    pub no_install_workspace: bool,
+
+    /// Do not install the given package(s) from the `uv.lock`.
+    ///
+    /// Only applies to `uv.lock` sources.
+    #[arg(long)]
+    pub no_install_package: Vec<PackageName>,
+
     #[command(flatten)]
     pub installer: InstallerArgs,
 
@@ -1476,6 +1752,9 @@ pub struct PipInstallArgs {
     /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will
     /// extract the requirements for the relevant project.
     ///
+    /// If a `uv.lock` file is provided, uv will install the locked packages for the
+    /// current platform, without performing a resolution.
+    ///
     /// If `-` is provided, then requirements will be read from stdin.
     #[arg(long, short, group = "sources", value_parser = parse_file_path)]
     pub requirement: Vec<PathBuf>,
@@ -1517,19 +1796,46 @@ pub struct PipInstallArgs {
 
     /// Include optional dependencies from the specified extra name; may be provided more than once.
     ///
-    /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
+    /// Only applies to `pyproject.toml`, `setup.py`, `setup.cfg`, and `uv.lock` sources.
     #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
     pub extra: Option<Vec<ExtraName>>,
 
     /// Include all optional dependencies.
     ///
-    /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
+    /// Only applies to `pyproject.toml`, `setup.py`, `setup.cfg`, and `uv.lock` sources.
     #[arg(long, conflicts_with = "extra", overrides_with = "no_all_extras")]
     pub all_extras: bool,
 
     #[arg(long, overrides_with("all_extras"), hide = true)]
     pub no_all_extras: bool,
 
+    /// Include dependencies from the specified dependency group; may be provided more than once.
+    ///
+    /// Only applies to `uv.lock` sources.
+    #[arg(long)]
+    pub group: Vec<GroupName>,
+
+    /// Do not install the root project of the `uv.lock`.
+    ///
+    /// Only applies to `uv.lock` sources.
+    #[arg(long)]
+    pub no_install_project: bool,
+
+    /// Do not install any workspace members of the `uv.lock`, including the root project.
+    ///
+    /// This is particularly useful when building Docker images from a `uv.lock` alone, as the
+    /// workspace sources aren't required to install the remaining dependencies.
+    ///
+    /// Only applies to `uv.lock` sources.
+    #[arg(long)]
+    pub no_install_workspace: bool,
+
+    /// Do not install the given package(s) from the `uv.lock`.
+    ///
+    /// Only applies to `uv.lock` sources.
+    #[arg(long)]
+    pub no_install_package: Vec<PackageName>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -2991,6 +3297,21 @@ pub struct LockArgs {
     #[arg(long, conflicts_with = "frozen", conflicts_with = "locked")]
     pub dry_run: bool,
 
+    /// Display the changes to the lockfile.
+    ///
+    /// Reports the packages that were added, removed, or changed, including changes to their
+    /// versions, sources, distributions, hashes, and resolution markers, along with changes to the
+    /// lockfile's supported Python versions and resolution markers.
+    ///
+    /// The report is written to stdout. Combine with `--dry-run` to preview the changes without
+    /// writing the lockfile.
+    #[arg(long)]
+    pub diff: bool,
+
+    /// The format in which to display the changes to the lockfile.
+    #[arg(long, value_enum, default_value_t = LockDiffFormat::default(), requires = "diff")]
+    pub diff_format: LockDiffFormat,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -3154,9 +3475,30 @@ pub struct AddArgs {
 #[allow(clippy::struct_excessive_bools)]
 pub struct RemoveArgs {
     /// The names of the dependencies to remove (e.g., `ruff`).
-    #[arg(required = true)]
+    #[arg(required_unless_present = "unused")]
     pub packages: Vec<PackageName>,
 
+    /// Remove any dependencies in `project.dependencies` that are never imported by the project.
+    ///
+    /// The top-level modules provided by each dependency are read from its installation in the
+    /// project environment, and compared against the `import` statements in the project's Python
+    /// source files. Dependencies that are not installed in the project environment are retained.
+    ///
+    /// As the detection relies on static analysis, dependencies that are only used dynamically
+    /// (e.g., plugins, or command-line tools) will be reported as unused; use `--dry-run` to
+    /// review the unused dependencies prior to removal. If the imports of a source file can't be
+    /// determined (e.g., as it imports a module by a computed name), the unused dependencies are
+    /// reported, but not removed.
+    #[arg(
+        long,
+        conflicts_with_all = ["packages", "dev", "optional", "group", "script"]
+    )]
+    pub unused: bool,
+
+    /// Report the unused dependencies, without removing them.
+    #[arg(long, requires = "unused")]
+    pub dry_run: bool,
+
     /// Remove the packages from the development dependency group.
     ///
     /// This option is an alias for `--group dev`.
@@ -3239,6 +3581,15 @@ pub struct TreeArgs {
     #[command(flatten)]
     pub tree: DisplayTreeArgs,
 
+    /// The format in which to display the dependency tree.
+    ///
+    /// With `json`, the dependency graph is displayed as a list of packages, with the name,
+    /// version, and source of each package, and the edges between them, with any extras,
+    /// dependency groups, and markers. The `--depth`, `--invert`, and `--outdated` options are
+    /// respected.
+    #[arg(long, value_enum, default_value_t = TreeFormat::default())]
+    pub format: TreeFormat,
+
     /// Include the development dependency group.
     ///
     /// Development dependencies are defined via `dependency-groups.dev` or
@@ -3341,12 +3692,32 @@ pub struct TreeArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+pub struct AuditArgs {
+    /// The path to a directory of security advisories in the OSV format.
+    ///
+    /// The directory should contain one JSON file per advisory, like the archive of PyPI advisories
+    /// published at `https://osv-vulnerabilities.storage.googleapis.com/PyPI/all.zip`.
+    ///
+    /// If not provided, uv will use the advisory database in the cache, downloading it if it's
+    /// missing, more than a day old, or if `--refresh` is provided.
+    #[arg(long, env = EnvVars::UV_AUDIT_DATABASE)]
+    pub database: Option<PathBuf>,
+
+    /// Ignore an advisory, by its ID or any of its aliases (e.g., a CVE identifier).
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub ignore: Vec<String>,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct ExportArgs {
     /// The format to which `uv.lock` should be exported.
     ///
-    /// At present, only `requirements-txt` is supported.
+    /// At present, `requirements-txt`, `pylock.toml` (PEP 751), and `cyclonedx1.5` (a CycloneDX
+    /// JSON SBOM) are supported.
     #[arg(long, value_enum, default_value_t = ExportFormat::default())]
     pub format: ExportFormat,
 
@@ -3574,6 +3945,17 @@ pub enum ToolCommand {
     /// installation, it will continue to be respected in upgrades.
     #[command(alias = "update")]
     Upgrade(ToolUpgradeArgs),
+    /// Install the tools declared in a tool manifest.
+    ///
+    /// The manifest is a TOML file with a `[[tool]]` entry for each tool,
+    /// including its version specifiers, additional requirements, Python
+    /// version, and settings.
+    ///
+    /// Tools that are not installed will be installed, and installed tools
+    /// that differ from the manifest will be updated. Tools that are
+    /// installed, but not declared in the manifest, are left untouched unless
+    /// `--exact` is provided.
+    Sync(ToolSyncArgs),
     /// List installed tools.
     List(ToolListArgs),
     /// Uninstall a tool.
@@ -3715,6 +4097,16 @@ pub struct ToolInstallArgs {
     #[arg(long)]
     pub force: bool,
 
+    /// Install the tool from the given lockfile.
+    ///
+    /// Accepts a `uv.lock` file, e.g., as persisted alongside an installed tool in the tool
+    /// directory. The requested tool is resolved against the lockfile, and if the resolution
+    /// doesn't match the locked packages exactly (by name, version, and source), uv will exit with
+    /// an error. Otherwise, the locked packages are installed from their locked sources, and
+    /// verified against the hashes recorded in the lockfile.
+    #[arg(long, value_name = "LOCKFILE", conflicts_with_all = ["upgrade", "upgrade_package"])]
+    pub locked: Option<PathBuf>,
+
     /// The Python interpreter to use to build the tool environment.
     ///
     /// See `uv help python` for details on Python discovery and supported
@@ -3741,6 +4133,21 @@ pub struct ToolListArgs {
     #[arg(long)]
     pub show_version_specifiers: bool,
 
+    /// List outdated tools.
+    ///
+    /// The latest version of each tool is determined using the index and settings with which the
+    /// tool was installed. Tools that are up-to-date are omitted.
+    #[arg(long)]
+    pub outdated: bool,
+
+    /// Select the output format.
+    ///
+    /// With `json`, each tool is displayed with its name, version, Python version, executables,
+    /// and additional requirements, along with the latest version of the tool if `--outdated` is
+    /// provided.
+    #[arg(long, value_enum, default_value_t = ToolListFormat::default())]
+    pub format: ToolListFormat,
+
     // Hide unused global Python options.
     #[arg(long, hide = true)]
     pub python_preference: Option<PythonPreference>,
@@ -3813,6 +4220,27 @@ pub struct ToolUpgradeArgs {
     pub build: BuildOptionsArgs,
 }
 
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ToolSyncArgs {
+    /// The path to the tool manifest.
+    #[arg(value_name = "MANIFEST")]
+    pub manifest: PathBuf,
+
+    /// Uninstall any tools that are not declared in the manifest.
+    #[arg(long)]
+    pub exact: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PythonNamespace {
@@ -3853,6 +4281,21 @@ pub enum PythonCommand {
     /// See `uv help python` to view supported request formats.
     Install(PythonInstallArgs),
 
+    /// Upgrade installed Python versions to the latest patch version.
+    ///
+    /// For each installed minor version, e.g., Python 3.12, the latest available patch version is
+    /// installed, e.g., Python 3.12.7.
+    ///
+    /// Any Python executables that point to the previous patch version are updated to point to the
+    /// latest patch version. Similarly, tool environments, the project virtual environment, and the
+    /// active virtual environment are updated to use the latest patch version if they were created
+    /// from the previous patch version.
+    ///
+    /// By default, the previous patch version is retained. Use `--remove-old` to uninstall it.
+    ///
+    /// See `uv help python` to view supported request formats.
+    Upgrade(PythonUpgradeArgs),
+
     /// Search for a Python installation.
     ///
     /// Displays the path to the Python executable.
@@ -3954,6 +4397,28 @@ pub struct PythonInstallArgs {
     pub force: bool,
 }
 
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PythonUpgradeArgs {
+    /// The Python version(s) to upgrade.
+    ///
+    /// If not provided, all installed Python versions will be upgraded.
+    ///
+    /// See `uv help python` to view supported request formats.
+    pub targets: Vec<String>,
+
+    /// Uninstall the previous patch version after upgrading.
+    ///
+    /// Virtual environments that were created from the previous patch version, but are not known
+    /// to uv (i.e., are not a tool environment, the project virtual environment, or the active
+    /// virtual environment), will no longer work once the previous patch version is removed.
+    ///
+    /// If a known environment cannot be updated to use the new patch version, the previous patch
+    /// version is retained.
+    #[arg(long)]
+    pub remove_old: bool,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PythonUninstallArgs {
@@ -4786,6 +5251,33 @@ pub struct PublishArgs {
     #[arg(default_value = "dist/*")]
     pub files: Vec<String>,
 
+    /// The name of an index in the configuration to use for publishing.
+    ///
+    /// The index must have a `publish-url` setting, for example:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://example.com/simple"
+    /// publish-url = "https://example.com/upload"
+    /// ```
+    ///
+    /// The index `url` will be used to check for existing files to skip duplicate uploads.
+    ///
+    /// With these settings, the following two calls are equivalent:
+    ///
+    /// ```shell
+    /// uv publish --index internal
+    /// uv publish --publish-url https://example.com/upload --check-url https://example.com/simple
+    /// ```
+    #[arg(
+        long,
+        env = EnvVars::UV_PUBLISH_INDEX,
+        conflicts_with = "publish_url",
+        conflicts_with = "check_url"
+    )]
+    pub index: Option<String>,
+
     /// The URL of the upload endpoint (not the index URL).
     ///
     /// Note that there are typically different URLs for index access (e.g., `https:://.../simple`)
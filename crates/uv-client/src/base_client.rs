@@ -13,7 +13,7 @@ use std::time::Duration;
 use std::{env, iter};
 use tracing::debug;
 use url::Url;
-use uv_auth::AuthMiddleware;
+use uv_auth::{AuthMiddleware, Indexes};
 use uv_configuration::{KeyringProviderType, TrustedHost};
 use uv_fs::Simplified;
 use uv_pep508::MarkerEnvironment;
@@ -54,6 +54,7 @@ pub struct BaseClientBuilder<'a> {
     markers: Option<&'a MarkerEnvironment>,
     platform: Option<&'a Platform>,
     auth_integration: AuthIntegration,
+    indexes: Indexes,
     default_timeout: Duration,
     extra_middleware: Option<ExtraMiddleware>,
 }
@@ -88,6 +89,7 @@ impl BaseClientBuilder<'_> {
             markers: None,
             platform: None,
             auth_integration: AuthIntegration::default(),
+            indexes: Indexes::default(),
             default_timeout: Duration::from_secs(30),
             extra_middleware: None,
         }
@@ -149,6 +151,12 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn indexes(mut self, indexes: Indexes) -> Self {
+        self.indexes = indexes;
+        self
+    }
+
     #[must_use]
     pub fn default_timeout(mut self, default_timeout: Duration) -> Self {
         self.default_timeout = default_timeout;
@@ -319,13 +327,17 @@ impl<'a> BaseClientBuilder<'a> {
                 // Initialize the authentication middleware to set headers.
                 match self.auth_integration {
                     AuthIntegration::Default => {
-                        client = client
-                            .with(AuthMiddleware::new().with_keyring(self.keyring.to_provider()));
+                        client = client.with(
+                            AuthMiddleware::new()
+                                .with_keyring(self.keyring.to_provider())
+                                .with_indexes(self.indexes.clone()),
+                        );
                     }
                     AuthIntegration::OnlyAuthenticated => {
                         client = client.with(
                             AuthMiddleware::new()
                                 .with_keyring(self.keyring.to_provider())
+                                .with_indexes(self.indexes.clone())
                                 .with_only_authenticated(true),
                         );
                     }
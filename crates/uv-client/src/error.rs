@@ -8,6 +8,7 @@ use url::Url;
 use uv_distribution_filename::{WheelFilename, WheelFilenameError};
 use uv_normalize::PackageName;
 
+use crate::flat_index::FlatIndexError;
 use crate::html;
 use crate::middleware::OfflineError;
 
@@ -144,6 +145,9 @@ pub enum ErrorKind {
     #[error(transparent)]
     JoinRelativeUrl(#[from] uv_pypi_types::JoinRelativeError),
 
+    #[error(transparent)]
+    Flat(#[from] FlatIndexError),
+
     #[error("Expected a file URL, but received: {0}")]
     NonFileUrl(Url),
 
@@ -45,7 +45,7 @@ pub struct FlatIndexEntries {
 
 impl FlatIndexEntries {
     /// Create a [`FlatIndexEntries`] from a list of `--find-links` entries.
-    fn from_entries(entries: Vec<(DistFilename, File, IndexUrl)>) -> Self {
+    pub(crate) fn from_entries(entries: Vec<(DistFilename, File, IndexUrl)>) -> Self {
         Self {
             entries,
             offline: false,
@@ -98,32 +98,7 @@ impl<'a> FlatIndexClient<'a> {
         indexes: impl Iterator<Item = &IndexUrl>,
     ) -> Result<FlatIndexEntries, FlatIndexError> {
         let mut fetches = futures::stream::iter(indexes)
-            .map(|index| async move {
-                let entries = match index {
-                    IndexUrl::Path(url) => {
-                        let path = url
-                            .to_file_path()
-                            .map_err(|()| FlatIndexError::NonFileUrl(url.to_url()))?;
-                        Self::read_from_directory(&path, index)
-                            .map_err(|err| FlatIndexError::FindLinksDirectory(path.clone(), err))?
-                    }
-                    IndexUrl::Pypi(url) | IndexUrl::Url(url) => self
-                        .read_from_url(url, index)
-                        .await
-                        .map_err(|err| FlatIndexError::FindLinksUrl(url.to_url(), err))?,
-                };
-                if entries.is_empty() {
-                    warn!("No packages found in `--find-links` entry: {}", index);
-                } else {
-                    debug!(
-                        "Found {} package{} in `--find-links` entry: {}",
-                        entries.len(),
-                        if entries.len() == 1 { "" } else { "s" },
-                        index
-                    );
-                }
-                Ok::<FlatIndexEntries, FlatIndexError>(entries)
-            })
+            .map(|index| self.fetch_index(index))
             .buffered(16);
 
         let mut results = FlatIndexEntries::default();
@@ -133,6 +108,35 @@ impl<'a> FlatIndexClient<'a> {
         Ok(results)
     }
 
+    /// Read a single flat index, either a local directory or a remote HTML index.
+    #[allow(clippy::result_large_err)]
+    pub async fn fetch_index(&self, index: &IndexUrl) -> Result<FlatIndexEntries, FlatIndexError> {
+        let entries = match index {
+            IndexUrl::Path(url) => {
+                let path = url
+                    .to_file_path()
+                    .map_err(|()| FlatIndexError::NonFileUrl(url.to_url()))?;
+                Self::read_from_directory(&path, index)
+                    .map_err(|err| FlatIndexError::FindLinksDirectory(path.clone(), err))?
+            }
+            IndexUrl::Pypi(url) | IndexUrl::Url(url) => self
+                .read_from_url(url, index)
+                .await
+                .map_err(|err| FlatIndexError::FindLinksUrl(url.to_url(), err))?,
+        };
+        if entries.is_empty() {
+            warn!("No packages found in `--find-links` entry: {}", index);
+        } else {
+            debug!(
+                "Found {} package{} in `--find-links` entry: {}",
+                entries.len(),
+                if entries.len() == 1 { "" } else { "s" },
+                index
+            );
+        }
+        Ok(entries)
+    }
+
     /// Read a flat remote index from a `--find-links` URL.
     async fn read_from_url(
         &self,
@@ -4,11 +4,14 @@ use http::HeaderMap;
 use itertools::Either;
 use reqwest::{Client, Response, StatusCode};
 use reqwest_middleware::ClientWithMiddleware;
+use rustc_hash::FxHashMap;
 use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use tokio::sync::OnceCell;
 use tracing::{info_span, instrument, trace, warn, Instrument};
 use url::Url;
 
@@ -17,7 +20,7 @@ use uv_configuration::KeyringProviderType;
 use uv_configuration::{IndexStrategy, TrustedHost};
 use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
 use uv_distribution_types::{
-    BuiltDist, File, FileLocation, Index, IndexCapabilities, IndexUrl, IndexUrls, Name,
+    BuiltDist, File, FileLocation, IndexCapabilities, IndexFormat, IndexUrl, IndexUrls, Name,
 };
 use uv_metadata::{read_metadata_async_seek, read_metadata_async_stream};
 use uv_normalize::PackageName;
@@ -31,7 +34,10 @@ use crate::cached_client::CacheControl;
 use crate::html::SimpleHtml;
 use crate::remote_metadata::wheel_metadata_from_remote_zip;
 use crate::rkyvutil::OwnedArchive;
-use crate::{BaseClient, CachedClient, CachedClientError, Error, ErrorKind};
+use crate::{
+    BaseClient, CachedClient, CachedClientError, Error, ErrorKind, FlatIndexClient,
+    FlatIndexEntries,
+};
 
 /// A builder for an [`RegistryClient`].
 #[derive(Debug, Clone)]
@@ -56,6 +62,7 @@ impl RegistryClientBuilder<'_> {
 impl<'a> RegistryClientBuilder<'a> {
     #[must_use]
     pub fn index_urls(mut self, index_urls: IndexUrls) -> Self {
+        self.base_client_builder = self.base_client_builder.indexes(index_urls.auth_indexes());
         self.index_urls = index_urls;
         self
     }
@@ -147,6 +154,7 @@ impl<'a> RegistryClientBuilder<'a> {
             connectivity,
             client,
             timeout,
+            flat_indexes: Arc::default(),
         }
     }
 
@@ -168,6 +176,7 @@ impl<'a> RegistryClientBuilder<'a> {
             connectivity,
             client,
             timeout,
+            flat_indexes: Arc::default(),
         }
     }
 }
@@ -200,6 +209,8 @@ pub struct RegistryClient {
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
     timeout: Duration,
+    /// The entries of each flat index, such that each index is fetched at most once.
+    flat_indexes: Arc<Mutex<FxHashMap<IndexUrl, Arc<OnceCell<FlatIndexEntries>>>>>,
 }
 
 impl RegistryClient {
@@ -234,11 +245,15 @@ impl RegistryClient {
         package_name: &PackageName,
         index: Option<&'index IndexUrl>,
         capabilities: &IndexCapabilities,
-    ) -> Result<Vec<(&'index IndexUrl, OwnedArchive<SimpleMetadata>)>, Error> {
+    ) -> Result<Vec<(&'index IndexUrl, MetadataFormat)>, Error> {
         let indexes = if let Some(index) = index {
-            Either::Left(std::iter::once(index))
+            Either::Left(std::iter::once((index, self.index_urls.format(index))))
         } else {
-            Either::Right(self.index_urls.indexes().map(Index::url))
+            Either::Right(
+                self.index_urls
+                    .indexes()
+                    .map(|index| (index.url(), index.format)),
+            )
         };
 
         let mut it = indexes.peekable();
@@ -247,8 +262,18 @@ impl RegistryClient {
         }
 
         let mut results = Vec::new();
-        for index in it {
-            match self.simple_single_index(package_name, index).await {
+        for (index, format) in it {
+            let result = match format {
+                IndexFormat::Simple => self
+                    .simple_single_index(package_name, index)
+                    .await
+                    .map(MetadataFormat::Simple),
+                IndexFormat::Flat => self
+                    .flat_single_index(package_name, index)
+                    .await
+                    .map(MetadataFormat::Flat),
+            };
+            match result {
                 Ok(metadata) => {
                     results.push((index, metadata));
 
@@ -276,6 +301,9 @@ impl RegistryClient {
                     // The package could not be found in the local index.
                     ErrorKind::FileNotFound(_) => {}
 
+                    // The package could not be found in the flat index.
+                    ErrorKind::PackageNotFound(_) => {}
+
                     other => return Err(other.into()),
                 },
             };
@@ -293,6 +321,48 @@ impl RegistryClient {
         Ok(results)
     }
 
+    /// Fetch the distributions for a given package from a single flat index (e.g., a
+    /// `--find-links`-style directory or HTML page).
+    async fn flat_single_index(
+        &self,
+        package_name: &PackageName,
+        index: &IndexUrl,
+    ) -> Result<FlatIndexEntries, Error> {
+        // Flat indexes aren't partitioned by package, so fetch the entire index once and reuse
+        // it for every subsequent package.
+        let cell = self
+            .flat_indexes
+            .lock()
+            .unwrap()
+            .entry(index.clone())
+            .or_default()
+            .clone();
+        let entries = cell
+            .get_or_try_init(|| async {
+                FlatIndexClient::new(self, &self.cache)
+                    .fetch_index(index)
+                    .await
+            })
+            .await
+            .map_err(ErrorKind::Flat)?;
+
+        if entries.offline && entries.entries.is_empty() {
+            return Err(ErrorKind::Offline(index.to_string()).into());
+        }
+
+        let entries = entries
+            .entries
+            .iter()
+            .filter(|(filename, ..)| filename.name() == package_name)
+            .cloned()
+            .collect::<Vec<_>>();
+        if entries.is_empty() {
+            return Err(ErrorKind::PackageNotFound(package_name.to_string()).into());
+        }
+
+        Ok(FlatIndexEntries::from_entries(entries))
+    }
+
     /// Fetch the [`SimpleMetadata`] from a single index for a given package.
     ///
     /// The index can either be a PEP 503-compatible remote repository, or a local directory laid
@@ -768,6 +838,15 @@ impl RegistryClient {
     }
 }
 
+/// The metadata for a package, as returned by a single index.
+#[derive(Debug)]
+pub enum MetadataFormat {
+    /// The metadata returned by a PyPI-style index implementing the Simple API.
+    Simple(OwnedArchive<SimpleMetadata>),
+    /// The distributions listed by a `--find-links`-style flat index.
+    Flat(FlatIndexEntries),
+}
+
 #[derive(Default, Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
 pub struct VersionFiles {
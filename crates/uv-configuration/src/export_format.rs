@@ -7,4 +7,12 @@ pub enum ExportFormat {
     /// Export in `requirements.txt` format.
     #[default]
     RequirementsTxt,
+    /// Export in `pylock.toml` format, as specified in PEP 751.
+    #[serde(rename = "pylock.toml", alias = "pylock-toml")]
+    #[cfg_attr(feature = "clap", clap(name = "pylock.toml", alias = "pylock-toml"))]
+    PylockToml,
+    /// Export as a CycloneDX 1.5 Software Bill of Materials, in JSON.
+    #[serde(rename = "cyclonedx1.5", alias = "cyclonedx")]
+    #[cfg_attr(feature = "clap", clap(name = "cyclonedx1.5", alias = "cyclonedx"))]
+    CycloneDx1_5,
 }
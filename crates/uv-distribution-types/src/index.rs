@@ -3,7 +3,7 @@ use std::str::FromStr;
 use thiserror::Error;
 use url::Url;
 
-use uv_auth::Credentials;
+use uv_auth::{AuthPolicy, Credentials};
 
 use crate::index_name::{IndexName, IndexNameError};
 use crate::origin::Origin;
@@ -11,6 +11,7 @@ use crate::{IndexUrl, IndexUrlError};
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
 pub struct Index {
     /// The name of the index.
     ///
@@ -57,29 +58,66 @@ pub struct Index {
     /// is given the highest priority when resolving packages.
     #[serde(default)]
     pub default: bool,
+    /// The format used by the index.
+    ///
+    /// Indexes can either be PEP 503-compliant (i.e., a PyPI-style registry implementing the Simple
+    /// API) or structured as a flat list of distributions (e.g., `--find-links`). In both cases,
+    /// indexes can point to either local or remote resources.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "pytorch"
+    /// url = "https://download.pytorch.org/whl/torch_stable.html"
+    /// format = "flat"
+    /// ```
+    #[serde(default)]
+    pub format: IndexFormat,
+    /// When uv should use authentication for requests to the index.
+    ///
+    /// By default (`auto`), uv attempts an unauthenticated request first, and searches for
+    /// credentials (e.g., in a netrc file or the keyring) if the request fails. If set to
+    /// `always`, uv will never send an unauthenticated request, and will error if no credentials
+    /// can be found. If set to `never`, uv will never send credentials to the index, even if
+    /// they're available for the same host.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.example.com/simple"
+    /// authenticate = "always"
+    /// ```
+    #[serde(default)]
+    pub authenticate: AuthPolicy,
+    /// The URL of the upload endpoint.
+    ///
+    /// When using `uv publish --index <name>`, this URL is used for publishing.
+    ///
+    /// A configuration for the default index PyPI would look as follows:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "pypi"
+    /// url = "https://pypi.org/simple"
+    /// publish-url = "https://upload.pypi.org/legacy/"
+    /// ```
+    pub publish_url: Option<Url>,
     /// The origin of the index (e.g., a CLI flag, a user-level configuration file, etc.).
     #[serde(skip)]
     pub origin: Option<Origin>,
-    // /// The type of the index.
-    // ///
-    // /// Indexes can either be PEP 503-compliant (i.e., a registry implementing the Simple API) or
-    // /// structured as a flat list of distributions (e.g., `--find-links`). In both cases, indexes
-    // /// can point to either local or remote resources.
-    // #[serde(default)]
-    // pub r#type: IndexKind,
 }
 
-// #[derive(
-//     Default, Debug, Copy, Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize,
-// )]
-// #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
-// pub enum IndexKind {
-//     /// A PEP 503 and/or PEP 691-compliant index.
-//     #[default]
-//     Simple,
-//     /// An index containing a list of links to distributions (e.g., `--find-links`).
-//     Flat,
-// }
+#[derive(
+    Default, Debug, Copy, Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum IndexFormat {
+    /// A PyPI-style index implementing the Simple API.
+    #[default]
+    Simple,
+    /// A `--find-links`-style index containing a flat list of wheels and source distributions.
+    Flat,
+}
 
 impl Index {
     /// Initialize an [`Index`] from a pip-style `--index-url`.
@@ -89,6 +127,9 @@ impl Index {
             name: None,
             explicit: false,
             default: true,
+            format: IndexFormat::Simple,
+            authenticate: AuthPolicy::default(),
+            publish_url: None,
             origin: None,
         }
     }
@@ -100,6 +141,9 @@ impl Index {
             name: None,
             explicit: false,
             default: false,
+            format: IndexFormat::Simple,
+            authenticate: AuthPolicy::default(),
+            publish_url: None,
             origin: None,
         }
     }
@@ -111,6 +155,9 @@ impl Index {
             name: None,
             explicit: false,
             default: false,
+            format: IndexFormat::Flat,
+            authenticate: AuthPolicy::default(),
+            publish_url: None,
             origin: None,
         }
     }
@@ -165,6 +212,9 @@ impl FromStr for Index {
                     url,
                     explicit: false,
                     default: false,
+                    format: IndexFormat::Simple,
+                    authenticate: AuthPolicy::default(),
+                    publish_url: None,
                     origin: None,
                 });
             }
@@ -177,6 +227,9 @@ impl FromStr for Index {
             url,
             explicit: false,
             default: false,
+            format: IndexFormat::Simple,
+            authenticate: AuthPolicy::default(),
+            publish_url: None,
             origin: None,
         })
     }
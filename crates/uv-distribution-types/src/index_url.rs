@@ -11,7 +11,7 @@ use url::{ParseError, Url};
 
 use uv_pep508::{VerbatimUrl, VerbatimUrlError};
 
-use crate::{Index, Verbatim};
+use crate::{Index, IndexFormat, Verbatim};
 
 static PYPI_URL: LazyLock<Url> = LazyLock::new(|| Url::parse("https://pypi.org/simple").unwrap());
 
@@ -384,6 +384,27 @@ impl<'a> IndexUrls {
             .chain(self.default_index())
             .filter(|index| !index.explicit)
     }
+
+    /// Return the [`IndexFormat`] of the given [`IndexUrl`].
+    ///
+    /// Includes explicit indexes. If the URL doesn't match any configured index, it's assumed to
+    /// implement the Simple API.
+    pub fn format(&'a self, url: &IndexUrl) -> IndexFormat {
+        self.indexes
+            .iter()
+            .find(|index| index.url == *url)
+            .map(|index| index.format)
+            .unwrap_or_default()
+    }
+
+    /// Return the authentication policies for the configured indexes, including explicit indexes.
+    pub fn auth_indexes(&'a self) -> uv_auth::Indexes {
+        uv_auth::Indexes::from_indexes(
+            self.indexes
+                .iter()
+                .map(|index| uv_auth::Index::new(index.raw_url().clone(), index.authenticate)),
+        )
+    }
 }
 
 bitflags::bitflags! {
@@ -1,7 +1,8 @@
 use std::fmt::{Display, Formatter};
-use uv_distribution_filename::BuildTag;
+use uv_distribution_filename::{BuildTag, WheelFilename};
 
 use uv_pep440::VersionSpecifiers;
+use uv_pep508::{MarkerExpression, MarkerOperator, MarkerTree, MarkerValueString};
 use uv_platform_tags::{IncompatibleTag, TagPriority};
 use uv_pypi_types::{HashDigest, Yanked};
 
@@ -82,6 +83,7 @@ impl IncompatibleDist {
                     None => format!("has {self}"),
                 },
                 IncompatibleWheel::RequiresPython(..) => format!("requires {self}"),
+                IncompatibleWheel::MissingPlatform(_) => format!("has {self}"),
             },
             Self::Source(incompatibility) => match incompatibility {
                 IncompatibleSource::NoBuild => format!("has {self}"),
@@ -109,6 +111,7 @@ impl IncompatibleDist {
                     None => format!("have {self}"),
                 },
                 IncompatibleWheel::RequiresPython(..) => format!("require {self}"),
+                IncompatibleWheel::MissingPlatform(_) => format!("have {self}"),
             },
             Self::Source(incompatibility) => match incompatibility {
                 IncompatibleSource::NoBuild => format!("have {self}"),
@@ -161,6 +164,13 @@ impl Display for IncompatibleDist {
                 IncompatibleWheel::RequiresPython(python, _) => {
                     write!(f, "Python {python}")
                 }
+                IncompatibleWheel::MissingPlatform(marker) => {
+                    if let Some(marker) = marker.contents() {
+                        write!(f, "no wheels for the required environment `{marker}`")
+                    } else {
+                        f.write_str("no wheels for the required environment")
+                    }
+                }
             },
             Self::Source(incompatibility) => match incompatibility {
                 IncompatibleSource::NoBuild => {
@@ -215,6 +225,8 @@ pub enum IncompatibleWheel {
     Yanked(Yanked),
     /// The use of binary wheels is disabled.
     NoBinary,
+    /// None of the wheels are compatible with a required environment.
+    MissingPlatform(MarkerTree),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -436,6 +448,35 @@ impl PrioritizedDist {
     pub fn best_wheel(&self) -> Option<&(RegistryBuiltWheel, WheelCompatibility)> {
         self.0.best_wheel_index.map(|i| &self.0.wheels[i])
     }
+
+    /// Returns the markers for the environments in which the distribution can be installed.
+    ///
+    /// If the distribution only includes a source distribution, it's assumed to be buildable in
+    /// any environment. Otherwise, the environments are inferred from the platform tags of its
+    /// usable wheels.
+    pub fn implied_markers(&self) -> MarkerTree {
+        if self.0.wheels.is_empty() {
+            return if self.0.source.is_some() {
+                MarkerTree::TRUE
+            } else {
+                MarkerTree::FALSE
+            };
+        }
+
+        let mut marker = MarkerTree::FALSE;
+        for (wheel, compatibility) in &self.0.wheels {
+            // Wheels that are incompatible with the current platform may still be compatible
+            // with another environment, but wheels that are unusable (e.g., yanked) are not.
+            if matches!(
+                compatibility,
+                WheelCompatibility::Compatible(..)
+                    | WheelCompatibility::Incompatible(IncompatibleWheel::Tag(_))
+            ) {
+                marker.or(implied_platform_markers(&wheel.filename));
+            }
+        }
+        marker
+    }
 }
 
 impl<'a> CompatibleDist<'a> {
@@ -471,6 +512,16 @@ impl<'a> CompatibleDist<'a> {
         }
     }
 
+    /// Returns the [`PrioritizedDist`] that the distribution came from, if any.
+    pub fn prioritized(&self) -> Option<&'a PrioritizedDist> {
+        match *self {
+            CompatibleDist::InstalledDist(_) => None,
+            CompatibleDist::SourceDist { prioritized, .. }
+            | CompatibleDist::CompatibleWheel { prioritized, .. }
+            | CompatibleDist::IncompatibleWheel { prioritized, .. } => Some(prioritized),
+        }
+    }
+
     /// Returns a [`RegistryBuiltWheel`] if the distribution includes a compatible or incompatible
     /// wheel.
     pub fn wheel(&self) -> Option<&RegistryBuiltWheel> {
@@ -566,28 +617,108 @@ impl IncompatibleWheel {
                         timestamp_other < timestamp_self
                     }
                 },
-                Self::NoBinary | Self::RequiresPython(_, _) | Self::Tag(_) | Self::Yanked(_) => {
-                    true
-                }
+                Self::NoBinary
+                | Self::RequiresPython(_, _)
+                | Self::Tag(_)
+                | Self::Yanked(_)
+                | Self::MissingPlatform(_) => true,
             },
             Self::Tag(tag_self) => match other {
                 Self::ExcludeNewer(_) => false,
                 Self::Tag(tag_other) => tag_other > tag_self,
-                Self::NoBinary | Self::RequiresPython(_, _) | Self::Yanked(_) => true,
+                Self::NoBinary
+                | Self::RequiresPython(_, _)
+                | Self::Yanked(_)
+                | Self::MissingPlatform(_) => true,
             },
             Self::RequiresPython(_, _) => match other {
                 Self::ExcludeNewer(_) | Self::Tag(_) => false,
                 // Version specifiers cannot be reasonably compared
                 Self::RequiresPython(_, _) => false,
-                Self::NoBinary | Self::Yanked(_) => true,
+                Self::NoBinary | Self::Yanked(_) | Self::MissingPlatform(_) => true,
             },
             Self::Yanked(_) => match other {
                 Self::ExcludeNewer(_) | Self::Tag(_) | Self::RequiresPython(_, _) => false,
                 // Yanks with a reason are more helpful for errors
                 Self::Yanked(yanked_other) => matches!(yanked_other, Yanked::Reason(_)),
-                Self::NoBinary => true,
+                Self::NoBinary | Self::MissingPlatform(_) => true,
             },
-            Self::NoBinary => false,
+            Self::NoBinary => matches!(other, Self::MissingPlatform(_)),
+            Self::MissingPlatform(_) => false,
+        }
+    }
+}
+
+/// Returns the markers for the platforms that a wheel can be installed on, as implied by its
+/// platform tags.
+///
+/// Unrecognized platform tags are assumed to be compatible with any platform.
+fn implied_platform_markers(filename: &WheelFilename) -> MarkerTree {
+    let mut marker = MarkerTree::FALSE;
+    for platform_tag in &filename.platform_tag {
+        marker.or(implied_platform_tag_markers(platform_tag));
+    }
+    marker
+}
+
+/// Returns the markers implied by a single platform tag (e.g., `manylinux_2_17_x86_64`).
+fn implied_platform_tag_markers(platform_tag: &str) -> MarkerTree {
+    /// The architectures that may appear as a suffix of a Linux platform tag.
+    const LINUX_ARCHITECTURES: &[&str] = &[
+        "x86_64", "i686", "aarch64", "armv7l", "ppc64le", "ppc64", "s390x", "riscv64",
+    ];
+
+    match platform_tag {
+        "any" => MarkerTree::TRUE,
+        "win32" => platform_markers("win32", &["x86"]),
+        "win_amd64" => platform_markers("win32", &["AMD64"]),
+        "win_arm64" => platform_markers("win32", &["ARM64"]),
+        tag if tag.starts_with("macosx_") => {
+            if tag.ends_with("_universal2") {
+                platform_markers("darwin", &["x86_64", "arm64"])
+            } else if tag.ends_with("_arm64") {
+                platform_markers("darwin", &["arm64"])
+            } else if tag.ends_with("_x86_64") || tag.ends_with("_intel") {
+                platform_markers("darwin", &["x86_64"])
+            } else {
+                platform_markers("darwin", &[])
+            }
+        }
+        tag if tag.starts_with("linux_")
+            || tag.starts_with("manylinux")
+            || tag.starts_with("musllinux_") =>
+        {
+            if let Some(arch) = LINUX_ARCHITECTURES
+                .iter()
+                .find(|arch| tag.ends_with(&format!("_{arch}")))
+            {
+                platform_markers("linux", &[*arch])
+            } else {
+                platform_markers("linux", &[])
+            }
+        }
+        _ => MarkerTree::TRUE,
+    }
+}
+
+/// Returns a marker for the given `sys_platform`, restricted to any of the given
+/// `platform_machine` values (if non-empty).
+fn platform_markers(sys_platform: &str, platform_machines: &[&str]) -> MarkerTree {
+    let mut marker = MarkerTree::expression(MarkerExpression::String {
+        key: MarkerValueString::SysPlatform,
+        operator: MarkerOperator::Equal,
+        value: sys_platform.to_string(),
+    });
+    if !platform_machines.is_empty() {
+        let mut machine = MarkerTree::FALSE;
+        for platform_machine in platform_machines {
+            machine.or(MarkerTree::expression(MarkerExpression::String {
+                key: MarkerValueString::PlatformMachine,
+                operator: MarkerOperator::Equal,
+                value: (*platform_machine).to_string(),
+            }));
         }
+        marker.and(machine);
     }
+    marker
 }
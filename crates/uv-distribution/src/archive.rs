@@ -18,7 +18,7 @@ impl Archive {
     }
 
     /// Returns `true` if the archive exists in the cache.
-    pub(crate) fn exists(&self, cache: &Cache) -> bool {
+    pub fn exists(&self, cache: &Cache) -> bool {
         cache.archive(&self.id).exists()
     }
 }
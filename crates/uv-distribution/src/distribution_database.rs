@@ -1,12 +1,13 @@
 use std::future::Future;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::rc::Rc;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use futures::{FutureExt, TryStreamExt};
+use rustc_hash::FxHashMap;
 use tempfile::TempDir;
 use tokio::io::{AsyncRead, AsyncSeekExt, ReadBuf};
 use tokio::sync::Semaphore;
@@ -14,7 +15,7 @@ use tokio_util::compat::FuturesAsyncReadCompatExt;
 use tracing::{debug, info_span, instrument, warn, Instrument};
 use url::Url;
 
-use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
+use uv_cache::{ArchiveId, Cache, CacheBucket, CacheEntry, WheelCache};
 use uv_cache_info::{CacheInfo, Timestamp};
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
@@ -765,7 +766,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         let archive = pointer
             .filter(|pointer| pointer.is_up_to_date(modified))
             .map(LocalArchivePointer::into_archive)
-            .filter(|archive| archive.has_digests(hashes));
+            .filter(|archive| archive.has_digests(hashes))
+            .filter(|archive| archive.exists(self.build_context.cache()));
 
         // If the file is already unzipped, and the cache is up-to-date, return it.
         if let Some(archive) = archive {
@@ -1037,3 +1039,38 @@ impl LocalArchivePointer {
         CacheInfo::from_timestamp(self.timestamp)
     }
 }
+
+/// Return the `.http` and `.rev` wheel pointers in the cache, mapped to the paths of the archives
+/// that they reference.
+pub fn archive_pointers(cache: &Cache) -> Result<FxHashMap<PathBuf, PathBuf>, Error> {
+    let mut archives = FxHashMap::default();
+
+    let bucket = cache.bucket(CacheBucket::Wheels);
+    if bucket.is_dir() {
+        for entry in walkdir::WalkDir::new(bucket) {
+            let entry = entry.map_err(Error::CacheWalk)?;
+
+            if !entry.file_type().is_file() {
+                continue;
+            }
+
+            let archive = match entry.path().extension().and_then(|ext| ext.to_str()) {
+                Some("http") => HttpArchivePointer::read_from(entry.path())
+                    .ok()
+                    .flatten()
+                    .map(HttpArchivePointer::into_archive),
+                Some("rev") => LocalArchivePointer::read_from(entry.path())
+                    .ok()
+                    .flatten()
+                    .map(LocalArchivePointer::into_archive),
+                _ => None,
+            };
+
+            if let Some(archive) = archive {
+                archives.insert(entry.into_path(), cache.archive(&archive.id));
+            }
+        }
+    }
+
+    Ok(archives)
+}
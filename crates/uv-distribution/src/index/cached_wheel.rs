@@ -103,7 +103,13 @@ impl CachedWheel {
         // Read the pointer.
         let pointer = HttpArchivePointer::read_from(path).ok()??;
         let cache_info = pointer.to_cache_info();
-        let Archive { id, hashes } = pointer.into_archive();
+        let archive = pointer.into_archive();
+
+        // Ignore archives that have since been removed from the cache (e.g., by eviction).
+        if !archive.exists(cache) {
+            return None;
+        }
+        let Archive { id, hashes } = archive;
 
         let entry = cache.entry(CacheBucket::Archive, "", id);
 
@@ -127,7 +133,13 @@ impl CachedWheel {
         // Read the pointer.
         let pointer = LocalArchivePointer::read_from(path).ok()??;
         let cache_info = pointer.to_cache_info();
-        let Archive { id, hashes } = pointer.into_archive();
+        let archive = pointer.into_archive();
+
+        // Ignore archives that have since been removed from the cache (e.g., by eviction).
+        if !archive.exists(cache) {
+            return None;
+        }
+        let Archive { id, hashes } = archive;
 
         // Convert to a cached wheel.
         let entry = cache.entry(CacheBucket::Archive, "", id);
@@ -21,7 +21,7 @@ use tracing::{debug, info_span, instrument, warn, Instrument};
 use url::Url;
 use uv_cache::{Cache, CacheBucket, CacheEntry, CacheShard, Removal, WheelCache};
 use uv_cache_info::CacheInfo;
-use uv_cache_key::cache_digest;
+use uv_cache_key::{cache_digest, CanonicalUrl};
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
 };
@@ -41,6 +41,7 @@ use uv_types::{BuildContext, SourceBuildTrait};
 use zip::ZipArchive;
 
 mod built_wheel_metadata;
+mod remote;
 mod revision;
 
 /// Fetch and build a source distribution from a remote source, or from a local cache.
@@ -420,6 +421,36 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             return Ok(built_wheel.with_hashes(revision.into_hashes()));
         }
 
+        // If the source distribution is immutable (i.e., it's served by a registry, or its contents
+        // are pinned by hash), check the remote cache for a compatible wheel.
+        let metadata_entry = cache_shard.entry(METADATA);
+        let remote_entry = if matches!(source, BuildableSource::Dist(SourceDist::Registry(_)))
+            || !revision.hashes().is_empty()
+        {
+            let identity = std::iter::once(CanonicalUrl::new(url).to_string())
+                .chain(revision.hashes().iter().map(ToString::to_string))
+                .collect::<Vec<_>>()
+                .join("\n");
+            self.remote_entry(&identity, subdirectory, tags, client)
+        } else {
+            None
+        };
+        if let Some(remote_entry) = remote_entry.as_ref() {
+            if let Some(built_wheel) = self
+                .fetch_remote(
+                    source,
+                    remote_entry,
+                    &cache_shard,
+                    &metadata_entry,
+                    tags,
+                    client,
+                )
+                .await?
+            {
+                return Ok(built_wheel.with_hashes(revision.into_hashes()));
+            }
+        }
+
         // Otherwise, we need to build a wheel. Before building, ensure that the source is present.
         let revision = if source_dist_entry.path().is_dir() {
             revision
@@ -458,8 +489,19 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // Share the wheel via the remote cache.
+        if let Some(remote_entry) = remote_entry.as_ref() {
+            self.upload_remote(
+                source,
+                remote_entry,
+                &wheel_filename,
+                &cache_shard.join(&disk_filename),
+                client,
+            )
+            .await;
+        }
+
         // Store the metadata.
-        let metadata_entry = cache_shard.entry(METADATA);
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
             .await
             .map_err(Error::CacheWrite)?;
@@ -1275,6 +1317,29 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             return Ok(built_wheel);
         }
 
+        // Otherwise, check the remote cache for a wheel built from the same commit.
+        let identity = format!(
+            "{}@{}",
+            CanonicalUrl::new(resource.git.repository()),
+            git_sha
+        );
+        let remote_entry = self.remote_entry(&identity, resource.subdirectory, tags, client);
+        if let Some(remote_entry) = remote_entry.as_ref() {
+            if let Some(built_wheel) = self
+                .fetch_remote(
+                    source,
+                    remote_entry,
+                    &cache_shard,
+                    &metadata_entry,
+                    tags,
+                    client,
+                )
+                .await?
+            {
+                return Ok(built_wheel);
+            }
+        }
+
         let task = self
             .reporter
             .as_ref()
@@ -1296,6 +1361,18 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // Share the wheel via the remote cache.
+        if let Some(remote_entry) = remote_entry.as_ref() {
+            self.upload_remote(
+                source,
+                remote_entry,
+                &filename,
+                &cache_shard.join(&disk_filename),
+                client,
+            )
+            .await;
+        }
+
         // Store the metadata.
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
             .await
@@ -1806,6 +1883,122 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         Ok((disk_filename, filename, metadata))
     }
 
+    /// Return the URL of the entry in the remote cache for a wheel built from the source
+    /// distribution with the given identity, if a remote cache is configured.
+    fn remote_entry(
+        &self,
+        identity: &str,
+        subdirectory: Option<&Path>,
+        tags: &Tags,
+        client: &ManagedClient<'_>,
+    ) -> Option<Url> {
+        let remote = self.build_context.cache().remote()?;
+        if client.unmanaged.connectivity().is_offline() {
+            return None;
+        }
+        let key = remote::cache_key(
+            identity,
+            subdirectory,
+            self.build_context.config_settings(),
+            tags,
+        );
+        Some(remote.entry(&key))
+    }
+
+    /// Fetch a compatible wheel from the remote cache, storing it (and its metadata) in the local
+    /// cache.
+    ///
+    /// Failures to query the remote cache are not fatal; instead, the wheel is built locally. The
+    /// same applies to entries that are incompatible or fail digest verification.
+    async fn fetch_remote(
+        &self,
+        source: &BuildableSource<'_>,
+        remote_entry: &Url,
+        cache_shard: &CacheShard,
+        metadata_entry: &CacheEntry,
+        tags: &Tags,
+        client: &ManagedClient<'_>,
+    ) -> Result<Option<BuiltWheelMetadata>, Error> {
+        // Stream the wheel into the cache, verifying its digest along the way.
+        fs::create_dir_all(cache_shard)
+            .await
+            .map_err(Error::CacheWrite)?;
+        let download = client
+            .managed(|client| {
+                remote::download(
+                    remote_entry,
+                    cache_shard,
+                    tags,
+                    client.uncached_client(remote_entry),
+                )
+            })
+            .await;
+        let (filename, path) = match download {
+            Ok(Some(download)) => download,
+            Ok(None) => {
+                debug!("No wheel found in remote cache for: {source}");
+                return Ok(None);
+            }
+            Err(err) => {
+                warn!("Failed to fetch wheel from remote cache for `{source}`: {err}");
+                return Ok(None);
+            }
+        };
+
+        // Read and validate the metadata, discarding the wheel if it doesn't match the source.
+        let metadata = match read_wheel_metadata(&filename, &path)
+            .and_then(|metadata| validate(source, &metadata).map(|()| metadata))
+        {
+            Ok(metadata) => metadata,
+            Err(err) => {
+                warn!("Ignoring invalid wheel from remote cache for `{source}`: {err}");
+                fs::remove_file(&path).await.map_err(Error::CacheWrite)?;
+                return Ok(None);
+            }
+        };
+
+        // Store the metadata.
+        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        debug!("Using wheel from remote cache for: {source}");
+        Ok(Some(BuiltWheelMetadata {
+            path,
+            target: cache_shard.join(filename.stem()),
+            filename,
+            hashes: vec![],
+            cache_info: CacheInfo::default(),
+        }))
+    }
+
+    /// Upload a wheel built from a source distribution to the remote cache.
+    ///
+    /// Failures to upload are not fatal, since the wheel is already stored in the local cache.
+    async fn upload_remote(
+        &self,
+        source: &BuildableSource<'_>,
+        remote_entry: &Url,
+        filename: &WheelFilename,
+        wheel: &Path,
+        client: &ManagedClient<'_>,
+    ) {
+        debug!("Uploading wheel to remote cache for: {source}");
+        if let Err(err) = client
+            .managed(|client| {
+                remote::upload(
+                    remote_entry,
+                    filename,
+                    wheel,
+                    client.uncached_client(remote_entry),
+                )
+            })
+            .await
+        {
+            warn!("Failed to upload wheel to remote cache for `{source}`: {err}");
+        }
+    }
+
     /// Build the metadata for a source distribution.
     #[instrument(skip_all, fields(dist = %source))]
     async fn build_metadata(
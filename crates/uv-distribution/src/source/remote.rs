@@ -0,0 +1,173 @@
+//! Share wheels built from source distributions via a remote cache.
+//!
+//! Each entry in the remote cache contains a single wheel, preceded by a line containing the
+//! wheel's filename and its SHA-256 digest, such that the entry can be stored by any HTTP server
+//! that supports `GET` and `PUT`. The digest is verified on download, such that truncated or
+//! corrupted entries are discarded rather than installed.
+
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use futures::TryStreamExt;
+use reqwest::{Body, StatusCode};
+use reqwest_middleware::ClientWithMiddleware;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt};
+use tokio_util::compat::FuturesAsyncReadCompatExt;
+use tokio_util::io::ReaderStream;
+use url::Url;
+
+use uv_cache_key::cache_digest;
+use uv_configuration::ConfigSettings;
+use uv_distribution_filename::{WheelFilename, WheelFilenameError};
+use uv_extract::hash::Hasher;
+use uv_fs::PortablePath;
+use uv_platform_tags::Tags;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+/// The version of the remote cache entry format, included in every key.
+const VERSION: &str = "v1";
+
+/// The maximum length of the line that precedes the wheel in a remote cache entry.
+const MAX_HEADER_LENGTH: u64 = 1024;
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum RemoteCacheError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+    #[error(transparent)]
+    ReqwestMiddleware(#[from] reqwest_middleware::Error),
+    #[error("Remote cache entry does not start with a wheel filename and digest")]
+    MissingHeader,
+    #[error(transparent)]
+    WheelFilename(#[from] WheelFilenameError),
+    #[error("Remote cache entry contains an incompatible wheel: {0}")]
+    Incompatible(WheelFilename),
+    #[error("Hash mismatch for remote cache entry `{filename}`: expected `{expected}`, found `{actual}`")]
+    HashMismatch {
+        filename: WheelFilename,
+        expected: HashDigest,
+        actual: HashDigest,
+    },
+}
+
+/// Compute the key for a wheel in the remote cache.
+///
+/// The `identity` must uniquely identify the contents of the source distribution, e.g., a
+/// registry URL, an archive hash, or a Git commit. The key additionally accounts for the
+/// subdirectory, the build settings, and the tags of the target platform.
+pub(crate) fn cache_key(
+    identity: &str,
+    subdirectory: Option<&Path>,
+    config_settings: &ConfigSettings,
+    tags: &Tags,
+) -> String {
+    let subdirectory = subdirectory
+        .map(|subdirectory| PortablePath::from(subdirectory).to_string())
+        .unwrap_or_default();
+    let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+    for part in [
+        VERSION,
+        identity,
+        &subdirectory,
+        &cache_digest(config_settings),
+        &tags.to_string(),
+    ] {
+        hasher.update(part.as_bytes());
+        hasher.update(b"\0");
+    }
+    HashDigest::from(hasher).digest.into_string()
+}
+
+/// Download a wheel from the remote cache into the given directory, returning the filename of the
+/// wheel along with its path.
+///
+/// The wheel is streamed to disk and verified against the digest stored in the entry; it's only
+/// persisted if it's compatible with the given tags and the digest matches.
+///
+/// Returns `None` if the remote cache does not contain an entry at the given URL.
+pub(crate) async fn download(
+    url: &Url,
+    directory: &Path,
+    tags: &Tags,
+    client: &ClientWithMiddleware,
+) -> Result<Option<(WheelFilename, PathBuf)>, RemoteCacheError> {
+    let response = client.get(url.clone()).send().await?;
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    let reader = response
+        .error_for_status()?
+        .bytes_stream()
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))
+        .into_async_read();
+    let mut reader = tokio::io::BufReader::new(reader.compat());
+
+    // Read the filename and digest that precede the wheel.
+    let mut header = String::new();
+    (&mut reader)
+        .take(MAX_HEADER_LENGTH)
+        .read_line(&mut header)
+        .await?;
+    let (filename, expected) = header
+        .strip_suffix('\n')
+        .and_then(|header| header.split_once(' '))
+        .ok_or(RemoteCacheError::MissingHeader)?;
+    let filename = WheelFilename::from_str(filename)?;
+    let expected = HashDigest::from_str(expected).map_err(|_| RemoteCacheError::MissingHeader)?;
+    if !filename.is_compatible(tags) {
+        return Err(RemoteCacheError::Incompatible(filename));
+    }
+
+    // Stream the wheel to a temporary file, computing its digest along the way.
+    let temp_file = tempfile::NamedTempFile::new_in(directory)?;
+    let mut writer =
+        tokio::io::BufWriter::new(tokio::fs::File::from_std(temp_file.as_file().try_clone()?));
+    let mut hashers = vec![Hasher::from(expected.algorithm)];
+    let mut hasher = uv_extract::hash::HashReader::new(&mut reader, &mut hashers);
+    tokio::io::copy(&mut hasher, &mut writer).await?;
+    writer.flush().await?;
+    drop(writer);
+
+    let actual = HashDigest::from(hashers.remove(0));
+    if actual != expected {
+        return Err(RemoteCacheError::HashMismatch {
+            filename,
+            expected,
+            actual,
+        });
+    }
+
+    let path = directory.join(filename.to_string());
+    temp_file
+        .persist(&path)
+        .map_err(|err| RemoteCacheError::Io(err.error))?;
+
+    Ok(Some((filename, path)))
+}
+
+/// Upload a wheel to the remote cache, streaming it from disk.
+pub(crate) async fn upload(
+    url: &Url,
+    filename: &WheelFilename,
+    wheel: &Path,
+    client: &ClientWithMiddleware,
+) -> Result<(), RemoteCacheError> {
+    // Compute the digest of the wheel, to be stored alongside it.
+    let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+    let mut hasher =
+        uv_extract::hash::HashReader::new(fs_err::tokio::File::open(wheel).await?, &mut hashers);
+    hasher.finish().await?;
+    let digest = HashDigest::from(hashers.remove(0));
+
+    let header = std::io::Cursor::new(format!("{filename} {digest}\n").into_bytes());
+    let body = header.chain(fs_err::tokio::File::open(wheel).await?);
+    client
+        .put(url.clone())
+        .body(Body::wrap_stream(ReaderStream::new(body)))
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
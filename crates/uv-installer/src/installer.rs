@@ -2,7 +2,7 @@ use anyhow::{Context, Error, Result};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::convert;
 use tokio::sync::oneshot;
-use tracing::instrument;
+use tracing::{debug, instrument};
 use uv_install_wheel::{linker::LinkMode, Layout};
 
 use uv_cache::Cache;
@@ -99,9 +99,16 @@ impl<'a> Installer<'a> {
             let _ = tx.send(result);
         });
 
-        rx.await
+        let wheels = rx
+            .await
             .map_err(|_| anyhow::anyhow!("`install_blocking` task panicked"))
-            .and_then(convert::identity)
+            .and_then(convert::identity)?;
+
+        if let Some(cache) = cache {
+            record_access(cache, venv, link_mode, &wheels);
+        }
+
+        Ok(wheels)
     }
 
     /// Install a set of wheels into a Python virtual environment synchronously.
@@ -115,14 +122,39 @@ impl<'a> Installer<'a> {
             }
         }
 
-        install(
+        let wheels = install(
             wheels,
             self.venv.interpreter().layout(),
             self.installer_name,
             self.link_mode,
             self.reporter,
             self.venv.relocatable(),
-        )
+        )?;
+
+        if let Some(cache) = self.cache {
+            record_access(cache, self.venv, self.link_mode, &wheels);
+        }
+
+        Ok(wheels)
+    }
+}
+
+/// Record an access to the cached archive for each installed wheel, such that recently-used
+/// archives (and those symlinked into the environment) are retained when evicting from the cache.
+fn record_access(
+    cache: &Cache,
+    venv: &PythonEnvironment,
+    link_mode: LinkMode,
+    wheels: &[CachedDist],
+) {
+    let environment = link_mode.is_symlink().then(|| venv.root());
+    for wheel in wheels {
+        if let Err(err) = cache.record_access(wheel.path(), environment) {
+            debug!(
+                "Failed to record cache access for `{}`: {err}",
+                wheel.path().display()
+            );
+        }
     }
 }
 
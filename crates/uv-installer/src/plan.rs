@@ -167,7 +167,7 @@ impl<'a> Planner<'a> {
                     // Read the HTTP pointer.
                     if let Some(pointer) = HttpArchivePointer::read_from(&cache_entry)? {
                         let archive = pointer.into_archive();
-                        if archive.satisfies(hasher.get(dist)) {
+                        if archive.satisfies(hasher.get(dist)) && archive.exists(cache) {
                             let cached_dist = CachedDirectUrlDist::from_url(
                                 wheel.filename.clone(),
                                 wheel.url.clone(),
@@ -216,7 +216,7 @@ impl<'a> Planner<'a> {
                         if pointer.is_up_to_date(timestamp) {
                             let cache_info = pointer.to_cache_info();
                             let archive = pointer.into_archive();
-                            if archive.satisfies(hasher.get(dist)) {
+                            if archive.satisfies(hasher.get(dist)) && archive.exists(cache) {
                                 let cached_dist = CachedDirectUrlDist::from_url(
                                     wheel.filename.clone(),
                                     wheel.url.clone(),
@@ -1214,6 +1214,34 @@ impl MarkerTree {
             }
         }))
     }
+
+    /// Remove negated extras from a marker.
+    ///
+    /// Any `extra` markers that are always `false` given the provided predicate will be removed.
+    /// Any `extra` markers that are always `true` given the provided predicate will be left
+    /// unchanged.
+    ///
+    /// For example, if `is_not_extra('dev')` is true, given
+    /// `sys_platform == 'linux' and extra != 'dev'`, the marker will be simplified to
+    /// `sys_platform == 'linux'`.
+    #[must_use]
+    pub fn simplify_not_extras_with(self, is_not_extra: impl Fn(&ExtraName) -> bool) -> MarkerTree {
+        self.simplify_not_extras_with_impl(&is_not_extra)
+    }
+
+    fn simplify_not_extras_with_impl(
+        self,
+        is_not_extra: &impl Fn(&ExtraName) -> bool,
+    ) -> MarkerTree {
+        MarkerTree(INTERNER.lock().restrict(self.0, &|var| {
+            match var {
+                Variable::Extra(name) => name
+                    .as_extra()
+                    .and_then(|name| is_not_extra(name).then_some(false)),
+                _ => None,
+            }
+        }))
+    }
 }
 
 impl fmt::Debug for MarkerTree {
@@ -2202,6 +2230,29 @@ mod test {
         assert_eq!(simplified, expected);
     }
 
+    #[test]
+    fn test_simplify_not_extras() {
+        let dev = ExtraName::from_str("dev").unwrap();
+
+        // Given `os_name == "nt" and extra != "dev"`, simplify to `os_name == "nt"`.
+        let markers = MarkerTree::from_str(r#"os_name == "nt" and extra != "dev""#).unwrap();
+        let simplified = markers.simplify_not_extras_with(|name| *name == dev);
+        let expected = MarkerTree::from_str(r#"os_name == "nt""#).unwrap();
+        assert_eq!(simplified, expected);
+
+        // Given `os_name == "nt" and extra == "dev"`, the marker can never be satisfied.
+        let markers = MarkerTree::from_str(r#"os_name == "nt" and extra == "dev""#).unwrap();
+        let simplified = markers.simplify_not_extras_with(|name| *name == dev);
+        assert_eq!(simplified, MarkerTree::FALSE);
+
+        // Given `os_name == "nt" and extra != "test"`, don't simplify.
+        let markers = MarkerTree::from_str(r#"os_name == "nt" and extra != "test""#).unwrap();
+        let simplified = markers
+            .clone()
+            .simplify_not_extras_with(|name| *name == dev);
+        assert_eq!(simplified, markers);
+    }
+
     #[test]
     fn test_marker_simplification() {
         assert_false("python_version == '3.9.1'");
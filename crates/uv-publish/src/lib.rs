@@ -22,7 +22,9 @@ use tokio::io::{AsyncReadExt, BufReader};
 use tokio_util::io::ReaderStream;
 use tracing::{debug, enabled, trace, Level};
 use url::Url;
-use uv_client::{BaseClient, OwnedArchive, RegistryClientBuilder, UvRetryableStrategy};
+use uv_client::{
+    BaseClient, MetadataFormat, OwnedArchive, RegistryClientBuilder, UvRetryableStrategy,
+};
 use uv_configuration::{KeyringProviderType, TrustedPublishing};
 use uv_distribution_filename::{DistFilename, SourceDistExtension, SourceDistFilename};
 use uv_fs::{ProgressReader, Simplified};
@@ -451,9 +453,13 @@ pub async fn check_url(
         .simple(filename.name(), Some(index_url), index_capabilities)
         .await
         .map_err(PublishError::CheckUrlIndex)?;
-    let [(_, simple_metadata)] = response.as_slice() else {
+    let [(_, metadata)] = response.as_slice() else {
         unreachable!("We queried a single index, we must get a single response");
     };
+    let MetadataFormat::Simple(simple_metadata) = metadata else {
+        // Flat indexes don't support uploads, so there's nothing to compare against.
+        return Ok(false);
+    };
     let simple_metadata = OwnedArchive::deserialize(simple_metadata);
     let Some(metadatum) = simple_metadata
         .iter()
@@ -1,13 +1,17 @@
 use futures::TryStreamExt;
 use owo_colors::OwoColorize;
+use serde::Deserialize;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::io;
 use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::str::FromStr;
+use std::sync::OnceLock;
 use std::task::{Context, Poll};
 use thiserror::Error;
-use tokio::io::{AsyncRead, ReadBuf};
+use tokio::io::{AsyncRead, AsyncReadExt, ReadBuf};
 use tokio_util::compat::FuturesAsyncReadCompatExt;
 use tokio_util::either::Either;
 use tracing::{debug, instrument};
@@ -25,7 +29,7 @@ use crate::implementation::{
 use crate::installation::PythonInstallationKey;
 use crate::libc::LibcDetectionError;
 use crate::platform::{self, Arch, Libc, Os};
-use crate::{Interpreter, PythonRequest, PythonVersion, VersionRequest};
+use crate::{Interpreter, PythonRequest, PythonVariant, PythonVersion, VersionRequest};
 
 #[derive(Error, Debug)]
 pub enum Error {
@@ -81,9 +85,13 @@ pub enum Error {
     Mirror(&'static str, &'static str),
     #[error(transparent)]
     LibcDetection(#[from] LibcDetectionError),
+    #[error("Failed to parse Python downloads from: {0}")]
+    InvalidDownloadsJson(String, #[source] serde_json::Error),
+    #[error("Invalid Python download `{0}`: {1}")]
+    InvalidDownloadsJsonEntry(String, String),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ManagedPythonDownload {
     key: PythonInstallationKey,
     url: &'static str,
@@ -433,15 +441,115 @@ impl ManagedPythonDownload {
             .ok_or(Error::NoDownloadFound(request.clone()))
     }
 
+    /// Return the latest [`ManagedPythonDownload`] that is an upgrade of the given installation,
+    /// i.e., a newer patch version of the same minor version for the same platform, if any.
+    pub fn find_upgrade(key: &PythonInstallationKey) -> Option<&'static ManagedPythonDownload> {
+        Self::iter_all().find(|download| {
+            download.key.os == key.os
+                && download.key.arch == key.arch
+                && download.key.libc == key.libc
+                && download.key.is_upgrade_of(key)
+        })
+    }
+
     /// Iterate over all [`ManagedPythonDownload`]s.
     pub fn iter_all() -> impl Iterator<Item = &'static ManagedPythonDownload> {
-        PYTHON_DOWNLOADS
+        CUSTOM_PYTHON_DOWNLOADS
+            .get()
+            .map_or(PYTHON_DOWNLOADS, Vec::as_slice)
             .iter()
             // TODO(konsti): musl python-build-standalone builds are currently broken (statically
             // linked), so we pretend they don't exist. https://github.com/astral-sh/uv/issues/4242
             .filter(|download| download.key.libc != Libc::Some(target_lexicon::Environment::Musl))
     }
 
+    /// Load the available [`ManagedPythonDownload`]s from the manifest provided via
+    /// `UV_PYTHON_DOWNLOADS_JSON_URL`, if any.
+    ///
+    /// Must be called before any downloads are queried. The manifest is only loaded once per
+    /// process; subsequent calls have no effect.
+    pub async fn load_custom_from_env(client: &uv_client::BaseClient) -> Result<(), Error> {
+        if CUSTOM_PYTHON_DOWNLOADS.get().is_some() {
+            return Ok(());
+        }
+        let Some(source) = std::env::var(EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL)
+            .ok()
+            .filter(|source| !source.is_empty())
+        else {
+            return Ok(());
+        };
+        let replace = std::env::var(EnvVars::UV_PYTHON_DOWNLOADS_JSON_REPLACE)
+            .is_ok_and(|value| matches!(value.to_ascii_lowercase().as_str(), "1" | "true"));
+        Self::load_custom(&source, replace, client).await
+    }
+
+    /// Load the available [`ManagedPythonDownload`]s from a JSON manifest at the given path or
+    /// URL, in the same format as the built-in `download-metadata.json`.
+    ///
+    /// Unless `replace` is set, the downloads are merged with the built-in downloads, taking
+    /// precedence over any built-in download with the same key.
+    ///
+    /// Must be called before any downloads are queried, as the set of downloads is fixed for the
+    /// duration of the process.
+    pub async fn load_custom(
+        source: &str,
+        replace: bool,
+        client: &uv_client::BaseClient,
+    ) -> Result<(), Error> {
+        let url = match Url::parse(source) {
+            Ok(url) if matches!(url.scheme(), "http" | "https" | "file") => url,
+            _ => Url::from_file_path(std::path::absolute(source)?)
+                .map_err(|()| Error::InvalidFileUrl(source.to_string()))?,
+        };
+
+        let (mut reader, size) = read_url(&url, client).await?;
+        let mut contents = Vec::with_capacity(
+            size.and_then(|size| usize::try_from(size).ok())
+                .unwrap_or_default(),
+        );
+        reader.read_to_end(&mut contents).await?;
+
+        let manifest: BTreeMap<String, JsonPythonDownload> = serde_json::from_slice(&contents)
+            .map_err(|err| Error::InvalidDownloadsJson(source.to_string(), err))?;
+        let mut downloads = manifest
+            .into_iter()
+            .map(|(name, download)| download.into_download(&name))
+            .collect::<Result<Vec<_>, _>>()?;
+        debug!("Loaded {} Python downloads from: {source}", downloads.len());
+
+        if !replace {
+            let builtin = PYTHON_DOWNLOADS
+                .iter()
+                .filter(|builtin| !downloads.iter().any(|download| download.key == builtin.key))
+                .cloned()
+                .collect::<Vec<_>>();
+            downloads.extend(builtin);
+        }
+
+        // Order the downloads as in the built-in downloads, such that the first matching download
+        // is the latest. The sort is stable, so custom downloads precede built-in downloads with
+        // the same implementation, version, and variant.
+        downloads.sort_by_cached_key(|download| {
+            let implementation = match download.key.implementation() {
+                LenientImplementationName::Known(ImplementationName::CPython) => 0,
+                LenientImplementationName::Known(ImplementationName::PyPy) => 1,
+                LenientImplementationName::Known(ImplementationName::GraalPy) => 2,
+                LenientImplementationName::Unknown(_) => 3,
+            };
+            (
+                implementation,
+                Reverse(download.key.version().version.clone()),
+                download.key.variant,
+            )
+        });
+
+        if CUSTOM_PYTHON_DOWNLOADS.set(downloads).is_err() {
+            debug!("Python downloads were already loaded; ignoring: {source}");
+        }
+
+        Ok(())
+    }
+
     pub fn url(&self) -> &str {
         self.url
     }
@@ -642,6 +750,71 @@ impl Display for ManagedPythonDownload {
     }
 }
 
+/// The Python downloads loaded via [`ManagedPythonDownload::load_custom`], which are used in lieu of
+/// the built-in [`PYTHON_DOWNLOADS`], if any.
+static CUSTOM_PYTHON_DOWNLOADS: OnceLock<Vec<ManagedPythonDownload>> = OnceLock::new();
+
+/// A Python download, as represented in `download-metadata.json`.
+#[derive(Debug, Deserialize)]
+struct JsonPythonDownload {
+    name: String,
+    arch: String,
+    os: String,
+    libc: String,
+    major: u8,
+    minor: u8,
+    patch: u8,
+    prerelease: Option<String>,
+    url: String,
+    sha256: Option<String>,
+    variant: Option<String>,
+}
+
+impl JsonPythonDownload {
+    /// Convert the JSON representation into a [`ManagedPythonDownload`].
+    fn into_download(self, name: &str) -> Result<ManagedPythonDownload, Error> {
+        let invalid = |reason: String| Error::InvalidDownloadsJsonEntry(name.to_string(), reason);
+
+        let implementation = ImplementationName::from_str(&self.name)
+            .map_err(|err| invalid(format!("invalid implementation: {err}")))?;
+        let arch = Arch::from_str(&self.arch)
+            .map_err(|err| invalid(format!("invalid architecture: {err}")))?;
+        let os = Os::from_str(&self.os).map_err(|err| invalid(format!("invalid OS: {err}")))?;
+        let libc =
+            Libc::from_str(&self.libc).map_err(|err| invalid(format!("invalid libc: {err}")))?;
+        let variant = PythonVariant::from_str(self.variant.as_deref().unwrap_or_default())
+            .map_err(|()| {
+                invalid(format!(
+                    "invalid Python variant: {}",
+                    self.variant.unwrap_or_default()
+                ))
+            })?;
+        let version = PythonVersion::from_str(&format!(
+            "{}.{}.{}{}",
+            self.major,
+            self.minor,
+            self.patch,
+            self.prerelease.unwrap_or_default()
+        ))
+        .map_err(|err| invalid(format!("invalid Python version: {err}")))?;
+
+        // The downloads are retained for the duration of the process, so we leak the strings to
+        // match the `'static` lifetime of the built-in downloads.
+        Ok(ManagedPythonDownload {
+            key: PythonInstallationKey::new_from_version(
+                LenientImplementationName::Known(implementation),
+                &version,
+                os,
+                arch,
+                libc,
+                variant,
+            ),
+            url: self.url.leak(),
+            sha256: self.sha256.map(|sha256| &*sha256.leak()),
+        })
+    }
+}
+
 pub trait Reporter: Send + Sync {
     fn on_progress(&self, name: &PythonInstallationKey, id: usize);
     fn on_download_start(&self, name: &PythonInstallationKey, size: Option<u64>) -> usize;
@@ -127,8 +127,9 @@ impl PythonInstallation {
         let cache_dir = installations.cache();
         let _lock = installations.lock().await?;
 
-        let download = ManagedPythonDownload::from_request(&request)?;
         let client = client_builder.build();
+        ManagedPythonDownload::load_custom_from_env(&client).await?;
+        let download = ManagedPythonDownload::from_request(&request)?;
 
         info!("Fetching requested Python...");
         let result = download
@@ -316,6 +317,35 @@ impl PythonInstallationKey {
             exe = std::env::consts::EXE_SUFFIX
         )
     }
+
+    /// Returns `true` if self is a suitable upgrade of other.
+    pub fn is_upgrade_of(&self, other: &PythonInstallationKey) -> bool {
+        // Require matching implementation
+        if self.implementation != other.implementation {
+            return false;
+        }
+        // Require a matching variant
+        if self.variant != other.variant {
+            return false;
+        }
+        // Require matching minor version
+        if (self.major, self.minor) != (other.major, other.minor) {
+            return false;
+        }
+        // Require a newer, or equal patch version (for pre-release upgrades)
+        if self.patch <= other.patch {
+            return false;
+        }
+        if let Some(other_pre) = other.prerelease {
+            if let Some(self_pre) = self.prerelease {
+                return self_pre > other_pre;
+            }
+            // Do not upgrade from non-prerelease to prerelease
+            return false;
+        }
+        // Do not upgrade if the patch versions are the same
+        self.patch != other.patch
+    }
 }
 
 impl fmt::Display for PythonInstallationKey {
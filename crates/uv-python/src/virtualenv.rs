@@ -34,6 +34,12 @@ pub struct PyVenvConfiguration {
     pub(crate) relocatable: bool,
     /// Was the virtual environment populated with seed packages?
     pub(crate) seed: bool,
+    /// Does the virtual environment have access to the system `site-packages` directory?
+    pub(crate) include_system_site_packages: bool,
+    /// The directory containing the base Python executable, i.e., the `home` key.
+    pub(crate) home: Option<PathBuf>,
+    /// The prompt to display when the virtual environment is activated.
+    pub(crate) prompt: Option<String>,
 }
 
 #[derive(Debug, Error)]
@@ -185,6 +191,9 @@ impl PyVenvConfiguration {
         let mut uv = false;
         let mut relocatable = false;
         let mut seed = false;
+        let mut include_system_site_packages = false;
+        let mut home = None;
+        let mut prompt = None;
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
@@ -208,6 +217,15 @@ impl PyVenvConfiguration {
                 "seed" => {
                     seed = value.trim().to_lowercase() == "true";
                 }
+                "include-system-site-packages" => {
+                    include_system_site_packages = value.trim().to_lowercase() == "true";
+                }
+                "home" => {
+                    home = Some(PathBuf::from(value.trim()));
+                }
+                "prompt" => {
+                    prompt = Some(value.trim().to_string());
+                }
                 _ => {}
             }
         }
@@ -217,6 +235,9 @@ impl PyVenvConfiguration {
             uv,
             relocatable,
             seed,
+            include_system_site_packages,
+            home,
+            prompt,
         })
     }
 
@@ -239,4 +260,19 @@ impl PyVenvConfiguration {
     pub fn is_seed(&self) -> bool {
         self.seed
     }
+
+    /// Returns true if the virtual environment has access to the system `site-packages`.
+    pub fn include_system_site_packages(&self) -> bool {
+        self.include_system_site_packages
+    }
+
+    /// Returns the directory containing the base Python executable, if known.
+    pub fn home(&self) -> Option<&Path> {
+        self.home.as_deref()
+    }
+
+    /// Returns the prompt to display when the virtual environment is activated, if any.
+    pub fn prompt(&self) -> Option<&str> {
+        self.prompt.as_deref()
+    }
 }
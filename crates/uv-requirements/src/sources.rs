@@ -21,6 +21,8 @@ pub enum RequirementsSource {
     SetupCfg(PathBuf),
     /// Dependencies were provided via a path to a source tree (e.g., `pip install .`).
     SourceTree(PathBuf),
+    /// Dependencies were provided via a `uv.lock` file (e.g., `pip sync uv.lock`).
+    UvLock(PathBuf),
 }
 
 impl RequirementsSource {
@@ -33,6 +35,8 @@ impl RequirementsSource {
             Self::SetupPy(path)
         } else if path.ends_with("setup.cfg") {
             Self::SetupCfg(path)
+        } else if path.ends_with("uv.lock") {
+            Self::UvLock(path)
         } else {
             Self::RequirementsTxt(path)
         }
@@ -174,7 +178,7 @@ impl RequirementsSource {
     pub fn allows_extras(&self) -> bool {
         matches!(
             self,
-            Self::PyprojectToml(_) | Self::SetupPy(_) | Self::SetupCfg(_)
+            Self::PyprojectToml(_) | Self::SetupPy(_) | Self::SetupCfg(_) | Self::UvLock(_)
         )
     }
 }
@@ -188,7 +192,8 @@ impl std::fmt::Display for RequirementsSource {
             | Self::PyprojectToml(path)
             | Self::SetupPy(path)
             | Self::SetupCfg(path)
-            | Self::SourceTree(path) => {
+            | Self::SourceTree(path)
+            | Self::UvLock(path) => {
                 write!(f, "{}", path.simplified_display())
             }
         }
@@ -202,6 +202,12 @@ impl RequirementsSpecification {
                     ..Self::default()
                 }
             }
+            RequirementsSource::UvLock(path) => {
+                return Err(anyhow::anyhow!(
+                    "Installing from a `uv.lock` is only supported by `uv pip sync` and `uv pip install`, and can't be combined with other requirements: `{}`",
+                    path.user_display()
+                ));
+            }
         })
     }
 
@@ -0,0 +1,352 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use url::Url;
+
+use uv_configuration::{DevGroupsManifest, ExtrasSpecification, InstallOptions};
+use uv_fs::normalize_path;
+use uv_normalize::PackageName;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_workspace::pyproject::License;
+
+use crate::lock::export::{ExportableRequirement, ExportableRequirements};
+use crate::lock::{Package, PackageId, RegistrySource, Source, WheelWireSource};
+use crate::{InstallTarget, LockError};
+
+/// The version of the CycloneDX specification that we write.
+const CYCLONEDX_SPEC_VERSION: &str = "1.5";
+
+/// The default index, which is omitted from the package URLs.
+const PYPI_SIMPLE_URL: &str = "https://pypi.org/simple";
+
+/// An export of a [`Lock`](crate::Lock) that renders as a
+/// [CycloneDX](https://cyclonedx.org/specification/overview/) Software Bill of Materials, in JSON.
+#[derive(Debug)]
+pub struct CycloneDxExport<'lock> {
+    nodes: Vec<ExportableRequirement<'lock>>,
+    /// The root against which the paths in the lockfile are resolved.
+    root: &'lock Path,
+    licenses: FxHashMap<&'lock PackageName, &'lock License>,
+}
+
+impl<'lock> CycloneDxExport<'lock> {
+    pub fn from_lock(
+        target: InstallTarget<'lock>,
+        extras: &ExtrasSpecification,
+        dev: &DevGroupsManifest,
+        install_options: &'lock InstallOptions,
+    ) -> Result<Self, LockError> {
+        let mut nodes =
+            ExportableRequirements::from_lock(target, extras, dev, install_options).into_inner();
+
+        // Sort the components by package name, then by version.
+        nodes.sort_unstable_by(|a, b| a.package.id.cmp(&b.package.id));
+
+        // The lockfile doesn't track license metadata, but the workspace members declare theirs
+        // in the `pyproject.toml`.
+        let licenses = target
+            .workspace()
+            .into_iter()
+            .flat_map(|workspace| workspace.packages())
+            .filter_map(|(name, member)| {
+                let license = member.pyproject_toml().project.as_ref()?.license.as_ref()?;
+                Some((name, license))
+            })
+            .collect();
+
+        Ok(Self {
+            nodes,
+            root: target.install_path(),
+            licenses,
+        })
+    }
+
+    /// Returns the JSON representation of this export.
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        let references = self
+            .nodes
+            .iter()
+            .map(|node| (&node.package.id, purl(node.package, self.root)))
+            .collect::<FxHashMap<_, _>>();
+
+        let components = self
+            .nodes
+            .iter()
+            .filter(|node| !matches!(node.package.id.source, Source::Virtual(_)))
+            .map(|node| self.component(node.package, &references))
+            .collect();
+
+        let dependencies = self
+            .nodes
+            .iter()
+            .filter(|node| !matches!(node.package.id.source, Source::Virtual(_)))
+            .map(|node| Dependency {
+                reference: references[&node.package.id].clone(),
+                depends_on: node
+                    .dependencies
+                    .iter()
+                    .filter(|id| !matches!(id.source, Source::Virtual(_)))
+                    .map(|id| references[id].clone())
+                    .collect(),
+            })
+            .collect();
+
+        let bom = Bom {
+            bom_format: "CycloneDX",
+            spec_version: CYCLONEDX_SPEC_VERSION,
+            version: 1,
+            metadata: Metadata {
+                tools: Tools {
+                    components: vec![Tool {
+                        kind: "application",
+                        name: "uv",
+                    }],
+                },
+            },
+            components,
+            dependencies,
+        };
+
+        let mut json = serde_json::to_string_pretty(&bom)?;
+        json.push('\n');
+        Ok(json)
+    }
+
+    /// Returns the CycloneDX component for a [`Package`].
+    fn component(
+        &self,
+        package: &Package,
+        references: &FxHashMap<&PackageId, String>,
+    ) -> Component {
+        let mut hashes = Vec::new();
+        let mut external_references = Vec::new();
+        match &package.id.source {
+            Source::Registry(_) => {
+                if let Some(sdist) = &package.sdist {
+                    if let Some(url) = sdist.url() {
+                        external_references.push(ExternalReference {
+                            kind: "distribution",
+                            url: url.to_string(),
+                            hashes: sdist
+                                .hash()
+                                .map(|hash| ComponentHash::from(&hash.0))
+                                .into_iter()
+                                .collect(),
+                        });
+                    }
+                }
+                for wheel in &package.wheels {
+                    if let WheelWireSource::Url { url } = &wheel.url {
+                        external_references.push(ExternalReference {
+                            kind: "distribution",
+                            url: url.to_string(),
+                            hashes: wheel
+                                .hash
+                                .as_ref()
+                                .map(|hash| ComponentHash::from(&hash.0))
+                                .into_iter()
+                                .collect(),
+                        });
+                    }
+                }
+            }
+            Source::Git(url, _) => {
+                let mut url = url.to_url();
+                url.set_fragment(None);
+                url.set_query(None);
+                external_references.push(ExternalReference {
+                    kind: "vcs",
+                    url: url.to_string(),
+                    hashes: Vec::new(),
+                });
+            }
+            Source::Direct(url, _) => {
+                hashes = package.hashes().iter().map(ComponentHash::from).collect();
+                external_references.push(ExternalReference {
+                    kind: "distribution",
+                    url: url.to_string(),
+                    hashes: Vec::new(),
+                });
+            }
+            Source::Path(_) => {
+                hashes = package.hashes().iter().map(ComponentHash::from).collect();
+            }
+            Source::Directory(_) | Source::Editable(_) | Source::Virtual(_) => {}
+        }
+
+        let licenses = match self.licenses.get(&package.id.name) {
+            Some(License::Spdx(expression)) => vec![LicenseChoice::Expression {
+                expression: expression.clone(),
+            }],
+            Some(License::Text { text }) => vec![LicenseChoice::License {
+                license: LicenseName { name: text.clone() },
+            }],
+            Some(License::File { .. }) | None => Vec::new(),
+        };
+
+        Component {
+            kind: "library",
+            reference: references[&package.id].clone(),
+            name: package.id.name.to_string(),
+            version: package.id.version.to_string(),
+            purl: references[&package.id].clone(),
+            hashes,
+            licenses,
+            external_references,
+        }
+    }
+}
+
+/// Returns the [package URL](https://github.com/package-url/purl-spec) for a [`Package`], which
+/// also serves as its `bom-ref`.
+///
+/// Local packages include their `file://` URL, to distinguish them from a release with the same
+/// name and version on the index.
+fn purl(package: &Package, root: &Path) -> String {
+    let mut purl = format!(
+        "pkg:pypi/{}@{}",
+        package.id.name,
+        percent_encode(&package.id.version.to_string())
+    );
+    match &package.id.source {
+        Source::Registry(RegistrySource::Url(url)) if url.as_ref() != PYPI_SIMPLE_URL => {
+            write!(purl, "?repository_url={}", percent_encode(url.as_ref())).unwrap();
+        }
+        Source::Git(url, git) => {
+            let mut url = url.to_url();
+            url.set_fragment(None);
+            url.set_query(None);
+            let vcs_url = format!("git+{url}@{}", git.precise);
+            write!(purl, "?vcs_url={}", percent_encode(&vcs_url)).unwrap();
+        }
+        Source::Direct(url, _) => {
+            write!(purl, "?download_url={}", percent_encode(url.as_ref())).unwrap();
+        }
+        Source::Registry(RegistrySource::Path(path)) => {
+            if let Ok(url) = Url::from_file_path(normalize_path(&root.join(path))) {
+                write!(purl, "?repository_url={}", percent_encode(url.as_ref())).unwrap();
+            }
+        }
+        Source::Path(path)
+        | Source::Directory(path)
+        | Source::Editable(path)
+        | Source::Virtual(path) => {
+            if let Ok(url) = Url::from_file_path(normalize_path(&root.join(path))) {
+                write!(purl, "?download_url={}", percent_encode(url.as_ref())).unwrap();
+            }
+        }
+        Source::Registry(RegistrySource::Url(_)) => {}
+    }
+    purl
+}
+
+/// Percent-encode a package URL component, preserving only the unreserved characters.
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            write!(encoded, "%{byte:02X}").unwrap();
+        }
+    }
+    encoded
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Bom {
+    bom_format: &'static str,
+    spec_version: &'static str,
+    version: u32,
+    metadata: Metadata,
+    components: Vec<Component>,
+    dependencies: Vec<Dependency>,
+}
+
+#[derive(Debug, Serialize)]
+struct Metadata {
+    tools: Tools,
+}
+
+#[derive(Debug, Serialize)]
+struct Tools {
+    components: Vec<Tool>,
+}
+
+#[derive(Debug, Serialize)]
+struct Tool {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    name: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Component {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    #[serde(rename = "bom-ref")]
+    reference: String,
+    name: String,
+    version: String,
+    purl: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    hashes: Vec<ComponentHash>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    licenses: Vec<LicenseChoice>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    external_references: Vec<ExternalReference>,
+}
+
+#[derive(Debug, Serialize)]
+struct ComponentHash {
+    alg: &'static str,
+    content: String,
+}
+
+impl From<&HashDigest> for ComponentHash {
+    fn from(digest: &HashDigest) -> Self {
+        let alg = match digest.algorithm {
+            HashAlgorithm::Md5 => "MD5",
+            HashAlgorithm::Sha256 => "SHA-256",
+            HashAlgorithm::Sha384 => "SHA-384",
+            HashAlgorithm::Sha512 => "SHA-512",
+        };
+        Self {
+            alg,
+            content: digest.digest.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum LicenseChoice {
+    Expression { expression: String },
+    License { license: LicenseName },
+}
+
+#[derive(Debug, Serialize)]
+struct LicenseName {
+    name: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ExternalReference {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    url: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    hashes: Vec<ComponentHash>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Dependency {
+    #[serde(rename = "ref")]
+    reference: String,
+    depends_on: Vec<String>,
+}
@@ -0,0 +1,358 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use serde::Serialize;
+
+use uv_normalize::PackageName;
+use uv_pep508::MarkerTree;
+
+use crate::lock::{Lock, Package};
+use crate::requires_python::SimplifiedMarkerTree;
+use crate::RequiresPython;
+
+/// The changes between two versions of a [`Lock`].
+///
+/// Packages are matched by name. Packages of the same name and version are compared in full
+/// (source, distributions, hashes, and resolution markers); if a package is locked at a single
+/// version before and after, the two are compared as a version change.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LockDiff {
+    /// The change to the supported Python versions, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires_python: Option<Change<String>>,
+    /// The changes to the resolution markers of the lockfile.
+    #[serde(skip_serializing_if = "MarkerChanges::is_empty")]
+    resolution_markers: MarkerChanges,
+    /// The packages that were added to the lockfile.
+    added: Vec<PackageSummary>,
+    /// The packages that were removed from the lockfile.
+    removed: Vec<PackageSummary>,
+    /// The packages that were present in both lockfiles, but changed.
+    changed: Vec<PackageChange>,
+}
+
+impl LockDiff {
+    /// Compute the changes from an existing lockfile (if any) to a new lockfile.
+    pub fn new(previous: Option<&Lock>, lock: &Lock) -> Self {
+        let mut diff = Self::default();
+
+        if let Some(previous) = previous {
+            if previous.requires_python != lock.requires_python {
+                diff.requires_python = Some(Change {
+                    from: previous.requires_python.to_string(),
+                    to: lock.requires_python.to_string(),
+                });
+            }
+        }
+
+        diff.resolution_markers = MarkerChanges::new(
+            previous.map(|previous| (&previous.requires_python, previous.fork_markers.as_slice())),
+            (&lock.requires_python, lock.fork_markers.as_slice()),
+        );
+
+        // Group the packages in each lockfile by name.
+        let mut packages: BTreeMap<&PackageName, (Vec<&Package>, Vec<&Package>)> = BTreeMap::new();
+        for package in previous.into_iter().flat_map(Lock::packages) {
+            packages
+                .entry(&package.id.name)
+                .or_default()
+                .0
+                .push(package);
+        }
+        for package in lock.packages() {
+            packages
+                .entry(&package.id.name)
+                .or_default()
+                .1
+                .push(package);
+        }
+
+        for (before, mut after) in packages.into_values() {
+            let mut unmatched = Vec::new();
+
+            // Match packages at the same version.
+            for old in before {
+                if let Some(index) = after
+                    .iter()
+                    .position(|new| new.id.version == old.id.version)
+                {
+                    let new = after.remove(index);
+                    diff.compare(previous, lock, old, new);
+                } else {
+                    unmatched.push(old);
+                }
+            }
+
+            // If a single version was locked before and after, treat it as a version change.
+            if let ([old], [new]) = (unmatched.as_slice(), after.as_slice()) {
+                diff.compare(previous, lock, old, new);
+                continue;
+            }
+
+            diff.removed
+                .extend(unmatched.into_iter().map(PackageSummary::from));
+            diff.added
+                .extend(after.into_iter().map(PackageSummary::from));
+        }
+
+        diff
+    }
+
+    /// Compare a package from the previous lockfile to its counterpart in the new lockfile.
+    fn compare(&mut self, previous: Option<&Lock>, lock: &Lock, old: &Package, new: &Package) {
+        let mut change = PackageChange {
+            name: new.id.name.clone(),
+            version: Change {
+                from: old.id.version.to_string(),
+                to: new.id.version.to_string(),
+            },
+            source: None,
+            added_distributions: Vec::new(),
+            removed_distributions: Vec::new(),
+            changed_hashes: Vec::new(),
+            resolution_markers: MarkerChanges::new(
+                previous.map(|previous| (&previous.requires_python, old.fork_markers.as_slice())),
+                (&lock.requires_python, new.fork_markers.as_slice()),
+            ),
+        };
+
+        let source = (old.id.source.to_string(), new.id.source.to_string());
+        if source.0 != source.1 {
+            change.source = Some(Change {
+                from: source.0,
+                to: source.1,
+            });
+        }
+
+        // The distributions of different versions are necessarily different, so we only compare
+        // them for a package at the same version.
+        if old.id.version == new.id.version {
+            let before = distributions(old);
+            let after = distributions(new);
+            for (filename, hash) in &before {
+                match after.get(filename) {
+                    None => change.removed_distributions.push(filename.clone()),
+                    Some(new_hash) if new_hash != hash => {
+                        change.changed_hashes.push(HashChange {
+                            filename: filename.clone(),
+                            from: hash.clone(),
+                            to: new_hash.clone(),
+                        });
+                    }
+                    Some(_) => {}
+                }
+            }
+            for filename in after.keys() {
+                if !before.contains_key(filename) {
+                    change.added_distributions.push(filename.clone());
+                }
+            }
+        }
+
+        if !change.is_empty() {
+            self.changed.push(change);
+        }
+    }
+
+    /// Returns `true` if the lockfiles are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.requires_python.is_none()
+            && self.resolution_markers.is_empty()
+            && self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+    }
+
+    /// Returns the JSON representation of the changes.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        let mut json = serde_json::to_string_pretty(self)?;
+        json.push('\n');
+        Ok(json)
+    }
+}
+
+impl Display for LockDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(Change { from, to }) = &self.requires_python {
+            writeln!(f, "~ requires-python: {from} -> {to}")?;
+        }
+        self.resolution_markers.fmt_with_indent(f, "")?;
+        for package in &self.added {
+            writeln!(
+                f,
+                "+ {} v{} ({})",
+                package.name, package.version, package.source
+            )?;
+        }
+        for package in &self.removed {
+            writeln!(
+                f,
+                "- {} v{} ({})",
+                package.name, package.version, package.source
+            )?;
+        }
+        for change in &self.changed {
+            if change.version.from == change.version.to {
+                writeln!(f, "~ {} v{}", change.name, change.version.to)?;
+            } else {
+                writeln!(
+                    f,
+                    "~ {} v{} -> v{}",
+                    change.name, change.version.from, change.version.to
+                )?;
+            }
+            if let Some(Change { from, to }) = &change.source {
+                writeln!(f, "    source: {from} -> {to}")?;
+            }
+            for filename in &change.added_distributions {
+                writeln!(f, "    + {filename}")?;
+            }
+            for filename in &change.removed_distributions {
+                writeln!(f, "    - {filename}")?;
+            }
+            for hash in &change.changed_hashes {
+                writeln!(
+                    f,
+                    "    ~ {}: {} -> {}",
+                    hash.filename,
+                    hash.from.as_deref().unwrap_or("(none)"),
+                    hash.to.as_deref().unwrap_or("(none)")
+                )?;
+            }
+            change.resolution_markers.fmt_with_indent(f, "    ")?;
+        }
+        Ok(())
+    }
+}
+
+/// A change from one value to another.
+#[derive(Debug, Serialize)]
+struct Change<T> {
+    from: T,
+    to: T,
+}
+
+/// A package that was added to or removed from the lockfile.
+#[derive(Debug, Serialize)]
+struct PackageSummary {
+    name: PackageName,
+    version: String,
+    source: String,
+}
+
+impl From<&Package> for PackageSummary {
+    fn from(package: &Package) -> Self {
+        Self {
+            name: package.id.name.clone(),
+            version: package.id.version.to_string(),
+            source: package.id.source.to_string(),
+        }
+    }
+}
+
+/// The changes to a package that is present in both lockfiles.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct PackageChange {
+    name: PackageName,
+    version: Change<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<Change<String>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    added_distributions: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    removed_distributions: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    changed_hashes: Vec<HashChange>,
+    #[serde(skip_serializing_if = "MarkerChanges::is_empty")]
+    resolution_markers: MarkerChanges,
+}
+
+impl PackageChange {
+    fn is_empty(&self) -> bool {
+        self.version.from == self.version.to
+            && self.source.is_none()
+            && self.added_distributions.is_empty()
+            && self.removed_distributions.is_empty()
+            && self.changed_hashes.is_empty()
+            && self.resolution_markers.is_empty()
+    }
+}
+
+/// A change to the hash of a distribution.
+#[derive(Debug, Serialize)]
+struct HashChange {
+    filename: String,
+    from: Option<String>,
+    to: Option<String>,
+}
+
+/// The resolution markers that were added or removed.
+#[derive(Debug, Default, Serialize)]
+struct MarkerChanges {
+    added: Vec<String>,
+    removed: Vec<String>,
+}
+
+impl MarkerChanges {
+    fn new(
+        before: Option<(&RequiresPython, &[MarkerTree])>,
+        after: (&RequiresPython, &[MarkerTree]),
+    ) -> Self {
+        let simplify = |(requires_python, markers): (&RequiresPython, &[MarkerTree])| {
+            markers
+                .iter()
+                .filter_map(|marker| {
+                    SimplifiedMarkerTree::new(requires_python, marker.clone()).try_to_string()
+                })
+                .collect::<Vec<_>>()
+        };
+        let before = before.map(simplify).unwrap_or_default();
+        let after = simplify(after);
+        Self {
+            added: after
+                .iter()
+                .filter(|marker| !before.contains(marker))
+                .cloned()
+                .collect(),
+            removed: before
+                .iter()
+                .filter(|marker| !after.contains(marker))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+
+    fn fmt_with_indent(&self, f: &mut Formatter<'_>, indent: &str) -> std::fmt::Result {
+        for marker in &self.added {
+            writeln!(f, "{indent}+ resolution-marker: {marker}")?;
+        }
+        for marker in &self.removed {
+            writeln!(f, "{indent}- resolution-marker: {marker}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the filename and hash of each distribution for a package.
+fn distributions(package: &Package) -> BTreeMap<String, Option<String>> {
+    package
+        .sdist
+        .iter()
+        .filter_map(|sdist| {
+            let filename = sdist.filename()?.to_string();
+            Some((filename, sdist.hash().map(ToString::to_string)))
+        })
+        .chain(package.wheels.iter().map(|wheel| {
+            (
+                wheel.filename.to_string(),
+                wheel.hash.as_ref().map(ToString::to_string),
+            )
+        }))
+        .collect()
+}
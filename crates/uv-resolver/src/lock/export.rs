@@ -0,0 +1,252 @@
+use std::collections::hash_map::Entry;
+use std::collections::VecDeque;
+
+use either::Either;
+use petgraph::visit::IntoNodeReferences;
+use petgraph::{Directed, Direction, Graph};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
+
+use uv_configuration::{DevGroupsManifest, ExtrasSpecification, InstallOptions};
+use uv_normalize::ExtraName;
+use uv_pep508::MarkerTree;
+
+use crate::graph_ops::marker_reachability;
+use crate::lock::{Package, PackageId};
+use crate::InstallTarget;
+
+type LockGraph<'lock> = Graph<Node<'lock>, Edge, Directed>;
+
+/// The packages of a [`Lock`](crate::Lock) selected for export, with the markers under which they
+/// are reachable.
+///
+/// This is the shared traversal behind the export formats, such as `requirements.txt`.
+#[derive(Debug)]
+pub(crate) struct ExportableRequirements<'lock>(Vec<ExportableRequirement<'lock>>);
+
+impl<'lock> ExportableRequirements<'lock> {
+    /// Collect the packages to export from the [`InstallTarget`], respecting the requested extras,
+    /// dependency groups and install options.
+    pub(crate) fn from_lock(
+        target: InstallTarget<'lock>,
+        extras: &ExtrasSpecification,
+        dev: &DevGroupsManifest,
+        install_options: &'lock InstallOptions,
+    ) -> Self {
+        let size_guess = target.lock().packages.len();
+        let mut petgraph = LockGraph::with_capacity(size_guess, size_guess);
+        let mut inverse = FxHashMap::with_capacity_and_hasher(size_guess, FxBuildHasher);
+
+        let mut queue: VecDeque<(&Package, Option<&ExtraName>)> = VecDeque::new();
+        let mut seen = FxHashSet::default();
+
+        let root = petgraph.add_node(Node::Root);
+
+        // Forks on conflicting extras or groups are recorded as `extra` markers in the lockfile.
+        // Resolve them based on the enabled extras and groups, since they'd be meaningless to
+        // other tools.
+        let conflict_extras = target.conflict_extras(extras, dev);
+        let all_conflict_extras = target
+            .lock()
+            .conflicts()
+            .marker_extras()
+            .collect::<FxHashSet<_>>();
+        let resolve_conflicts = |marker: &MarkerTree| {
+            marker
+                .clone()
+                .simplify_extras_with(|extra| conflict_extras.contains(extra))
+                .simplify_not_extras_with(|extra| all_conflict_extras.contains(extra))
+        };
+
+        // Add the workspace package to the queue.
+        for root_name in target.packages() {
+            let dist = target
+                .lock()
+                .find_by_name(root_name)
+                .expect("found too many packages matching root")
+                .expect("could not find root");
+
+            if dev.prod() {
+                // Add the workspace package to the graph.
+                if let Entry::Vacant(entry) = inverse.entry(&dist.id) {
+                    entry.insert(petgraph.add_node(Node::Package(dist)));
+                }
+
+                // Add an edge from the root.
+                let index = inverse[&dist.id];
+                petgraph.add_edge(root, index, MarkerTree::TRUE);
+
+                // Push its dependencies on the queue.
+                queue.push_back((dist, None));
+                match extras {
+                    ExtrasSpecification::None => {}
+                    ExtrasSpecification::All => {
+                        for extra in dist.optional_dependencies.keys() {
+                            queue.push_back((dist, Some(extra)));
+                        }
+                    }
+                    ExtrasSpecification::Some(extras) => {
+                        for extra in extras {
+                            queue.push_back((dist, Some(extra)));
+                        }
+                    }
+                }
+            }
+
+            // Add any development dependencies.
+            for group in dev.iter() {
+                for dep in dist.dependency_groups.get(group).into_iter().flatten() {
+                    let dep_dist = target.lock().find_by_id(&dep.package_id);
+
+                    // Add the dependency to the graph.
+                    if let Entry::Vacant(entry) = inverse.entry(&dep.package_id) {
+                        entry.insert(petgraph.add_node(Node::Package(dep_dist)));
+                    }
+
+                    // Add an edge from the root. Development dependencies may be installed without
+                    // installing the workspace package itself (which can never have markers on it
+                    // anyway), so they're directly connected to the root.
+                    let dep_index = inverse[&dep.package_id];
+                    petgraph.add_edge(
+                        root,
+                        dep_index,
+                        resolve_conflicts(dep.simplified_marker.as_simplified_marker_tree()),
+                    );
+
+                    // Push its dependencies on the queue.
+                    if seen.insert((&dep.package_id, None)) {
+                        queue.push_back((dep_dist, None));
+                    }
+                    for extra in &dep.extra {
+                        if seen.insert((&dep.package_id, Some(extra))) {
+                            queue.push_back((dep_dist, Some(extra)));
+                        }
+                    }
+                }
+            }
+        }
+
+        // Create all the relevant nodes.
+        while let Some((package, extra)) = queue.pop_front() {
+            let index = inverse[&package.id];
+
+            let deps = if let Some(extra) = extra {
+                Either::Left(
+                    package
+                        .optional_dependencies
+                        .get(extra)
+                        .into_iter()
+                        .flatten(),
+                )
+            } else {
+                Either::Right(package.dependencies.iter())
+            };
+
+            for dep in deps {
+                let dep_dist = target.lock().find_by_id(&dep.package_id);
+
+                // Add the dependency to the graph.
+                if let Entry::Vacant(entry) = inverse.entry(&dep.package_id) {
+                    entry.insert(petgraph.add_node(Node::Package(dep_dist)));
+                }
+
+                // Add the edge.
+                let dep_index = inverse[&dep.package_id];
+                petgraph.add_edge(
+                    index,
+                    dep_index,
+                    resolve_conflicts(dep.simplified_marker.as_simplified_marker_tree()),
+                );
+
+                // Push its dependencies on the queue.
+                if seen.insert((&dep.package_id, None)) {
+                    queue.push_back((dep_dist, None));
+                }
+                for extra in &dep.extra {
+                    if seen.insert((&dep.package_id, Some(extra))) {
+                        queue.push_back((dep_dist, Some(extra)));
+                    }
+                }
+            }
+        }
+
+        let mut reachability = marker_reachability(&petgraph, &[]);
+
+        // Collect all packages, skipping any that are unreachable given the enabled extras and
+        // groups (i.e., that belong to a conflicting fork).
+        let packages = petgraph
+            .node_references()
+            .filter_map(|(index, node)| match node {
+                Node::Root => None,
+                Node::Package(package) => Some((index, *package)),
+            })
+            .filter(|(index, _package)| {
+                reachability
+                    .get(index)
+                    .map_or(true, |marker| !marker.is_false())
+            })
+            .filter(|(_index, package)| {
+                install_options.include_package(
+                    &package.id.name,
+                    target.project_name(),
+                    target.lock().members(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let included = packages
+            .iter()
+            .map(|(_index, package)| &package.id)
+            .collect::<FxHashSet<_>>();
+
+        let nodes = packages
+            .iter()
+            .map(|&(index, package)| {
+                // Collect the direct dependencies that are themselves part of the export.
+                let mut dependencies = petgraph
+                    .neighbors_directed(index, Direction::Outgoing)
+                    .filter_map(|dep| match petgraph[dep] {
+                        Node::Root => None,
+                        Node::Package(dep) => Some(&dep.id),
+                    })
+                    .filter(|id| included.contains(id))
+                    .collect::<Vec<_>>();
+                dependencies.sort_unstable();
+                dependencies.dedup();
+
+                ExportableRequirement {
+                    package,
+                    marker: reachability.remove(&index).unwrap_or_default(),
+                    dependencies,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Self(nodes)
+    }
+
+    /// Return the exportable requirements.
+    pub(crate) fn into_inner(self) -> Vec<ExportableRequirement<'lock>> {
+        self.0
+    }
+}
+
+/// A flat requirement, with its associated marker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ExportableRequirement<'lock> {
+    /// The [`Package`] associated with the requirement.
+    pub(crate) package: &'lock Package,
+    /// The marker that must be satisfied to install the package.
+    pub(crate) marker: MarkerTree,
+    /// The direct dependencies of the package that are included in the export.
+    pub(crate) dependencies: Vec<&'lock PackageId>,
+}
+
+/// A node in the [`LockGraph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node<'lock> {
+    Root,
+    Package(&'lock Package),
+}
+
+/// The edges of the [`LockGraph`].
+type Edge = MarkerTree;
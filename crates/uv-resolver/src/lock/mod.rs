@@ -13,7 +13,10 @@ use std::sync::{Arc, LazyLock};
 use toml_edit::{value, Array, ArrayOfTables, InlineTable, Item, Table, Value};
 use url::Url;
 
+pub use crate::lock::cyclonedx::CycloneDxExport;
+pub use crate::lock::diff::LockDiff;
 pub use crate::lock::map::PackageMap;
+pub use crate::lock::pylock_toml::PylockTomlExport;
 pub use crate::lock::requirements_txt::RequirementsTxtExport;
 pub use crate::lock::target::InstallTarget;
 pub use crate::lock::tree::TreeDisplay;
@@ -40,13 +43,18 @@ use uv_pep440::Version;
 use uv_pep508::{split_scheme, MarkerEnvironment, MarkerTree, VerbatimUrl, VerbatimUrlError};
 use uv_platform_tags::{TagCompatibility, TagPriority, Tags};
 use uv_pypi_types::{
-    redact_credentials, HashDigest, ParsedArchiveUrl, ParsedGitUrl, Requirement, RequirementSource,
+    redact_credentials, ConflictPackage, Conflicts, HashDigest, ParsedArchiveUrl, ParsedGitUrl,
+    Requirement, RequirementSource,
 };
 use uv_types::{BuildContext, HashStrategy};
 use uv_workspace::dependency_groups::DependencyGroupError;
 use uv_workspace::Workspace;
 
+mod cyclonedx;
+mod diff;
+mod export;
 mod map;
+mod pylock_toml;
 mod requirements_txt;
 mod target;
 mod tree;
@@ -82,6 +90,10 @@ pub struct Lock {
     fork_markers: Vec<MarkerTree>,
     /// The list of supported environments specified by the user.
     supported_environments: Vec<MarkerTree>,
+    /// The list of required environments specified by the user.
+    required_environments: Vec<MarkerTree>,
+    /// The sets of extras and groups that were declared as conflicting by the user.
+    conflicts: Conflicts,
     /// The range of supported Python versions.
     requires_python: RequiresPython,
     /// We discard the lockfile if these options don't match.
@@ -237,6 +249,8 @@ impl Lock {
             options,
             ResolverManifest::default(),
             vec![],
+            vec![],
+            Conflicts::empty(),
             graph.fork_markers.clone(),
         )?;
         Ok(lock)
@@ -312,6 +326,8 @@ impl Lock {
         options: ResolverOptions,
         manifest: ResolverManifest,
         supported_environments: Vec<MarkerTree>,
+        required_environments: Vec<MarkerTree>,
+        conflicts: Conflicts,
         fork_markers: Vec<MarkerTree>,
     ) -> Result<Self, LockError> {
         // Put all dependencies for each package in a canonical order and
@@ -461,6 +477,8 @@ impl Lock {
             version,
             fork_markers,
             supported_environments,
+            required_environments,
+            conflicts,
             requires_python,
             options,
             packages,
@@ -496,6 +514,25 @@ impl Lock {
         self
     }
 
+    /// Record the required environments that were used to generate this lock.
+    #[must_use]
+    pub fn with_required_environments(mut self, required_environments: Vec<MarkerTree>) -> Self {
+        // As with the supported environments, the markers given are assumed to be simplified, so
+        // we "complexify" them here.
+        self.required_environments = required_environments
+            .into_iter()
+            .map(|marker| self.requires_python.complexify_markers(marker))
+            .collect();
+        self
+    }
+
+    /// Record the conflicting extras and groups that were used to generate this lock.
+    #[must_use]
+    pub fn with_conflicts(mut self, conflicts: Conflicts) -> Self {
+        self.conflicts = conflicts;
+        self
+    }
+
     /// Returns the lockfile version.
     pub fn version(&self) -> u32 {
         self.version
@@ -541,11 +578,31 @@ impl Lock {
         &self.supported_environments
     }
 
+    /// Returns the required environments that were used to generate this lock.
+    pub fn required_environments(&self) -> &[MarkerTree] {
+        &self.required_environments
+    }
+
+    /// Returns the conflicting extras and groups that were used to generate this lock.
+    pub fn conflicts(&self) -> &Conflicts {
+        &self.conflicts
+    }
+
     /// Returns the workspace members that were used to generate this lock.
     pub fn members(&self) -> &BTreeSet<PackageName> {
         &self.manifest.members
     }
 
+    /// Returns `true` if this lock contains exactly the same packages as the `other` lock, with
+    /// each package identified by its name, version, and source.
+    pub fn has_same_packages(&self, other: &Lock) -> bool {
+        self.packages.len() == other.packages.len()
+            && self
+                .packages
+                .iter()
+                .all(|package| other.by_id.contains_key(&package.id))
+    }
+
     /// Return the workspace root used to generate this lock.
     pub fn root(&self) -> Option<&Package> {
         self.packages.iter().find(|package| {
@@ -573,6 +630,18 @@ impl Lock {
             .collect()
     }
 
+    /// Returns the required environments that were used to generate this lock, simplified with
+    /// respect to the lockfile's `requires-python` setting.
+    ///
+    /// See [`Lock::simplified_supported_environments`] for details.
+    pub fn simplified_required_environments(&self) -> Vec<MarkerTree> {
+        self.required_environments()
+            .iter()
+            .cloned()
+            .map(|marker| self.simplify_environment(marker))
+            .collect()
+    }
+
     /// Simplify the given marker environment with respect to the lockfile's
     /// `requires-python` setting.
     pub fn simplify_environment(&self, marker: MarkerTree) -> MarkerTree {
@@ -614,6 +683,36 @@ impl Lock {
             doc.insert("supported-markers", value(supported_environments));
         }
 
+        if !self.required_environments.is_empty() {
+            let required_environments = each_element_on_its_line_array(
+                self.required_environments
+                    .iter()
+                    .map(|marker| SimplifiedMarkerTree::new(&self.requires_python, marker.clone()))
+                    .filter_map(|marker| marker.try_to_string()),
+            );
+            doc.insert("required-markers", value(required_environments));
+        }
+
+        if !self.conflicts.is_empty() {
+            let mut list = Array::new();
+            for set in self.conflicts.iter() {
+                list.push(each_element_on_its_line_array(set.iter().map(|item| {
+                    let mut table = InlineTable::new();
+                    table.insert("package", Value::from(item.package().to_string()));
+                    match item.conflict() {
+                        ConflictPackage::Extra(extra) => {
+                            table.insert("extra", Value::from(extra.to_string()));
+                        }
+                        ConflictPackage::Group(group) => {
+                            table.insert("group", Value::from(group.to_string()));
+                        }
+                    }
+                    table
+                })));
+            }
+            doc.insert("conflicts", value(list));
+        }
+
         // Write the settings that were used to generate the resolution.
         // This enables us to invalidate the lockfile if the user changes
         // their settings.
@@ -1352,6 +1451,10 @@ struct LockWire {
     fork_markers: Vec<SimplifiedMarkerTree>,
     #[serde(rename = "supported-markers", default)]
     supported_environments: Vec<SimplifiedMarkerTree>,
+    #[serde(rename = "required-markers", default)]
+    required_environments: Vec<SimplifiedMarkerTree>,
+    #[serde(default)]
+    conflicts: Conflicts,
     /// We discard the lockfile if these options match.
     #[serde(default)]
     options: ResolverOptions,
@@ -1392,6 +1495,11 @@ impl TryFrom<LockWire> for Lock {
             .into_iter()
             .map(|simplified_marker| simplified_marker.into_marker(&wire.requires_python))
             .collect();
+        let required_environments = wire
+            .required_environments
+            .into_iter()
+            .map(|simplified_marker| simplified_marker.into_marker(&wire.requires_python))
+            .collect();
         let fork_markers = wire
             .fork_markers
             .into_iter()
@@ -1404,6 +1512,8 @@ impl TryFrom<LockWire> for Lock {
             wire.options,
             wire.manifest,
             supported_environments,
+            required_environments,
+            wire.conflicts,
             fork_markers,
         )?;
 
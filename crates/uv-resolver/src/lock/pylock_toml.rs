@@ -0,0 +1,251 @@
+use std::path::Path;
+
+use toml_edit::{value, ArrayOfTables, InlineTable, Item, Table, Value};
+
+use uv_configuration::{DevGroupsManifest, EditableMode, ExtrasSpecification, InstallOptions};
+use uv_fs::PortablePath;
+use uv_pypi_types::HashDigest;
+
+use crate::lock::export::{ExportableRequirement, ExportableRequirements};
+use crate::lock::{
+    each_element_on_its_line_array, GitSourceKind, Lock, LockErrorKind, RegistrySource, Source,
+    SourceDist, Wheel, WheelWireSource,
+};
+use crate::requires_python::SimplifiedMarkerTree;
+use crate::{InstallTarget, LockError};
+
+/// The version of the `pylock.toml` format that we write.
+const PYLOCK_VERSION: &str = "1.0";
+
+/// An export of a [`Lock`] that renders in the [PEP 751](https://peps.python.org/pep-0751/)
+/// `pylock.toml` format.
+#[derive(Debug)]
+pub struct PylockTomlExport<'lock> {
+    lock: &'lock Lock,
+    nodes: Vec<ExportableRequirement<'lock>>,
+    editable: EditableMode,
+}
+
+impl<'lock> PylockTomlExport<'lock> {
+    pub fn from_lock(
+        target: InstallTarget<'lock>,
+        extras: &ExtrasSpecification,
+        dev: &DevGroupsManifest,
+        editable: EditableMode,
+        install_options: &'lock InstallOptions,
+    ) -> Result<Self, LockError> {
+        let mut nodes =
+            ExportableRequirements::from_lock(target, extras, dev, install_options).into_inner();
+
+        // `pylock.toml` files are sorted by package name, then by version.
+        nodes.sort_unstable_by(|a, b| a.package.id.cmp(&b.package.id));
+
+        Ok(Self {
+            lock: target.lock(),
+            nodes,
+            editable,
+        })
+    }
+
+    /// Returns the TOML representation of this export.
+    pub fn to_toml(&self) -> anyhow::Result<String> {
+        let requires_python = &self.lock.requires_python;
+
+        let mut doc = toml_edit::DocumentMut::new();
+        doc.insert("lock-version", value(PYLOCK_VERSION));
+
+        if !self.lock.supported_environments.is_empty() {
+            let environments = each_element_on_its_line_array(
+                self.lock
+                    .supported_environments
+                    .iter()
+                    .map(|marker| SimplifiedMarkerTree::new(requires_python, marker.clone()))
+                    .filter_map(|marker| marker.try_to_string()),
+            );
+            doc.insert("environments", value(environments));
+        }
+
+        doc.insert("requires-python", value(requires_python.to_string()));
+        doc.insert("created-by", value("uv"));
+
+        let mut packages = ArrayOfTables::new();
+        for ExportableRequirement {
+            package, marker, ..
+        } in &self.nodes
+        {
+            let mut table = Table::new();
+            table.insert("name", value(package.id.name.to_string()));
+
+            // The version of a source tree may change, so it's omitted for directories.
+            if !matches!(
+                package.id.source,
+                Source::Directory(_) | Source::Editable(_) | Source::Virtual(_)
+            ) {
+                table.insert("version", value(package.id.version.to_string()));
+            }
+
+            if let Some(marker) = marker.contents() {
+                table.insert("marker", value(marker.to_string()));
+            }
+
+            match &package.id.source {
+                Source::Registry(registry) => {
+                    if let RegistrySource::Url(url) = registry {
+                        table.insert("index", value(url.as_ref()));
+                    }
+                    if let Some(sdist) = &package.sdist {
+                        table.insert("sdist", Item::Table(sdist_to_toml(sdist, registry)?));
+                    }
+                    if !package.wheels.is_empty() {
+                        let mut wheels = ArrayOfTables::new();
+                        for wheel in &package.wheels {
+                            wheels.push(wheel_to_toml(wheel, registry)?);
+                        }
+                        table.insert("wheels", Item::ArrayOfTables(wheels));
+                    }
+                }
+                Source::Git(url, git) => {
+                    // Remove the fragment and query from the URL; they're already present in the
+                    // `GitSource`.
+                    let mut url = url.to_url();
+                    url.set_fragment(None);
+                    url.set_query(None);
+
+                    let mut vcs = Table::new();
+                    vcs.insert("type", value("git"));
+                    vcs.insert("url", value(url.to_string()));
+                    match &git.kind {
+                        GitSourceKind::Tag(reference)
+                        | GitSourceKind::Branch(reference)
+                        | GitSourceKind::Rev(reference) => {
+                            vcs.insert("requested-revision", value(reference.as_str()));
+                        }
+                        GitSourceKind::DefaultBranch => {}
+                    }
+                    vcs.insert("commit-id", value(git.precise.to_string()));
+                    if let Some(subdirectory) = &git.subdirectory {
+                        vcs.insert("subdirectory", value(subdirectory.as_str()));
+                    }
+                    table.insert("vcs", Item::Table(vcs));
+                }
+                Source::Direct(url, direct) => {
+                    let mut archive = Table::new();
+                    archive.insert("url", value(url.as_ref()));
+                    if let Some(hashes) = hashes_to_toml(&package.hashes()) {
+                        archive.insert("hashes", value(hashes));
+                    }
+                    if let Some(subdirectory) = &direct.subdirectory {
+                        archive.insert("subdirectory", value(subdirectory.as_str()));
+                    }
+                    table.insert("archive", Item::Table(archive));
+                }
+                Source::Path(path) => {
+                    let mut archive = Table::new();
+                    archive.insert("path", value(PortablePath::from(path).to_string()));
+                    if let Some(hashes) = hashes_to_toml(&package.hashes()) {
+                        archive.insert("hashes", value(hashes));
+                    }
+                    table.insert("archive", Item::Table(archive));
+                }
+                Source::Directory(path) => {
+                    let mut directory = Table::new();
+                    directory.insert("path", value(PortablePath::from(path).to_string()));
+                    table.insert("directory", Item::Table(directory));
+                }
+                Source::Editable(path) => {
+                    let mut directory = Table::new();
+                    directory.insert("path", value(PortablePath::from(path).to_string()));
+                    if self.editable == EditableMode::Editable {
+                        directory.insert("editable", value(true));
+                    }
+                    table.insert("directory", Item::Table(directory));
+                }
+                Source::Virtual(_) => {
+                    continue;
+                }
+            }
+
+            packages.push(table);
+        }
+
+        doc.insert("packages", Item::ArrayOfTables(packages));
+        Ok(doc.to_string())
+    }
+}
+
+/// Returns the `packages.sdist` table for a registry source distribution.
+fn sdist_to_toml(sdist: &SourceDist, registry: &RegistrySource) -> anyhow::Result<Table> {
+    let mut table = Table::new();
+    if let Some(filename) = sdist.filename() {
+        table.insert("name", value(filename.as_ref()));
+    }
+    match sdist {
+        SourceDist::Url { url, .. } => {
+            table.insert("url", value(url.as_ref()));
+        }
+        SourceDist::Path { path, .. } => {
+            table.insert("path", value(registry_path(registry, path)));
+        }
+    }
+    if let Some(size) = sdist.size() {
+        table.insert("size", value(i64::try_from(size)?));
+    }
+    if let Some(hashes) = hashes_to_toml(sdist.hash().map(|hash| &hash.0)) {
+        table.insert("hashes", value(hashes));
+    }
+    Ok(table)
+}
+
+/// Returns the `packages.wheels` entry for a registry wheel.
+fn wheel_to_toml(wheel: &Wheel, registry: &RegistrySource) -> anyhow::Result<Table> {
+    let mut table = Table::new();
+    table.insert("name", value(wheel.filename.to_string()));
+    match &wheel.url {
+        WheelWireSource::Url { url } => {
+            table.insert("url", value(url.as_ref()));
+        }
+        WheelWireSource::Path { path } => {
+            table.insert("path", value(registry_path(registry, path)));
+        }
+        // Without a URL or path, the wheel can't be fetched, so we can't write an entry for it.
+        WheelWireSource::Filename { filename } => {
+            let (name, version) = (filename.name.clone(), filename.version.clone());
+            return Err(LockError::from(match registry {
+                RegistrySource::Url(_) => LockErrorKind::MissingUrl { name, version },
+                RegistrySource::Path(_) => LockErrorKind::MissingPath { name, version },
+            })
+            .into());
+        }
+    }
+    if let Some(size) = wheel.size {
+        table.insert("size", value(i64::try_from(size)?));
+    }
+    if let Some(hashes) = hashes_to_toml(wheel.hash.as_ref().map(|hash| &hash.0)) {
+        table.insert("hashes", value(hashes));
+    }
+    Ok(table)
+}
+
+/// Returns the path of a file in a local registry, relative to the workspace root.
+fn registry_path(registry: &RegistrySource, path: &Path) -> String {
+    match registry {
+        RegistrySource::Path(index) => PortablePath::from(&index.join(path)).to_string(),
+        RegistrySource::Url(_) => PortablePath::from(path).to_string(),
+    }
+}
+
+/// Returns the `hashes` table, mapping each algorithm to its digest.
+fn hashes_to_toml<'a>(hashes: impl IntoIterator<Item = &'a HashDigest>) -> Option<InlineTable> {
+    let mut table = InlineTable::new();
+    for hash in hashes {
+        table.insert(
+            hash.algorithm.to_string(),
+            Value::from(hash.digest.as_ref()),
+        );
+    }
+    if table.is_empty() {
+        None
+    } else {
+        Some(table)
+    }
+}
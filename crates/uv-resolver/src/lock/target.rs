@@ -1,4 +1,5 @@
 use std::collections::{BTreeMap, VecDeque};
+use std::path::Path;
 
 use either::Either;
 use rustc_hash::FxHashSet;
@@ -7,7 +8,7 @@ use uv_configuration::{BuildOptions, DevGroupsManifest, ExtrasSpecification, Ins
 use uv_distribution_types::{Resolution, ResolvedDist};
 use uv_normalize::{ExtraName, GroupName, PackageName, DEV_DEPENDENCIES};
 use uv_platform_tags::Tags;
-use uv_pypi_types::{ResolverMarkerEnvironment, VerbatimParsedUrl};
+use uv_pypi_types::{ConflictItem, ResolverMarkerEnvironment, VerbatimParsedUrl};
 use uv_workspace::dependency_groups::{DependencyGroupError, FlatDependencyGroups};
 use uv_workspace::Workspace;
 
@@ -33,15 +34,39 @@ pub enum InstallTarget<'env> {
         workspace: &'env Workspace,
         lock: &'env Lock,
     },
+    /// A standalone lockfile, without access to the workspace that produced it (e.g., in
+    /// `uv pip sync uv.lock`).
+    ///
+    /// Relative paths in the lockfile are resolved against the `install_path`, i.e., the directory
+    /// containing the `uv.lock`.
+    ///
+    /// If the lockfile has neither workspace members nor a root (e.g., the lockfile for an
+    /// installed tool, which is resolved for a single environment), every package in the lockfile
+    /// is installed.
+    Lock {
+        install_path: &'env Path,
+        lock: &'env Lock,
+    },
 }
 
 impl<'env> InstallTarget<'env> {
-    /// Return the [`Workspace`] of the target.
-    pub fn workspace(&self) -> &'env Workspace {
+    /// Return the [`Workspace`] of the target, if available.
+    pub fn workspace(&self) -> Option<&'env Workspace> {
         match self {
-            Self::Project { workspace, .. } => workspace,
-            Self::Workspace { workspace, .. } => workspace,
-            Self::NonProjectWorkspace { workspace, .. } => workspace,
+            Self::Project { workspace, .. } => Some(*workspace),
+            Self::Workspace { workspace, .. } => Some(*workspace),
+            Self::NonProjectWorkspace { workspace, .. } => Some(*workspace),
+            Self::Lock { .. } => None,
+        }
+    }
+
+    /// Return the path against which the relative paths in the lockfile are resolved.
+    pub fn install_path(&self) -> &'env Path {
+        match self {
+            Self::Project { workspace, .. } => workspace.install_path(),
+            Self::Workspace { workspace, .. } => workspace.install_path(),
+            Self::NonProjectWorkspace { workspace, .. } => workspace.install_path(),
+            Self::Lock { install_path, .. } => install_path,
         }
     }
 
@@ -51,6 +76,7 @@ impl<'env> InstallTarget<'env> {
             Self::Project { lock, .. } => lock,
             Self::Workspace { lock, .. } => lock,
             Self::NonProjectWorkspace { lock, .. } => lock,
+            Self::Lock { lock, .. } => lock,
         }
     }
 
@@ -59,7 +85,7 @@ impl<'env> InstallTarget<'env> {
         match self {
             Self::Project { name, .. } => Either::Right(Either::Left(std::iter::once(*name))),
             Self::NonProjectWorkspace { lock, .. } => Either::Left(lock.members().iter()),
-            Self::Workspace { lock, .. } => {
+            Self::Workspace { lock, .. } | Self::Lock { lock, .. } => {
                 // Identify the workspace members.
                 //
                 // The members are encoded directly in the lockfile, unless the workspace contains a
@@ -89,6 +115,7 @@ impl<'env> InstallTarget<'env> {
         match self {
             Self::Project { .. } => Ok(BTreeMap::default()),
             Self::Workspace { .. } => Ok(BTreeMap::default()),
+            Self::Lock { .. } => Ok(BTreeMap::default()),
             Self::NonProjectWorkspace { workspace, .. } => {
                 // For non-projects, we might have `dependency-groups` or `tool.uv.dev-dependencies`
                 // that are attached to the workspace root (which isn't a member).
@@ -142,9 +169,50 @@ impl<'env> InstallTarget<'env> {
             Self::Project { name, .. } => Some(name),
             Self::Workspace { .. } => None,
             Self::NonProjectWorkspace { .. } => None,
+            // Without a workspace, treat the root of the lockfile as the project.
+            Self::Lock { lock, .. } => lock.root().map(|package| &package.id.name),
         }
     }
 
+    /// Return the encoded conflict extras (see [`ConflictItem::to_marker_extra`]) for the extras
+    /// and dependency groups of the workspace members that are enabled.
+    ///
+    /// Forks on conflicting extras or groups are recorded in the lockfile as `extra` markers, which
+    /// are satisfied by passing these names as the active extras when evaluating markers.
+    pub(crate) fn conflict_extras(
+        &self,
+        extras: &ExtrasSpecification,
+        dev: &DevGroupsManifest,
+    ) -> Vec<ExtraName> {
+        let mut conflict_extras = Vec::new();
+        for root_name in self.packages() {
+            if dev.prod() {
+                match extras {
+                    ExtrasSpecification::None => {}
+                    ExtrasSpecification::All => {
+                        if let Ok(Some(root)) = self.lock().find_by_name(root_name) {
+                            conflict_extras.extend(root.optional_dependencies.keys().map(
+                                |extra| {
+                                    ConflictItem::from((root_name.clone(), extra.clone()))
+                                        .to_marker_extra()
+                                },
+                            ));
+                        }
+                    }
+                    ExtrasSpecification::Some(extras) => {
+                        conflict_extras.extend(extras.iter().map(|extra| {
+                            ConflictItem::from((root_name.clone(), extra.clone())).to_marker_extra()
+                        }));
+                    }
+                }
+            }
+            conflict_extras.extend(dev.iter().map(|group| {
+                ConflictItem::from((root_name.clone(), group.clone())).to_marker_extra()
+            }));
+        }
+        conflict_extras
+    }
+
     /// Convert the [`Lock`] to a [`Resolution`] using the given marker environment, tags, and root.
     pub fn to_resolution(
         &self,
@@ -158,6 +226,9 @@ impl<'env> InstallTarget<'env> {
         let mut queue: VecDeque<(&Package, Option<&ExtraName>)> = VecDeque::new();
         let mut seen = FxHashSet::default();
 
+        // Determine the conflict markers that are satisfied by the enabled extras and groups.
+        let conflict_extras = self.conflict_extras(extras, dev);
+
         // Add the workspace packages to the queue.
         for root_name in self.packages() {
             let root = self
@@ -193,7 +264,10 @@ impl<'env> InstallTarget<'env> {
             // Add any dev dependencies.
             for group in dev.iter() {
                 for dep in root.dependency_groups.get(group).into_iter().flatten() {
-                    if dep.complexified_marker.evaluate(marker_env, &[]) {
+                    if dep
+                        .complexified_marker
+                        .evaluate(marker_env, &conflict_extras)
+                    {
                         let dep_dist = self.lock().find_by_id(&dep.package_id);
                         if seen.insert((&dep.package_id, None)) {
                             queue.push_back((dep_dist, None));
@@ -238,6 +312,17 @@ impl<'env> InstallTarget<'env> {
             }
         }
 
+        // Add every package in a standalone lockfile without a root.
+        if let Self::Lock { lock, .. } = self {
+            if lock.members().is_empty() && lock.root().is_none() {
+                for package in lock.packages() {
+                    if seen.insert((&package.id, None)) {
+                        queue.push_back((package, None));
+                    }
+                }
+            }
+        }
+
         let mut map = BTreeMap::default();
         let mut hashes = BTreeMap::default();
         while let Some((dist, extra)) = queue.pop_front() {
@@ -247,7 +332,10 @@ impl<'env> InstallTarget<'env> {
                 Either::Right(dist.dependencies.iter())
             };
             for dep in deps {
-                if dep.complexified_marker.evaluate(marker_env, &[]) {
+                if dep
+                    .complexified_marker
+                    .evaluate(marker_env, &conflict_extras)
+                {
                     let dep_dist = self.lock().find_by_id(&dep.package_id);
                     if seen.insert((&dep.package_id, None)) {
                         queue.push_back((dep_dist, None));
@@ -267,7 +355,7 @@ impl<'env> InstallTarget<'env> {
                 map.insert(
                     dist.id.name.clone(),
                     ResolvedDist::Installable(dist.to_dist(
-                        self.workspace().install_path(),
+                        self.install_path(),
                         TagPolicy::Required(tags),
                         build_options,
                     )?),
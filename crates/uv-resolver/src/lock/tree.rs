@@ -7,6 +7,7 @@ use petgraph::graph::{EdgeIndex, NodeIndex};
 use petgraph::prelude::EdgeRef;
 use petgraph::Direction;
 use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
 
 use uv_configuration::DevGroupsManifest;
 use uv_normalize::{ExtraName, GroupName, PackageName};
@@ -403,6 +404,132 @@ impl<'env> TreeDisplay<'env> {
 
         lines
     }
+
+    /// Returns the JSON representation of the dependency graph.
+    ///
+    /// Rather than rendering a tree, the graph is emitted as a list of nodes and the edges between
+    /// them. Nodes beyond the maximum depth are omitted. If the graph is inverted, each edge points
+    /// from a package to its dependent.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        // Determine the distance of each node from the nearest root.
+        let mut distances = FxHashMap::default();
+        let mut queue = VecDeque::new();
+        for root in &self.roots {
+            distances.insert(*root, 0);
+            queue.push_back(*root);
+        }
+        while let Some(node) = queue.pop_front() {
+            let distance = distances[&node];
+            if distance >= self.depth {
+                continue;
+            }
+            for edge in self.graph.edges_directed(node, Direction::Outgoing) {
+                if !distances.contains_key(&edge.target()) {
+                    distances.insert(edge.target(), distance + 1);
+                    queue.push_back(edge.target());
+                }
+            }
+        }
+
+        // Sort the nodes, such that the output is deterministic.
+        let mut nodes = distances.keys().copied().collect::<Vec<_>>();
+        nodes.sort_by_key(|index| self.graph[*index]);
+        let positions = nodes
+            .iter()
+            .enumerate()
+            .map(|(position, index)| (*index, position))
+            .collect::<FxHashMap<_, _>>();
+
+        let mut edges = self
+            .graph
+            .edge_references()
+            .filter(|edge| {
+                distances
+                    .get(&edge.source())
+                    .is_some_and(|distance| *distance < self.depth)
+                    && positions.contains_key(&edge.target())
+            })
+            .map(|edge| {
+                let (extra, group) = match edge.weight() {
+                    Edge::Prod(_) => (None, None),
+                    Edge::Optional(extra, _) => (Some(*extra), None),
+                    Edge::Dev(group, _) => (None, Some(*group)),
+                };
+                let dependency = edge.weight().dependency();
+                JsonEdge {
+                    from: positions[&edge.source()],
+                    to: positions[&edge.target()],
+                    extras: dependency.extra.iter().collect(),
+                    extra,
+                    group,
+                    marker: dependency.simplified_marker.try_to_string(),
+                }
+            })
+            .collect::<Vec<_>>();
+        edges.sort_by(|a, b| {
+            (a.from, a.to, a.extra, a.group).cmp(&(b.from, b.to, b.extra, b.group))
+        });
+
+        let graph = JsonGraph {
+            roots: self.roots.iter().map(|root| positions[root]).collect(),
+            nodes: nodes
+                .iter()
+                .map(|index| {
+                    let package_id = self.graph[*index];
+                    JsonNode {
+                        name: &package_id.name,
+                        version: &package_id.version,
+                        source: package_id.source.to_string(),
+                        latest: self.latest.get(package_id),
+                    }
+                })
+                .collect(),
+            edges,
+        };
+
+        let mut json = serde_json::to_string_pretty(&graph)?;
+        json.push('\n');
+        Ok(json)
+    }
+}
+
+/// The JSON representation of a [`TreeDisplay`].
+#[derive(Debug, Serialize)]
+struct JsonGraph<'env> {
+    /// The indices of the root nodes.
+    roots: Vec<usize>,
+    nodes: Vec<JsonNode<'env>>,
+    edges: Vec<JsonEdge<'env>>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonNode<'env> {
+    name: &'env PackageName,
+    version: &'env Version,
+    source: String,
+    /// The latest available version of the package, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest: Option<&'env Version>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonEdge<'env> {
+    /// The index of the dependent node (or, if inverted, the dependency).
+    from: usize,
+    /// The index of the dependency node (or, if inverted, the dependent).
+    to: usize,
+    /// The extras requested for the dependency.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    extras: Vec<&'env ExtraName>,
+    /// The extra of the dependent that includes the dependency, for optional dependencies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extra: Option<&'env ExtraName>,
+    /// The dependency group that includes the dependency, for development dependencies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<&'env GroupName>,
+    /// The markers under which the dependency applies, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd)]
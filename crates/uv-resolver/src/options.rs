@@ -1,15 +1,17 @@
 use uv_configuration::IndexStrategy;
+use uv_pypi_types::SupportedEnvironments;
 
 use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
 
 /// Options for resolving a manifest.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Options {
     pub resolution_mode: ResolutionMode,
     pub prerelease_mode: PrereleaseMode,
     pub dependency_mode: DependencyMode,
     pub exclude_newer: Option<ExcludeNewer>,
     pub index_strategy: IndexStrategy,
+    pub required_environments: SupportedEnvironments,
     pub flexibility: Flexibility,
 }
 
@@ -21,6 +23,7 @@ pub struct OptionsBuilder {
     dependency_mode: DependencyMode,
     exclude_newer: Option<ExcludeNewer>,
     index_strategy: IndexStrategy,
+    required_environments: SupportedEnvironments,
     flexibility: Flexibility,
 }
 
@@ -65,6 +68,13 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the environments for which the resolution must include a compatible wheel.
+    #[must_use]
+    pub fn required_environments(mut self, required_environments: SupportedEnvironments) -> Self {
+        self.required_environments = required_environments;
+        self
+    }
+
     /// Sets the [`Flexibility`].
     #[must_use]
     pub fn flexibility(mut self, flexibility: Flexibility) -> Self {
@@ -80,6 +90,7 @@ impl OptionsBuilder {
             dependency_mode: self.dependency_mode,
             exclude_newer: self.exclude_newer,
             index_strategy: self.index_strategy,
+            required_environments: self.required_environments,
             flexibility: self.flexibility,
         }
     }
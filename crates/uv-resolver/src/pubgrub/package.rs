@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep508::{MarkerTree, MarkerTreeContents};
+use uv_pypi_types::ConflictItem;
 
 use crate::python_requirement::PythonRequirement;
 
@@ -176,6 +177,35 @@ impl PubGrubPackage {
         )
     }
 
+    /// Returns the conflicting extra or group that this PubGrub package
+    /// enables, if any.
+    ///
+    /// For example, `black[colorama]` enables the `colorama` extra of
+    /// `black`.
+    pub(crate) fn conflicting_item(&self) -> Option<ConflictItem> {
+        match &**self {
+            PubGrubPackageInner::Root(_)
+            | PubGrubPackageInner::Python(_)
+            | PubGrubPackageInner::Marker { .. } => None,
+            PubGrubPackageInner::Package {
+                name, extra, dev, ..
+            } => {
+                if let Some(extra) = extra {
+                    Some(ConflictItem::from((name.clone(), extra.clone())))
+                } else {
+                    dev.as_ref()
+                        .map(|dev| ConflictItem::from((name.clone(), dev.clone())))
+                }
+            }
+            PubGrubPackageInner::Extra { name, extra, .. } => {
+                Some(ConflictItem::from((name.clone(), extra.clone())))
+            }
+            PubGrubPackageInner::Dev { name, dev, .. } => {
+                Some(ConflictItem::from((name.clone(), dev.clone())))
+            }
+        }
+    }
+
     /// This simplifies the markers on this package (if any exist) using the
     /// given Python requirement as assumed context.
     ///
@@ -195,6 +195,8 @@ impl ResolutionGraph {
                 })
                 .unwrap_or_else(Vec::new)
         } else {
+            // Forks on conflicting extras or groups carry conflict markers (see
+            // `ConflictItem::to_marker`), so they remain distinct and disjoint.
             resolutions
                 .iter()
                 .map(|resolution| {
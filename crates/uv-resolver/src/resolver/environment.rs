@@ -1,7 +1,10 @@
 use std::sync::Arc;
 
+use itertools::Itertools;
+use rustc_hash::FxHashSet;
+
 use uv_pep508::{MarkerEnvironment, MarkerTree};
-use uv_pypi_types::ResolverMarkerEnvironment;
+use uv_pypi_types::{ConflictItem, ResolverMarkerEnvironment};
 
 use crate::requires_python::RequiresPythonRange;
 use crate::resolver::ForkState;
@@ -94,6 +97,14 @@ enum Kind {
         initial_forks: Arc<[MarkerTree]>,
         /// The markers associated with this resolver fork.
         markers: MarkerTree,
+        /// The conflicting extras and groups that are excluded from this
+        /// resolver fork.
+        ///
+        /// When two or more extras (or groups) are declared as conflicting,
+        /// the resolver forks such that each fork includes at most one of
+        /// them. Any dependency that would enable an excluded extra or group
+        /// is dropped from the fork.
+        exclude: Arc<FxHashSet<ConflictItem>>,
     },
 }
 
@@ -131,6 +142,7 @@ impl ResolverEnvironment {
         let kind = Kind::Universal {
             initial_forks: initial_forks.into(),
             markers: MarkerTree::TRUE,
+            exclude: Arc::new(FxHashSet::default()),
         };
         ResolverEnvironment { kind }
     }
@@ -156,6 +168,50 @@ impl ResolverEnvironment {
         }
     }
 
+    /// Returns `false` only when this environment is a fork and it excludes
+    /// the given conflicting extra or group.
+    pub(crate) fn included_by_group(&self, item: &ConflictItem) -> bool {
+        match self.kind {
+            Kind::Specific { .. } => true,
+            Kind::Universal { ref exclude, .. } => !exclude.contains(item),
+        }
+    }
+
+    /// Exclude the given conflicting extras and groups from this environment.
+    ///
+    /// This should be used when generating forking states for conflicting
+    /// extras or groups in the resolver. Any dependency that enables one of
+    /// the excluded items is then dropped from the fork.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the resolver environment corresponds to one and only one
+    /// specific marker environment. i.e., "pip"-style resolution.
+    pub(crate) fn exclude_by_group(
+        &self,
+        items: impl IntoIterator<Item = ConflictItem>,
+    ) -> ResolverEnvironment {
+        match self.kind {
+            Kind::Specific { .. } => {
+                unreachable!("environment exclusion only happens in universal resolution")
+            }
+            Kind::Universal {
+                ref initial_forks,
+                ref markers,
+                ref exclude,
+            } => {
+                let mut exclude: FxHashSet<_> = (**exclude).clone();
+                exclude.extend(items);
+                let kind = Kind::Universal {
+                    initial_forks: initial_forks.clone(),
+                    markers: markers.clone(),
+                    exclude: Arc::new(exclude),
+                };
+                ResolverEnvironment { kind }
+            }
+        }
+    }
+
     /// Narrow this environment given the forking markers.
     ///
     /// This should be used when generating forking states in the resolver. In
@@ -182,6 +238,7 @@ impl ResolverEnvironment {
             Kind::Universal {
                 ref initial_forks,
                 markers: ref lhs,
+                ref exclude,
             } => {
                 let mut lhs = lhs.clone();
                 lhs.and(rhs.clone());
@@ -198,6 +255,7 @@ impl ResolverEnvironment {
                 let kind = Kind::Universal {
                     initial_forks: initial_forks.clone(),
                     markers: lhs,
+                    exclude: exclude.clone(),
                 };
 
                 Some(ResolverEnvironment { kind })
@@ -259,13 +317,19 @@ impl ResolverEnvironment {
     pub(crate) fn end_user_fork_display(&self) -> Option<impl std::fmt::Display + '_> {
         match self.kind {
             Kind::Specific { .. } => None,
-            Kind::Universal { ref markers, .. } => {
-                if markers.is_true() {
-                    None
-                } else {
-                    Some(format!("split ({markers:?})"))
-                }
-            }
+            Kind::Universal {
+                ref markers,
+                ref exclude,
+                ..
+            } => match (markers.is_true(), exclude.is_empty()) {
+                (true, true) => None,
+                (true, false) => Some(format!("split (excluding {})", display_items(exclude))),
+                (false, true) => Some(format!("split ({markers:?})")),
+                (false, false) => Some(format!(
+                    "split ({markers:?}; excluding {})",
+                    display_items(exclude)
+                )),
+            },
         }
     }
 
@@ -303,17 +367,34 @@ impl std::fmt::Display for ResolverEnvironment {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self.kind {
             Kind::Specific { .. } => write!(f, "marker environment"),
-            Kind::Universal { ref markers, .. } => {
+            Kind::Universal {
+                ref markers,
+                ref exclude,
+                ..
+            } => {
                 if markers.is_true() {
-                    write!(f, "all marker environments")
+                    write!(f, "all marker environments")?;
                 } else {
-                    write!(f, "split `{markers:?}`")
+                    write!(f, "split `{markers:?}`")?;
+                }
+                if !exclude.is_empty() {
+                    write!(f, " (excluding {})", display_items(exclude))?;
                 }
+                Ok(())
             }
         }
     }
 }
 
+/// Format a set of conflicting items in a stable order, e.g., `` `foo[bar]`, `foo[baz]` ``.
+fn display_items(items: &FxHashSet<ConflictItem>) -> String {
+    items
+        .iter()
+        .sorted()
+        .map(|item| format!("`{item}`"))
+        .join(", ")
+}
+
 #[cfg(test)]
 mod tests {
     use std::ops::Bound;
@@ -38,7 +38,7 @@ use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{release_specifiers_to_ranges, Version, MIN_VERSION};
 use uv_pep508::MarkerTree;
 use uv_platform_tags::Tags;
-use uv_pypi_types::{Requirement, ResolutionMetadata, VerbatimParsedUrl};
+use uv_pypi_types::{ConflictItem, Conflicts, Requirement, ResolutionMetadata, VerbatimParsedUrl};
 use uv_types::{BuildContext, HashStrategy, InstalledPackagesProvider};
 use uv_warnings::warn_user_once;
 
@@ -107,6 +107,8 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     dependency_mode: DependencyMode,
     hasher: HashStrategy,
     env: ResolverEnvironment,
+    /// The sets of extras and groups that can never be enabled together.
+    conflicts: Conflicts,
     python_requirement: PythonRequirement,
     workspace_members: BTreeSet<PackageName>,
     selector: CandidateSelector,
@@ -148,6 +150,7 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
         options: Options,
         python_requirement: &'a PythonRequirement,
         env: ResolverEnvironment,
+        conflicts: Conflicts,
         tags: Option<&'a Tags>,
         flat_index: &'a FlatIndex,
         index: &'a InMemoryIndex,
@@ -173,6 +176,7 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             options,
             hasher,
             env,
+            conflicts,
             python_requirement,
             index,
             build_context.git(),
@@ -193,6 +197,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         options: Options,
         hasher: &HashStrategy,
         env: ResolverEnvironment,
+        conflicts: Conflicts,
         python_requirement: &PythonRequirement,
         index: &InMemoryIndex,
         git: &GitResolver,
@@ -205,7 +210,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             index: index.clone(),
             git: git.clone(),
             capabilities: capabilities.clone(),
-            selector: CandidateSelector::for_resolution(options, &manifest, &env),
+            selector: CandidateSelector::for_resolution(&options, &manifest, &env),
             dependency_mode: options.dependency_mode,
             urls: Urls::from_manifest(&manifest, &env, git, options.dependency_mode)?,
             indexes: Indexes::from_manifest(&manifest, &env, options.dependency_mode),
@@ -220,6 +225,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             hasher: hasher.clone(),
             locations: locations.clone(),
             env,
+            conflicts,
             python_requirement: python_requirement.clone(),
             installed_packages,
             unavailable_packages: DashMap::default(),
@@ -602,7 +608,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             &self.git,
             &self.python_requirement,
             self.selector.resolution_strategy(),
-            self.options,
+            self.options.clone(),
         )
     }
 
@@ -691,7 +697,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 }
 
                 let markers = fork.markers.clone();
-                Some((fork, forked_state.with_env(&markers)?))
+                let mut forked_state = forked_state.with_env(&markers)?;
+                if !fork.exclude.is_empty() {
+                    forked_state.env = forked_state.env.exclude_by_group(fork.exclude.clone());
+                }
+                Some((fork, forked_state))
             })
             .map(move |(fork, mut forked_state)| {
                 forked_state.add_package_version_dependencies(
@@ -725,8 +735,12 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             // match any marker environments.
             .filter(|result| {
                 if let Ok(ref forked_state) = result {
-                    let markers = forked_state.env.try_markers().expect("is a fork");
-                    if markers.is_false() {
+                    // Forks on conflicting extras or groups may not narrow the markers at all.
+                    if forked_state
+                        .env
+                        .try_markers()
+                        .is_some_and(MarkerTree::is_false)
+                    {
                         return false;
                     }
                 }
@@ -1121,6 +1135,30 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             )));
         }
 
+        // If the user marked any environments as required, ensure that the distribution has a
+        // compatible wheel for each of them (within the current fork).
+        if env.marker_environment().is_none() {
+            if let Some(prioritized) = dist.prioritized() {
+                let implied_markers = prioritized.implied_markers();
+                if let Some(environment) = self
+                    .options
+                    .required_environments
+                    .as_markers()
+                    .iter()
+                    .find(|environment| {
+                        env.included(environment) && implied_markers.is_disjoint(environment)
+                    })
+                {
+                    return Ok(Some(ResolverVersion::Unavailable(
+                        candidate.version().clone(),
+                        UnavailableVersion::IncompatibleDist(IncompatibleDist::Wheel(
+                            IncompatibleWheel::MissingPlatform(environment.clone()),
+                        )),
+                    )));
+                }
+            }
+        }
+
         let filename = match dist.for_installation() {
             ResolvedDistRef::InstallableRegistrySourceDist { sdist, .. } => sdist
                 .filename()
@@ -1185,7 +1223,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 Dependencies::Unavailable(err) => ForkedDependencies::Unavailable(err),
             })
         } else {
-            Ok(result?.fork(python_requirement))
+            Ok(result?.fork(python_requirement, &self.conflicts))
         }
     }
 
@@ -1200,7 +1238,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         python_requirement: &PythonRequirement,
     ) -> Result<Dependencies, ResolveError> {
         let url = package.name().and_then(|name| fork_urls.get(name));
-        let dependencies = match &**package {
+        let mut dependencies = match &**package {
             PubGrubPackageInner::Root(_) => {
                 let no_dev_deps = BTreeMap::default();
                 let requirements = self.flatten_requirements(
@@ -1453,6 +1491,15 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 ))
             }
         };
+
+        // Drop any dependencies that enable an extra or group that this fork excludes.
+        dependencies.retain(|dependency| {
+            dependency
+                .package
+                .conflicting_item()
+                .map_or(true, |item| env.included_by_group(&item))
+        });
+
         Ok(Dependencies::Available(dependencies))
     }
 
@@ -2043,7 +2090,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             fork_urls,
             env,
             self.workspace_members.clone(),
-            self.options,
+            self.options.clone(),
         ))
     }
 
@@ -2664,7 +2711,14 @@ impl Dependencies {
     /// A fork *only* occurs when there are multiple dependencies with the same
     /// name *and* those dependency specifications have corresponding marker
     /// expressions that are completely disjoint with one another.
-    fn fork(self, python_requirement: &PythonRequirement) -> ForkedDependencies {
+    ///
+    /// A fork also occurs when the dependencies enable two or more extras (or
+    /// groups) that were declared as conflicting with one another.
+    fn fork(
+        self,
+        python_requirement: &PythonRequirement,
+        conflicts: &Conflicts,
+    ) -> ForkedDependencies {
         let deps = match self {
             Dependencies::Available(deps) => deps,
             Dependencies::Unforkable(deps) => return ForkedDependencies::Unforked(deps),
@@ -2682,7 +2736,7 @@ impl Dependencies {
         let Forks {
             mut forks,
             diverging_packages,
-        } = Forks::new(name_to_deps, python_requirement);
+        } = Forks::new(name_to_deps, python_requirement, conflicts);
         if forks.is_empty() {
             ForkedDependencies::Unforked(vec![])
         } else if forks.len() == 1 {
@@ -2743,12 +2797,14 @@ impl Forks {
     fn new(
         name_to_deps: BTreeMap<PackageName, Vec<PubGrubDependency>>,
         python_requirement: &PythonRequirement,
+        conflicts: &Conflicts,
     ) -> Forks {
         let python_marker = python_requirement.to_marker_tree();
 
         let mut forks = vec![Fork {
             dependencies: vec![],
             markers: MarkerTree::TRUE,
+            exclude: vec![],
         }];
         let mut diverging_packages = BTreeSet::new();
         for (name, mut deps) in name_to_deps {
@@ -2838,6 +2894,43 @@ impl Forks {
                 forks = new;
             }
         }
+
+        // Split any fork that enables two or more extras (or groups) from the same set of
+        // conflicts, such that each of the new forks enables at most one of them.
+        //
+        // Each new fork is tagged with a conflict marker, such that the forks remain disjoint
+        // in the lockfile: the fork for the first item applies unless any of the other items is
+        // enabled (which includes the case in which none of them are), while the fork for each
+        // of the other items applies only when that item is enabled.
+        for set in conflicts.iter() {
+            let mut new = vec![];
+            for fork in std::mem::take(&mut forks) {
+                let enabled = set
+                    .iter()
+                    .filter(|item| fork.enables(item))
+                    .collect::<Vec<_>>();
+                if enabled.len() < 2 {
+                    new.push(fork);
+                    continue;
+                }
+                for (i, item) in enabled.into_iter().enumerate() {
+                    let mut new_fork = fork.clone();
+                    let mut conflict_marker = if i == 0 {
+                        MarkerTree::TRUE
+                    } else {
+                        item.to_marker()
+                    };
+                    for other in set.iter().filter(|other| *other != item) {
+                        conflict_marker.and(other.to_marker().negate());
+                    }
+                    new_fork.markers.and(conflict_marker);
+                    new_fork.exclude(set.iter().filter(|other| *other != item).cloned());
+                    new.push(new_fork);
+                }
+            }
+            forks = new;
+        }
+
         Forks {
             forks,
             diverging_packages,
@@ -2873,6 +2966,13 @@ struct Fork {
     ///
     /// (This doesn't include any marker expressions from a parent fork.)
     markers: MarkerTree,
+    /// The conflicting extras and groups that are excluded from this fork.
+    ///
+    /// For example, given conflicting extras `foo[bar]` and `foo[baz]`, the
+    /// fork that enables `foo[bar]` excludes `foo[baz]` (and vice versa).
+    ///
+    /// (This doesn't include any exclusions from a parent fork.)
+    exclude: Vec<ConflictItem>,
 }
 
 impl Fork {
@@ -2885,6 +2985,25 @@ impl Fork {
             !self.markers.is_disjoint(markers)
         });
     }
+
+    /// Returns `true` if any dependency in this fork enables the given conflicting extra or
+    /// group.
+    fn enables(&self, item: &ConflictItem) -> bool {
+        self.dependencies
+            .iter()
+            .any(|dep| dep.package.conflicting_item().as_ref() == Some(item))
+    }
+
+    /// Exclude the given conflicting extras and groups from this fork, dropping any dependencies
+    /// that would enable them.
+    fn exclude(&mut self, items: impl IntoIterator<Item = ConflictItem>) {
+        self.exclude.extend(items);
+        self.dependencies.retain(|dep| {
+            dep.package
+                .conflicting_item()
+                .map_or(true, |item| !self.exclude.contains(&item))
+        });
+    }
 }
 
 impl Ord for Fork {
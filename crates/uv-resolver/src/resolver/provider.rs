@@ -1,5 +1,6 @@
 use std::future::Future;
 
+use uv_client::MetadataFormat;
 use uv_configuration::BuildOptions;
 use uv_distribution::{ArchiveMetadata, DistributionDatabase};
 use uv_distribution_types::{Dist, IndexCapabilities, IndexUrl};
@@ -131,8 +132,8 @@ impl<'a, Context: BuildContext> ResolverProvider for DefaultResolverProvider<'a,
             Ok(results) => Ok(VersionsResponse::Found(
                 results
                     .into_iter()
-                    .map(|(index, metadata)| {
-                        VersionMap::from_metadata(
+                    .filter_map(|(index, metadata)| match metadata {
+                        MetadataFormat::Simple(metadata) => Some(VersionMap::from_metadata(
                             metadata,
                             package_name,
                             index,
@@ -143,7 +144,16 @@ impl<'a, Context: BuildContext> ResolverProvider for DefaultResolverProvider<'a,
                             self.exclude_newer.as_ref(),
                             self.flat_index.get(package_name).cloned(),
                             self.build_options,
+                        )),
+                        MetadataFormat::Flat(entries) => FlatIndex::from_entries(
+                            entries,
+                            self.tags.as_ref(),
+                            &self.hasher,
+                            self.build_options,
                         )
+                        .get(package_name)
+                        .cloned()
+                        .map(VersionMap::from),
                     })
                     .collect(),
             )),
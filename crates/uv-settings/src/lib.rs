@@ -274,12 +274,27 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
             "default-groups",
         ));
     }
+    if options.conflicts.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "conflicts"));
+    }
+    if options.required_environments.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "required-environments",
+        ));
+    }
     if options.managed.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "managed"));
     }
     if options.package.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "package"));
     }
+    if options.build_backend.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "build-backend",
+        ));
+    }
     Ok(())
 }
 
@@ -73,6 +73,11 @@ pub struct Options {
     /// to include the current Git commit hash in the cache key (in addition to the
     /// `pyproject.toml`). Git tags are also supported via `cache-keys = [{ git = { commit = true, tags = true } }]`.
     ///
+    /// Cache keys can also include environment variables. For example, if a project relies on
+    /// `CMAKE_ARGS` to configure its native extensions, you can specify `cache-keys = [{ env = "CMAKE_ARGS" }, { file = "pyproject.toml" }]`
+    /// to ensure that the project is rebuilt whenever the value of `CMAKE_ARGS` changes (in
+    /// addition to watching the `pyproject.toml`).
+    ///
     /// Cache keys only affect the project defined by the `pyproject.toml` in which they're
     /// specified (as opposed to, e.g., affecting all members in a workspace), and all paths and
     /// globs are interpreted as relative to the project directory.
@@ -112,11 +117,23 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub default_groups: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub conflicts: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub required_environments: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub managed: Option<serde::de::IgnoredAny>,
 
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub r#package: Option<serde::de::IgnoredAny>,
+
+    // NOTE: The build backend settings are read by `uv-build-backend` directly from the
+    // `pyproject.toml`. They're only respected in `pyproject.toml` files, and should be rejected in
+    // `uv.toml` files.
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub build_backend: Option<serde::de::IgnoredAny>,
 }
 
 impl Options {
@@ -183,6 +200,22 @@ pub struct GlobalOptions {
         "#
     )]
     pub cache_dir: Option<PathBuf>,
+    /// The URL of a remote cache for wheels built from source distributions.
+    ///
+    /// When set, uv will query the remote cache for a compatible wheel before building a source
+    /// distribution, and upload any wheels it builds to the remote cache, such that builds can be
+    /// shared across machines (e.g., in continuous integration).
+    ///
+    /// The remote cache is expected to be an HTTP server that supports `GET` and `PUT` requests
+    /// for entries nested under the given URL.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            remote-cache-url = "https://cache.example.com/uv/"
+        "#
+    )]
+    pub remote_cache_url: Option<Url>,
     /// Whether to enable experimental, preview features.
     #[option(
         default = "false",
@@ -1505,6 +1538,7 @@ pub struct OptionsWire {
     offline: Option<bool>,
     no_cache: Option<bool>,
     cache_dir: Option<PathBuf>,
+    remote_cache_url: Option<Url>,
     preview: Option<bool>,
     python_preference: Option<PythonPreference>,
     python_downloads: Option<PythonDownloads>,
@@ -1563,8 +1597,11 @@ pub struct OptionsWire {
     sources: Option<serde::de::IgnoredAny>,
     managed: Option<serde::de::IgnoredAny>,
     r#package: Option<serde::de::IgnoredAny>,
+    conflicts: Option<serde::de::IgnoredAny>,
+    required_environments: Option<serde::de::IgnoredAny>,
     default_groups: Option<serde::de::IgnoredAny>,
     dev_dependencies: Option<serde::de::IgnoredAny>,
+    build_backend: Option<serde::de::IgnoredAny>,
 }
 
 impl From<OptionsWire> for Options {
@@ -1574,6 +1611,7 @@ impl From<OptionsWire> for Options {
             offline,
             no_cache,
             cache_dir,
+            remote_cache_url,
             preview,
             python_preference,
             python_downloads,
@@ -1617,8 +1655,11 @@ impl From<OptionsWire> for Options {
             sources,
             default_groups,
             dev_dependencies,
+            conflicts,
+            required_environments,
             managed,
             package,
+            build_backend,
         } = value;
 
         Self {
@@ -1627,6 +1668,7 @@ impl From<OptionsWire> for Options {
                 offline,
                 no_cache,
                 cache_dir,
+                remote_cache_url,
                 preview,
                 python_preference,
                 python_downloads,
@@ -1676,8 +1718,11 @@ impl From<OptionsWire> for Options {
             sources,
             dev_dependencies,
             default_groups,
+            conflicts,
+            required_environments,
             managed,
             package,
+            build_backend,
         }
     }
 }
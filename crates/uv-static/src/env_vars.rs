@@ -35,6 +35,11 @@ impl EnvVars {
     /// cache for any operations.
     pub const UV_NO_CACHE: &'static str = "UV_NO_CACHE";
 
+    /// The URL of a remote cache for wheels built from source distributions. If set, uv will
+    /// query the remote cache before building a source distribution, and upload the built wheel
+    /// to the remote cache afterwards.
+    pub const UV_REMOTE_CACHE_URL: &'static str = "UV_REMOTE_CACHE_URL";
+
     /// Equivalent to the `--resolution` command-line argument. For example, if set to
     /// `lowest-direct`, uv will install the lowest compatible versions of all direct dependencies.
     pub const UV_RESOLUTION: &'static str = "UV_RESOLUTION";
@@ -149,6 +154,10 @@ impl EnvVars {
     /// Don't upload a file if it already exists on the index. The value is the URL of the index.
     pub const UV_PUBLISH_CHECK_URL: &'static str = "UV_PUBLISH_CHECK_URL";
 
+    /// Equivalent to the `--index` command-line argument in `uv publish`. If
+    /// set, uv will publish to the index with this name in the configuration.
+    pub const UV_PUBLISH_INDEX: &'static str = "UV_PUBLISH_INDEX";
+
     /// Equivalent to the `--no-sync` command-line argument. If set, uv will skip updating
     /// the environment.
     pub const UV_NO_SYNC: &'static str = "UV_NO_SYNC";
@@ -161,6 +170,10 @@ impl EnvVars {
     /// updating the `uv.lock` file.
     pub const UV_FROZEN: &'static str = "UV_FROZEN";
 
+    /// Equivalent to the `--database` command-line argument for `uv audit`. If set, uv will read
+    /// security advisories from this directory rather than the cache.
+    pub const UV_AUDIT_DATABASE: &'static str = "UV_AUDIT_DATABASE";
+
     /// Equivalent to the `--preview` argument. Enables preview mode.
     pub const UV_PREVIEW: &'static str = "UV_PREVIEW";
 
@@ -194,6 +207,9 @@ impl EnvVars {
     /// Specifies the "bin" directory for installing tool executables.
     pub const UV_TOOL_BIN_DIR: &'static str = "UV_TOOL_BIN_DIR";
 
+    /// Specifies the directory where uv stores credentials saved with `uv auth login`.
+    pub const UV_CREDENTIALS_DIR: &'static str = "UV_CREDENTIALS_DIR";
+
     /// Specifies the path to the directory to use for a project virtual environment.
     /// See the [project documentation](../concepts/projects.md#configuring-the-project-environment-path)
     /// for more details.
@@ -221,6 +237,17 @@ impl EnvVars {
     /// Distributions can be read from a local directory by using the `file://` URL scheme.
     pub const UV_PYPY_INSTALL_MIRROR: &'static str = "UV_PYPY_INSTALL_MIRROR";
 
+    /// A path or URL to a JSON file describing the available managed Python downloads, in the
+    /// same format as uv's built-in
+    /// [`download-metadata.json`](https://github.com/astral-sh/uv/blob/main/crates/uv-python/download-metadata.json).
+    /// The downloads are merged with the built-in downloads, taking precedence over any built-in
+    /// download for the same version and platform.
+    pub const UV_PYTHON_DOWNLOADS_JSON_URL: &'static str = "UV_PYTHON_DOWNLOADS_JSON_URL";
+
+    /// If set to `1` or `true`, the downloads provided via `UV_PYTHON_DOWNLOADS_JSON_URL` replace
+    /// the built-in managed Python downloads, rather than being merged with them.
+    pub const UV_PYTHON_DOWNLOADS_JSON_REPLACE: &'static str = "UV_PYTHON_DOWNLOADS_JSON_REPLACE";
+
     /// Used to override `PATH` to limit Python executable availability in the test suite.
     #[attr_hidden]
     pub const UV_TEST_PYTHON_PATH: &'static str = "UV_TEST_PYTHON_PATH";
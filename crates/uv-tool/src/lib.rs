@@ -15,15 +15,18 @@ use tracing::{debug, warn};
 
 use uv_install_wheel::read_record_file;
 
+pub use manifest::{ToolManifest, ToolManifestEntry};
 pub use receipt::ToolReceipt;
 pub use tool::{Tool, ToolEntrypoint};
 use uv_cache::Cache;
 use uv_fs::{LockedFile, Simplified};
 use uv_installer::SitePackages;
 use uv_python::{Interpreter, PythonEnvironment};
+use uv_resolver::{Lock, VERSION};
 use uv_state::{StateBucket, StateStore};
 use uv_static::EnvVars;
 
+mod manifest;
 mod receipt;
 mod tool;
 
@@ -55,6 +58,16 @@ pub enum Error {
     MissingToolPackage(PackageName),
     #[error(transparent)]
     Serialization(#[from] toml_edit::ser::Error),
+    #[error("Failed to read lockfile at {0}")]
+    LockRead(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Failed to write lockfile at {0}: {1}")]
+    LockWrite(PathBuf, String),
+    #[error("Unsupported lockfile version at {0}; expected version {1}, but found version {2}")]
+    UnsupportedLockVersion(PathBuf, u32, u32),
+    #[error("Failed to read tool manifest at {0}")]
+    ManifestRead(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Tool `{0}` is declared multiple times in the tool manifest at {1}")]
+    DuplicateManifestEntry(PackageName, PathBuf),
 }
 
 /// A collection of uv-managed tools installed on the current system.
@@ -140,6 +153,44 @@ impl InstalledTools {
         }
     }
 
+    /// Get the lockfile for the given tool.
+    ///
+    /// If the tool does not have a lockfile (e.g., it was installed by an older version of uv),
+    /// returns `Ok(None)`.
+    ///
+    /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
+    pub fn get_tool_lock(&self, name: &PackageName) -> Result<Option<Lock>, Error> {
+        let path = self.tool_dir(name).join("uv.lock");
+        match read_lock(&path) {
+            Ok(lock) => Ok(Some(lock)),
+            Err(Error::Io(err)) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Add a lockfile for a tool, recording the resolution of its environment.
+    ///
+    /// Any existing lockfile will be replaced.
+    ///
+    /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
+    pub fn add_tool_lock(&self, name: &PackageName, lock: &Lock) -> Result<(), Error> {
+        let path = self.tool_dir(name).join("uv.lock");
+
+        debug!(
+            "Adding lockfile for tool `{name}` at {}",
+            path.user_display()
+        );
+
+        let doc = lock
+            .to_toml()
+            .map_err(|err| Error::LockWrite(path.clone(), err.to_string()))?;
+
+        // Save the modified `uv.lock`.
+        fs_err::write(&path, doc)?;
+
+        Ok(())
+    }
+
     /// Grab a file lock for the tools directory to prevent concurrent access across processes.
     pub async fn lock(&self) -> Result<LockedFile, Error> {
         Ok(LockedFile::acquire(self.root.join(".lock"), self.root.user_display()).await?)
@@ -354,6 +405,21 @@ impl fmt::Display for InstalledTool {
     }
 }
 
+/// Read a tool lockfile (i.e., a `uv.lock`) from the given path.
+pub fn read_lock(path: &Path) -> Result<Lock, Error> {
+    let contents = fs_err::read_to_string(path)?;
+    let lock = toml::from_str::<Lock>(&contents)
+        .map_err(|err| Error::LockRead(path.to_path_buf(), Box::new(err)))?;
+    if lock.version() != VERSION {
+        return Err(Error::UnsupportedLockVersion(
+            path.to_path_buf(),
+            VERSION,
+            lock.version(),
+        ));
+    }
+    Ok(lock)
+}
+
 /// Find the tool executable directory.
 pub fn tool_executable_dir() -> Result<PathBuf, Error> {
     user_executable_directory(Some(EnvVars::UV_TOOL_BIN_DIR)).ok_or(Error::NoExecutableDirectory)
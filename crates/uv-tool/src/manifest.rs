@@ -0,0 +1,86 @@
+use std::path::Path;
+
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use uv_pep440::VersionSpecifiers;
+use uv_pep508::PackageName;
+use uv_settings::ToolOptions;
+
+use crate::Error;
+
+/// A manifest declaring the set of tools to install, as consumed by `uv tool sync`.
+///
+/// For example:
+///
+/// ```toml
+/// [[tool]]
+/// name = "black"
+/// version = ">=24,<25"
+/// with = ["black[d]"]
+/// python = "3.12"
+/// options = { prerelease = "allow" }
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ToolManifest {
+    #[serde(default, rename = "tool")]
+    tools: Vec<ToolManifestEntry>,
+}
+
+/// A single tool in a [`ToolManifest`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ToolManifestEntry {
+    /// The name of the package providing the tool.
+    pub name: PackageName,
+    /// The version specifiers for the tool, e.g., `>=24,<25`.
+    #[serde(default)]
+    pub version: Option<VersionSpecifiers>,
+    /// Additional requirements to include in the tool environment, as in `--with`.
+    #[serde(default)]
+    pub with: Vec<String>,
+    /// The Python interpreter to use to build the tool environment, as in `--python`.
+    #[serde(default)]
+    pub python: Option<String>,
+    /// The [`ToolOptions`] to use when installing the tool.
+    #[serde(default)]
+    pub options: ToolOptions,
+}
+
+impl ToolManifest {
+    /// Read a [`ToolManifest`] from the given path.
+    pub fn from_path(path: &Path) -> Result<Self, Error> {
+        let contents = fs_err::read_to_string(path)?;
+        let manifest = toml::from_str::<Self>(&contents)
+            .map_err(|err| Error::ManifestRead(path.to_path_buf(), Box::new(err)))?;
+
+        // Each tool may only be declared once.
+        let mut seen = FxHashSet::default();
+        for tool in &manifest.tools {
+            if !seen.insert(&tool.name) {
+                return Err(Error::DuplicateManifestEntry(
+                    tool.name.clone(),
+                    path.to_path_buf(),
+                ));
+            }
+        }
+
+        Ok(manifest)
+    }
+
+    /// Returns the tools declared in the manifest.
+    pub fn tools(&self) -> &[ToolManifestEntry] {
+        &self.tools
+    }
+}
+
+impl ToolManifestEntry {
+    /// Returns the requirement for the tool, e.g., `black>=24,<25`.
+    pub fn requirement(&self) -> String {
+        match &self.version {
+            Some(version) => format!("{}{version}", self.name),
+            None => self.name.to_string(),
+        }
+    }
+}
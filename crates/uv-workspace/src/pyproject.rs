@@ -24,7 +24,10 @@ use uv_macros::OptionsMetadata;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::MarkerTree;
-use uv_pypi_types::{RequirementSource, SupportedEnvironments, VerbatimParsedUrl};
+use uv_pypi_types::{
+    Conflicts, RequirementSource, SchemaConflicts, SupportedEnvironments, VerbatimParsedUrl,
+};
+use uv_warnings::warn_user_once;
 
 #[derive(Error, Debug)]
 pub enum PyprojectTomlError {
@@ -90,6 +93,26 @@ impl PyProjectToml {
         self.build_system.is_some()
     }
 
+    /// Returns the set of conflicts for the project.
+    ///
+    /// Conflicting items that omit a package name are attributed to the project itself.
+    pub fn conflicts(&self) -> Conflicts {
+        let empty = Conflicts::default();
+        let Some(project) = self.project.as_ref() else {
+            return empty;
+        };
+        let Some(tool) = self.tool.as_ref() else {
+            return empty;
+        };
+        let Some(tooluv) = tool.uv.as_ref() else {
+            return empty;
+        };
+        let Some(conflicting) = tooluv.conflicts.as_ref() else {
+            return empty;
+        };
+        conflicting.to_conflicts_with_package_name(&project.name)
+    }
+
     /// Returns whether the project manifest contains any script table.
     pub fn has_scripts(&self) -> bool {
         if let Some(ref project) = self.project {
@@ -199,6 +222,12 @@ pub struct Project {
     pub dependencies: Option<Vec<String>>,
     /// The optional dependencies of the project.
     pub optional_dependencies: Option<BTreeMap<ExtraName, Vec<String>>>,
+    /// The license of the project.
+    ///
+    /// Only used for exports, so an unsupported shape is ignored with a warning rather than
+    /// rejecting the entire `pyproject.toml`.
+    #[serde(default, skip_serializing, deserialize_with = "deserialize_license")]
+    pub license: Option<License>,
 
     /// Used to determine whether a `gui-scripts` section is present.
     #[serde(default, skip_serializing)]
@@ -208,6 +237,39 @@ pub struct Project {
     pub(crate) scripts: Option<serde::de::IgnoredAny>,
 }
 
+/// The license of a project, either as an SPDX license expression (PEP 639) or as a table with
+/// the license text or a path to the license file (PEP 621).
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum License {
+    Spdx(String),
+    Text { text: String },
+    File { file: String },
+}
+
+/// Deserialize `project.license`, warning on and ignoring any unsupported value.
+fn deserialize_license<'de, D>(deserializer: D) -> Result<Option<License>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum MaybeLicense {
+        License(License),
+        Unsupported(serde::de::IgnoredAny),
+    }
+
+    match MaybeLicense::deserialize(deserializer)? {
+        MaybeLicense::License(license) => Ok(Some(license)),
+        MaybeLicense::Unsupported(_) => {
+            warn_user_once!(
+                "Ignoring unsupported `project.license` value; expected an SPDX expression, or a table with `text` or `file`"
+            );
+            Ok(None)
+        }
+    }
+}
+
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(test, derive(Serialize))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -439,6 +501,80 @@ pub struct ToolUv {
         "#
     )]
     pub environments: Option<SupportedEnvironments>,
+
+    /// A list of environments for which the lockfile must include a compatible wheel.
+    ///
+    /// By default, uv will lock any version of a package that satisfies the project's
+    /// requirements, even if the package doesn't publish wheels for every platform on which the
+    /// project is used. For example, a package that only publishes `x86_64` wheels would need to be
+    /// built from source on Linux ARM.
+    ///
+    /// When `required-environments` is set, `uv lock` will only select versions that publish a
+    /// wheel compatible with each required environment, falling back to older versions as
+    /// necessary, and will fail to resolve if no such version exists.
+    ///
+    /// Packages that only publish a source distribution are assumed to be buildable in any
+    /// environment.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<Vec<String>>",
+            description = "A list of environment markers, e.g., `sys_platform == 'darwin'`."
+        )
+    )]
+    #[option(
+        default = "[]",
+        value_type = "str | list[str]",
+        example = r#"
+            # Require that the package is available for macOS ARM and x86 (Intel).
+            required-environments = [
+                "sys_platform == 'darwin' and platform_machine == 'arm64'",
+                "sys_platform == 'darwin' and platform_machine == 'x86_64'",
+            ]
+        "#
+    )]
+    pub required_environments: Option<SupportedEnvironments>,
+
+    /// Conflicting extras or groups may be declared here.
+    ///
+    /// It's useful to declare conflicts when, for example, two or more extras
+    /// have mutually incompatible dependencies. For example, extra `foo`
+    /// might depend on `numpy==2.0.0` while extra `bar` might depend on
+    /// `numpy==2.1.0`. These extras cannot be activated at the same time.
+    /// This usually isn't a problem for pip-style workflows, but when using
+    /// uv project support with universal resolution, it will try to produce
+    /// a resolution that satisfies both extras simultaneously.
+    ///
+    /// When this happens, resolution will fail, because one cannot install
+    /// both `numpy 2.0.0` and `numpy 2.1.0` into the same environment.
+    ///
+    /// To work around this, you may specify `foo` and `bar` as conflicting
+    /// extras. When doing universal resolution in project mode, these extras
+    /// will get their own "forks" distinct from one another in order to permit
+    /// conflicting dependencies. In exchange, if one tries to install from the
+    /// lock file with both conflicting extras activated, installation will
+    /// fail.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<Vec<Vec<BTreeMap<String, String>>>>",
+            description = "A list of sets of conflicting extras or groups, e.g., `[[{ extra = \"cpu\" }, { extra = \"cu121\" }]]`."
+        )
+    )]
+    #[option(
+        default = "[]",
+        value_type = "list[list[dict]]",
+        example = r#"
+            # Require that `package[cpu]` and `package[cu121]` are never installed together.
+            conflicts = [
+                [
+                  { extra = "cpu" },
+                  { extra = "cu121" },
+                ],
+            ]
+        "#
+    )]
+    pub conflicts: Option<SchemaConflicts>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
@@ -241,7 +241,9 @@ async fn albatross_root_workspace() {
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
-                  "environments": null
+                  "environments": null,
+                  "required-environments": null,
+                  "conflicts": null
                 }
               },
               "dependency-groups": null
@@ -332,7 +334,9 @@ async fn albatross_virtual_workspace() {
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
-                  "environments": null
+                  "environments": null,
+                  "required-environments": null,
+                  "conflicts": null
                 }
               },
               "dependency-groups": null
@@ -537,7 +541,9 @@ async fn exclude_package() -> Result<()> {
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
-                  "environments": null
+                  "environments": null,
+                  "required-environments": null,
+                  "conflicts": null
                 }
               },
               "dependency-groups": null
@@ -640,7 +646,9 @@ async fn exclude_package() -> Result<()> {
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
-                  "environments": null
+                  "environments": null,
+                  "required-environments": null,
+                  "conflicts": null
                 }
               },
               "dependency-groups": null
@@ -756,7 +764,9 @@ async fn exclude_package() -> Result<()> {
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
-                  "environments": null
+                  "environments": null,
+                  "required-environments": null,
+                  "conflicts": null
                 }
               },
               "dependency-groups": null
@@ -846,7 +856,9 @@ async fn exclude_package() -> Result<()> {
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
-                  "environments": null
+                  "environments": null,
+                  "required-environments": null,
+                  "conflicts": null
                 }
               },
               "dependency-groups": null
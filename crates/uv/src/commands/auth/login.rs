@@ -0,0 +1,55 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Context, Result};
+use console::Term;
+use owo_colors::OwoColorize;
+
+use uv_auth::{CredentialStore, Credentials};
+use uv_distribution_types::IndexLocations;
+use uv_fs::Simplified;
+
+use crate::commands::auth::{display_realm, service_url};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Store credentials for a service in the uv credential store.
+pub(crate) fn login(
+    service: &str,
+    username: Option<String>,
+    password: Option<String>,
+    index_locations: &IndexLocations,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let url = service_url(service, index_locations)?;
+
+    let term = Term::stderr();
+    let username = match username {
+        Some(username) => username,
+        None if term.is_term() => {
+            let prompt = "Enter username ('__token__' if using a token): ";
+            uv_console::input(prompt, &term).context("Failed to read username")?
+        }
+        None => bail!("No username provided; did you mean to provide `--username` or `--token`?"),
+    };
+    let password = match password {
+        Some(password) => password,
+        None if term.is_term() => {
+            let prompt = "Enter password: ";
+            uv_console::password(prompt, &term).context("Failed to read password")?
+        }
+        None => bail!("No password provided; did you mean to provide `--password` or `--token`?"),
+    };
+
+    let mut store = CredentialStore::from_settings()?;
+    store.insert(&url, &Credentials::new(Some(username), Some(password)));
+    store.write()?;
+
+    writeln!(
+        printer.stderr(),
+        "Stored credentials for {} in: {}",
+        display_realm(&url).cyan(),
+        store.path().user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
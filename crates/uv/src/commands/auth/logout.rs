@@ -0,0 +1,34 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use uv_auth::CredentialStore;
+use uv_distribution_types::IndexLocations;
+
+use crate::commands::auth::{display_realm, service_url};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Remove the credentials for a service from the uv credential store.
+pub(crate) fn logout(
+    service: &str,
+    index_locations: &IndexLocations,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let url = service_url(service, index_locations)?;
+
+    let mut store = CredentialStore::from_settings()?;
+    if store.remove(&url).is_none() {
+        bail!("No credentials found for: `{}`", display_realm(&url));
+    }
+    store.write()?;
+
+    writeln!(
+        printer.stderr(),
+        "Removed credentials for {}",
+        display_realm(&url).cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,41 @@
+use anyhow::{Context, Result};
+use url::Url;
+
+use uv_distribution_types::IndexLocations;
+
+pub(crate) mod login;
+pub(crate) mod logout;
+pub(crate) mod token;
+
+/// Resolve a service to a URL.
+///
+/// The service may either be the name of a configured index, or a URL. If the URL omits a scheme,
+/// `https` is assumed.
+fn service_url(service: &str, index_locations: &IndexLocations) -> Result<Url> {
+    if let Some(index) = index_locations.allowed_indexes().into_iter().find(|index| {
+        index
+            .name
+            .as_ref()
+            .is_some_and(|name| name.as_ref() == service)
+    }) {
+        return Ok(index.raw_url().clone());
+    }
+
+    let url = if service.contains("://") {
+        Url::parse(service)
+    } else {
+        Url::parse(&format!("https://{service}"))
+    }
+    .with_context(|| format!("Invalid service: `{service}`. Expected an index name or a URL."))?;
+
+    if url.host_str().is_none() {
+        anyhow::bail!("Invalid service: `{service}`. Expected an index name or a URL.");
+    }
+
+    Ok(url)
+}
+
+/// Return a human-readable representation of the realm of a URL, e.g., `https://example.com`.
+fn display_realm(url: &Url) -> String {
+    url.origin().ascii_serialization()
+}
@@ -0,0 +1,31 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+
+use uv_auth::CredentialStore;
+use uv_distribution_types::IndexLocations;
+
+use crate::commands::auth::{display_realm, service_url};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Show the stored token (or password) for a service.
+pub(crate) fn token(
+    service: &str,
+    index_locations: &IndexLocations,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let url = service_url(service, index_locations)?;
+
+    let store = CredentialStore::from_settings()?;
+    let Some(password) = store
+        .get(&url, None)
+        .and_then(|credentials| credentials.password().map(ToString::to_string))
+    else {
+        bail!("No credentials found for: `{}`", display_realm(&url));
+    };
+
+    writeln!(printer.stdout(), "{password}")?;
+
+    Ok(ExitStatus::Success)
+}
@@ -29,18 +29,34 @@ pub(crate) fn build_wheel(
 }
 
 pub(crate) fn build_editable(
-    _wheel_directory: &Path,
-    _metadata_directory: Option<&Path>,
+    wheel_directory: &Path,
+    metadata_directory: Option<&Path>,
 ) -> Result<ExitStatus> {
-    todo!()
+    let filename = uv_build_backend::build_editable(
+        &env::current_dir()?,
+        wheel_directory,
+        metadata_directory,
+        uv_version::version(),
+    )?;
+    println!("{filename}");
+    Ok(ExitStatus::Success)
+}
+
+/// Print the additional build requirements as JSON list on a single line.
+fn get_requires_for_build() -> Result<ExitStatus> {
+    let requires =
+        uv_build_backend::get_requires_for_build(&env::current_dir()?, uv_version::version())?;
+    let requires: Vec<String> = requires.iter().map(ToString::to_string).collect();
+    println!("{}", serde_json::to_string(&requires)?);
+    Ok(ExitStatus::Success)
 }
 
 pub(crate) fn get_requires_for_build_sdist() -> Result<ExitStatus> {
-    todo!()
+    get_requires_for_build()
 }
 
 pub(crate) fn get_requires_for_build_wheel() -> Result<ExitStatus> {
-    todo!()
+    get_requires_for_build()
 }
 pub(crate) fn prepare_metadata_for_build_wheel(metadata_directory: &Path) -> Result<ExitStatus> {
     let filename = uv_build_backend::metadata(
@@ -53,9 +69,15 @@ pub(crate) fn prepare_metadata_for_build_wheel(metadata_directory: &Path) -> Res
 }
 
 pub(crate) fn get_requires_for_build_editable() -> Result<ExitStatus> {
-    todo!()
+    get_requires_for_build()
 }
 
-pub(crate) fn prepare_metadata_for_build_editable(_wheel_directory: &Path) -> Result<ExitStatus> {
-    todo!()
+pub(crate) fn prepare_metadata_for_build_editable(metadata_directory: &Path) -> Result<ExitStatus> {
+    let filename = uv_build_backend::metadata(
+        &env::current_dir()?,
+        metadata_directory,
+        uv_version::version(),
+    )?;
+    println!("{filename}");
+    Ok(ExitStatus::Success)
 }
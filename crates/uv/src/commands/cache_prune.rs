@@ -3,14 +3,20 @@ use std::fmt::Write;
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 
-use uv_cache::{Cache, Removal};
+use uv_cache::{Cache, Eviction, Removal};
 use uv_fs::Simplified;
 
 use crate::commands::{human_readable_bytes, ExitStatus};
 use crate::printer::Printer;
 
-/// Prune all unreachable objects from the cache.
-pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+/// Prune all unreachable objects from the cache, evicting any entries that fail to meet the
+/// [`Eviction`] criteria.
+pub(crate) fn cache_prune(
+    ci: bool,
+    eviction: Eviction,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
     if !cache.root().exists() {
         writeln!(
             printer.stderr(),
@@ -28,6 +34,14 @@ pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<E
 
     let mut summary = Removal::default();
 
+    // Evict the least-recently-used entries, before pruning any entries that referenced them.
+    summary += cache.evict(eviction).with_context(|| {
+        format!(
+            "Failed to evict from cache at: {}",
+            cache.root().user_display()
+        )
+    })?;
+
     // Prune the source distribution cache, which is tightly coupled to the builder crate.
     summary += uv_distribution::prune(cache)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
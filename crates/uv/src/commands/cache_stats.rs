@@ -0,0 +1,138 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_cli::CacheStatsFormat;
+use uv_fs::Simplified;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// The number of packages to display in the list of largest packages.
+const LARGEST_PACKAGES: usize = 10;
+
+/// Show statistics on the contents of the cache.
+pub(crate) fn cache_stats(
+    format: CacheStatsFormat,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let pointers = uv_distribution::archive_pointers(cache)
+        .with_context(|| format!("Failed to read cache at: {}", cache.root().user_display()))?;
+    let stats = cache
+        .stats(&pointers)
+        .with_context(|| format!("Failed to read cache at: {}", cache.root().user_display()))?;
+
+    match format {
+        CacheStatsFormat::Json => {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&stats)?
+            )?;
+        }
+        CacheStatsFormat::Text => {
+            writeln!(
+                printer.stdout(),
+                "Cache at: {} ({})",
+                cache.root().user_display().cyan(),
+                format_bytes(stats.total_bytes).green()
+            )?;
+
+            // Display the size of each bucket.
+            if !stats.buckets.is_empty() {
+                writeln!(printer.stdout())?;
+                writeln!(printer.stdout(), "{}", "Buckets:".bold())?;
+                let width = stats
+                    .buckets
+                    .iter()
+                    .map(|bucket| bucket.name.len())
+                    .max()
+                    .unwrap_or_default();
+                for bucket in &stats.buckets {
+                    writeln!(
+                        printer.stdout(),
+                        "  {:width$}  {:>8} files  {}",
+                        bucket.name,
+                        bucket.files,
+                        format_bytes(bucket.bytes)
+                    )?;
+                }
+            }
+
+            // Display the number of unzipped wheels, and any that are no longer referenced.
+            writeln!(printer.stdout())?;
+            writeln!(
+                printer.stdout(),
+                "{} {} ({})",
+                "Unzipped wheels:".bold(),
+                stats.archives.count,
+                format_bytes(stats.archives.bytes)
+            )?;
+            let unreferenced = stats
+                .archives
+                .unreferenced
+                .iter()
+                .map(|archive| archive.bytes)
+                .sum::<u64>();
+            writeln!(
+                printer.stdout(),
+                "{} {} ({})",
+                "Unreferenced wheels:".bold(),
+                stats.archives.unreferenced.len(),
+                format_bytes(unreferenced)
+            )?;
+            for archive in &stats.archives.unreferenced {
+                writeln!(
+                    printer.stdout(),
+                    "  {} ({})",
+                    archive.id,
+                    format_bytes(archive.bytes)
+                )?;
+            }
+
+            // Display the largest packages.
+            if !stats.packages.is_empty() {
+                writeln!(printer.stdout())?;
+                writeln!(printer.stdout(), "{}", "Largest packages:".bold())?;
+                let packages = &stats.packages[..stats.packages.len().min(LARGEST_PACKAGES)];
+                let width = packages
+                    .iter()
+                    .map(|package| package.name.as_ref().len())
+                    .max()
+                    .unwrap_or_default();
+                for package in packages {
+                    writeln!(
+                        printer.stdout(),
+                        "  {:width$}  {}",
+                        package.name.to_string(),
+                        format_bytes(package.bytes)
+                    )?;
+                }
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Format a number of bytes for display, e.g., `512B` or `1.5MiB`.
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
@@ -7,8 +7,9 @@ use tracing::{debug, enabled, Level};
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, HashCheckingMode,
-    IndexStrategy, LowerBound, Reinstall, SourceStrategy, TrustedHost, Upgrade,
+    BuildOptions, Concurrency, ConfigSettings, Constraints, DevGroupsSpecification,
+    ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions, LowerBound, Reinstall,
+    SourceStrategy, TrustedHost, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::BuildDispatch;
@@ -20,14 +21,14 @@ use uv_fs::Simplified;
 use uv_install_wheel::linker::LinkMode;
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_pep508::PackageName;
-use uv_pypi_types::Requirement;
+use uv_pypi_types::{Conflicts, Requirement};
 use uv_python::{
     EnvironmentPreference, Prefix, PythonEnvironment, PythonRequest, PythonVersion, Target,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverEnvironment,
+    DependencyMode, ExcludeNewer, FlatIndex, InstallTarget, OptionsBuilder, PrereleaseMode,
+    PythonRequirement, ResolutionMode, ResolverEnvironment,
 };
 use uv_types::{BuildIsolation, HashStrategy};
 
@@ -48,6 +49,8 @@ pub(crate) async fn pip_install(
     constraints_from_workspace: Vec<Requirement>,
     overrides_from_workspace: Vec<Requirement>,
     extras: &ExtrasSpecification,
+    groups: DevGroupsSpecification,
+    install_options: &InstallOptions,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
     dependency_mode: DependencyMode,
@@ -91,6 +94,16 @@ pub(crate) async fn pip_install(
         .keyring(keyring_provider)
         .allow_insecure_host(allow_insecure_host.to_vec());
 
+    // If a `uv.lock` was provided, install from the lockfile directly, rather than resolving.
+    let lock = operations::read_lock(requirements, constraints, overrides).await?;
+
+    // Dependency groups can only be selected from a lockfile.
+    if lock.is_none() && groups.iter().next().is_some() {
+        return Err(anyhow::anyhow!(
+            "Requesting dependency groups requires a `uv.lock` file"
+        ));
+    }
+
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
         project,
@@ -105,14 +118,18 @@ pub(crate) async fn pip_install(
         no_binary,
         no_build,
         extras: _,
-    } = operations::read_requirements(
-        requirements,
-        constraints,
-        overrides,
-        extras,
-        &client_builder,
-    )
-    .await?;
+    } = if lock.is_some() {
+        RequirementsSpecification::default()
+    } else {
+        operations::read_requirements(
+            requirements,
+            constraints,
+            overrides,
+            extras,
+            &client_builder,
+        )
+        .await?
+    };
 
     // Read build constraints.
     let build_constraints =
@@ -203,7 +220,8 @@ pub(crate) async fn pip_install(
     // Check if the current environment satisfies the requirements.
     // Ideally, the resolver would be fast enough to let us remove this check. But right now, for large environments,
     // it's an order of magnitude faster to validate the environment than to resolve the requirements.
-    if reinstall.is_none()
+    if lock.is_none()
+        && reinstall.is_none()
         && upgrade.is_none()
         && source_trees.is_empty()
         && overrides.is_empty()
@@ -250,8 +268,30 @@ pub(crate) async fn pip_install(
         interpreter,
     )?;
 
+    // If installing from a lockfile, the resolution is known upfront.
+    let locked_resolution = if let Some((install_path, lock)) = &lock {
+        operations::validate_lock_python(lock, &marker_env)?;
+        let target = InstallTarget::Lock { install_path, lock };
+        Some(target.to_resolution(
+            &marker_env,
+            &tags,
+            extras,
+            &groups.with_defaults(Vec::new()),
+            &build_options,
+            install_options,
+        )?)
+    } else {
+        None
+    };
+
     // Collect the set of required hashes.
-    let hasher = if let Some(hash_checking) = hash_checking {
+    let hasher = if let Some(resolution) = &locked_resolution {
+        // Verify the hashes recorded in the lockfile.
+        HashStrategy::from_resolution(
+            resolution,
+            hash_checking.unwrap_or(HashCheckingMode::Verify),
+        )?
+    } else if let Some(hash_checking) = hash_checking {
         HashStrategy::from_requirements(
             requirements
                 .iter()
@@ -383,59 +423,67 @@ pub(crate) async fn pip_install(
         .build();
 
     // Resolve the requirements.
-    let resolution = match operations::resolve(
-        requirements,
-        constraints,
-        overrides,
-        dev,
-        source_trees,
-        project,
-        None,
-        extras,
-        preferences,
-        site_packages.clone(),
-        &hasher,
-        &reinstall,
-        &upgrade,
-        Some(&tags),
-        ResolverEnvironment::specific(marker_env.clone()),
-        python_requirement,
-        &client,
-        &flat_index,
-        &state.index,
-        &build_dispatch,
-        concurrency,
-        options,
-        Box::new(DefaultResolveLogger),
-        printer,
-    )
-    .await
-    {
-        Ok(resolution) => Resolution::from(resolution),
-        Err(operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err))) => {
-            diagnostics::no_solution(&err);
-            return Ok(ExitStatus::Failure);
-        }
-        Err(operations::Error::Resolve(uv_resolver::ResolveError::DownloadAndBuild(dist, err))) => {
-            diagnostics::download_and_build(dist, err);
-            return Ok(ExitStatus::Failure);
-        }
-        Err(operations::Error::Resolve(uv_resolver::ResolveError::Build(dist, err))) => {
-            diagnostics::build(dist, err);
-            return Ok(ExitStatus::Failure);
-        }
-        Err(operations::Error::Requirements(uv_requirements::Error::DownloadAndBuild(
-            dist,
-            err,
-        ))) => {
-            diagnostics::download_and_build(dist, err);
-            return Ok(ExitStatus::Failure);
-        }
-        Err(operations::Error::Requirements(uv_requirements::Error::Build(dist, err))) => {
-            diagnostics::build(dist, err);
-            return Ok(ExitStatus::Failure);
+    let resolution = if let Some(resolution) = locked_resolution {
+        resolution
+    } else {
+        match operations::resolve(
+            requirements,
+            constraints,
+            overrides,
+            dev,
+            source_trees,
+            project,
+            None,
+            extras,
+            preferences,
+            site_packages.clone(),
+            &hasher,
+            &reinstall,
+            &upgrade,
+            Some(&tags),
+            ResolverEnvironment::specific(marker_env.clone()),
+            python_requirement,
+            Conflicts::empty(),
+            &client,
+            &flat_index,
+            &state.index,
+            &build_dispatch,
+            concurrency,
+            options,
+            Box::new(DefaultResolveLogger),
+            printer,
+        )
+        .await
+        {
+            Ok(resolution) => Resolution::from(resolution),
+            Err(operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err))) => {
+                diagnostics::no_solution(&err);
+                return Ok(ExitStatus::Failure);
+            }
+            Err(operations::Error::Resolve(uv_resolver::ResolveError::DownloadAndBuild(
+                dist,
+                err,
+            ))) => {
+                diagnostics::download_and_build(dist, err);
+                return Ok(ExitStatus::Failure);
+            }
+            Err(operations::Error::Resolve(uv_resolver::ResolveError::Build(dist, err))) => {
+                diagnostics::build(dist, err);
+                return Ok(ExitStatus::Failure);
+            }
+            Err(operations::Error::Requirements(uv_requirements::Error::DownloadAndBuild(
+                dist,
+                err,
+            ))) => {
+                diagnostics::download_and_build(dist, err);
+                return Ok(ExitStatus::Failure);
+            }
+            Err(operations::Error::Requirements(uv_requirements::Error::Build(dist, err))) => {
+                diagnostics::build(dist, err);
+                return Ok(ExitStatus::Failure);
+            }
+            Err(err) => return Err(err.into()),
         }
-        Err(err) => return Err(err.into()),
     };
 
     // Sync the environment.
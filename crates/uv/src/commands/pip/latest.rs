@@ -1,6 +1,6 @@
-use uv_client::{RegistryClient, VersionFiles};
+use uv_client::{MetadataFormat, RegistryClient, VersionFiles};
 use uv_distribution_filename::DistFilename;
-use uv_distribution_types::{IndexCapabilities, IndexUrl};
+use uv_distribution_types::{File, IndexCapabilities, IndexUrl};
 use uv_normalize::PackageName;
 use uv_platform_tags::Tags;
 use uv_resolver::{ExcludeNewer, PrereleaseMode, RequiresPython};
@@ -40,93 +40,123 @@ impl<'env> LatestClient<'env> {
         };
 
         let mut latest: Option<DistFilename> = None;
-        for (_, archive) in archives {
-            for datum in archive.iter().rev() {
-                // Find the first compatible distribution.
-                let files = rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
-                    .expect("archived version files always deserializes");
+        for (_, metadata) in archives {
+            match metadata {
+                MetadataFormat::Simple(archive) => {
+                    for datum in archive.iter().rev() {
+                        // Find the first compatible distribution.
+                        let files =
+                            rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
+                                .expect("archived version files always deserializes");
 
-                // Determine whether there's a compatible wheel and/or source distribution.
-                let mut best = None;
+                        // Determine whether there's a compatible wheel and/or source distribution.
+                        let mut best = None;
 
-                for (filename, file) in files.all() {
-                    // Skip distributions uploaded after the cutoff.
-                    if let Some(exclude_newer) = self.exclude_newer {
-                        match file.upload_time_utc_ms.as_ref() {
-                            Some(&upload_time)
-                                if upload_time >= exclude_newer.timestamp_millis() =>
-                            {
+                        for (filename, file) in files.all() {
+                            if !self.is_compatible(&filename, &file) {
                                 continue;
                             }
-                            None => {
-                                warn_user_once!(
-                                    "{} is missing an upload date, but user provided: {exclude_newer}",
-                                    file.filename,
-                                );
+
+                            match filename {
+                                DistFilename::WheelFilename(_) => {
+                                    best = Some(filename);
+                                    break;
+                                }
+                                DistFilename::SourceDistFilename(_) => {
+                                    if best.is_none() {
+                                        best = Some(filename);
+                                    }
+                                }
                             }
-                            _ => {}
                         }
-                    }
 
-                    // Skip pre-release distributions.
-                    if !filename.version().is_stable() {
-                        if !matches!(self.prerelease, PrereleaseMode::Allow) {
-                            continue;
+                        match (latest.as_ref(), best) {
+                            (Some(current), Some(best)) => {
+                                if best.version() > current.version() {
+                                    latest = Some(best);
+                                }
+                            }
+                            (None, Some(best)) => {
+                                latest = Some(best);
+                            }
+                            _ => {}
                         }
                     }
-
-                    // Skip distributions that are yanked.
-                    if file.yanked.is_some_and(|yanked| yanked.is_yanked()) {
-                        continue;
-                    }
-
-                    // Skip distributions that are incompatible with the Python requirement.
-                    if file
-                        .requires_python
-                        .as_ref()
-                        .is_some_and(|requires_python| {
-                            !self.requires_python.is_contained_by(requires_python)
-                        })
-                    {
-                        continue;
-                    }
-
-                    // Skip distributions that are incompatible with the current platform.
-                    if let DistFilename::WheelFilename(filename) = &filename {
-                        if self
-                            .tags
-                            .is_some_and(|tags| !filename.compatibility(tags).is_compatible())
-                        {
+                }
+                MetadataFormat::Flat(entries) => {
+                    for (filename, file, _) in entries.entries {
+                        if !self.is_compatible(&filename, &file) {
                             continue;
                         }
-                    }
 
-                    match filename {
-                        DistFilename::WheelFilename(_) => {
-                            best = Some(filename);
-                            break;
-                        }
-                        DistFilename::SourceDistFilename(_) => {
-                            if best.is_none() {
-                                best = Some(filename);
-                            }
+                        // Prefer the latest version and, within a version, wheels over source
+                        // distributions.
+                        let is_wheel = |filename: &DistFilename| {
+                            matches!(filename, DistFilename::WheelFilename(_))
+                        };
+                        if latest.as_ref().map_or(true, |current| {
+                            (filename.version(), is_wheel(&filename))
+                                > (current.version(), is_wheel(current))
+                        }) {
+                            latest = Some(filename);
                         }
                     }
                 }
+            }
+        }
+        Ok(latest)
+    }
 
-                match (latest.as_ref(), best) {
-                    (Some(current), Some(best)) => {
-                        if best.version() > current.version() {
-                            latest = Some(best);
-                        }
-                    }
-                    (None, Some(best)) => {
-                        latest = Some(best);
-                    }
-                    _ => {}
+    /// Returns `true` if the distribution is compatible with the current platform, the Python
+    /// requirement, and the user's settings.
+    fn is_compatible(&self, filename: &DistFilename, file: &File) -> bool {
+        // Skip distributions uploaded after the cutoff.
+        if let Some(exclude_newer) = self.exclude_newer {
+            match file.upload_time_utc_ms.as_ref() {
+                Some(&upload_time) if upload_time >= exclude_newer.timestamp_millis() => {
+                    return false;
                 }
+                None => {
+                    warn_user_once!(
+                        "{} is missing an upload date, but user provided: {exclude_newer}",
+                        file.filename,
+                    );
+                }
+                _ => {}
             }
         }
-        Ok(latest)
+
+        // Skip pre-release distributions.
+        if !filename.version().is_stable() {
+            if !matches!(self.prerelease, PrereleaseMode::Allow) {
+                return false;
+            }
+        }
+
+        // Skip distributions that are yanked.
+        if file.yanked.is_some_and(|yanked| yanked.is_yanked()) {
+            return false;
+        }
+
+        // Skip distributions that are incompatible with the Python requirement.
+        if file
+            .requires_python
+            .as_ref()
+            .is_some_and(|requires_python| !self.requires_python.is_contained_by(requires_python))
+        {
+            return false;
+        }
+
+        // Skip distributions that are incompatible with the current platform.
+        if let DistFilename::WheelFilename(filename) = &filename {
+            if self
+                .tags
+                .is_some_and(|tags| !filename.compatibility(tags).is_compatible())
+            {
+                return false;
+            }
+        }
+
+        true
     }
 }
@@ -5,7 +5,7 @@ use itertools::Itertools;
 use owo_colors::OwoColorize;
 use std::collections::{BTreeSet, HashSet};
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tracing::debug;
 use uv_tool::InstalledTools;
 
@@ -29,15 +29,15 @@ use uv_install_wheel::linker::LinkMode;
 use uv_installer::{Plan, Planner, Preparer, SitePackages};
 use uv_normalize::{GroupName, PackageName};
 use uv_platform_tags::Tags;
-use uv_pypi_types::ResolverMarkerEnvironment;
+use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment};
 use uv_python::PythonEnvironment;
 use uv_requirements::{
     LookaheadResolver, NamedRequirementsResolver, RequirementsSource, RequirementsSpecification,
     SourceTreeResolver,
 };
 use uv_resolver::{
-    DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options, Preference,
-    Preferences, PythonRequirement, ResolutionGraph, Resolver, ResolverEnvironment,
+    DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Lock, Manifest, Options, Preference,
+    Preferences, PythonRequirement, ResolutionGraph, Resolver, ResolverEnvironment, VERSION,
 };
 use uv_types::{HashStrategy, InFlight, InstalledPackagesProvider};
 use uv_warnings::warn_user;
@@ -86,6 +86,71 @@ pub(crate) async fn read_constraints(
     )
 }
 
+/// Read a `uv.lock` to install from directly, if it was provided as the sole requirements source.
+///
+/// Returns the lockfile along with the directory that contains it, against which any relative
+/// paths in the lockfile are resolved.
+pub(crate) async fn read_lock(
+    requirements: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    overrides: &[RequirementsSource],
+) -> Result<Option<(PathBuf, Lock)>, Error> {
+    let [RequirementsSource::UvLock(path)] = requirements else {
+        return Ok(None);
+    };
+
+    // The lockfile is already resolved, so constraints and overrides can't be applied.
+    if !constraints.is_empty() || !overrides.is_empty() {
+        return Err(anyhow!(
+            "Constraints and overrides are not supported when installing from a `uv.lock`"
+        )
+        .into());
+    }
+
+    let encoded = match fs_err::tokio::read_to_string(path).await {
+        Ok(encoded) => encoded,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(anyhow!("File not found: `{}`", path.user_display()).into());
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let lock = toml::from_str::<Lock>(&encoded)
+        .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+
+    // If the lockfile uses an unsupported version, raise an error.
+    if lock.version() != VERSION {
+        return Err(anyhow!(
+            "The lockfile at `{}` uses an unsupported schema version (v{}, but only v{VERSION} is supported)",
+            path.user_display(),
+            lock.version(),
+        )
+        .into());
+    }
+
+    let install_path = std::path::absolute(path)?
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    Ok(Some((install_path, lock)))
+}
+
+/// Validate that the target Python version is supported by a lockfile read with [`read_lock`].
+pub(crate) fn validate_lock_python(
+    lock: &Lock,
+    marker_env: &ResolverMarkerEnvironment,
+) -> Result<(), Error> {
+    let python_version = &marker_env.python_full_version().version;
+    if !lock.requires_python().contains(python_version) {
+        return Err(anyhow!(
+            "The current Python version ({python_version}) is not compatible with the locked Python requirement: `{}`",
+            lock.requires_python(),
+        )
+        .into());
+    }
+    Ok(())
+}
+
 /// Resolve a set of requirements, similar to running `pip compile`.
 pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     requirements: Vec<UnresolvedRequirementSpecification>,
@@ -104,6 +169,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     tags: Option<&Tags>,
     resolver_env: ResolverEnvironment,
     python_requirement: PythonRequirement,
+    conflicts: Conflicts,
     client: &RegistryClient,
     flat_index: &FlatIndex,
     index: &InMemoryIndex,
@@ -290,6 +356,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
             options,
             &python_requirement,
             resolver_env,
+            conflicts,
             tags,
             flat_index,
             index,
@@ -7,8 +7,9 @@ use tracing::debug;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, HashCheckingMode,
-    IndexStrategy, LowerBound, Reinstall, SourceStrategy, TrustedHost, Upgrade,
+    BuildOptions, Concurrency, ConfigSettings, Constraints, DevGroupsSpecification,
+    ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions, LowerBound, Reinstall,
+    SourceStrategy, TrustedHost, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::BuildDispatch;
@@ -17,13 +18,14 @@ use uv_fs::Simplified;
 use uv_install_wheel::linker::LinkMode;
 use uv_installer::SitePackages;
 use uv_pep508::PackageName;
+use uv_pypi_types::Conflicts;
 use uv_python::{
     EnvironmentPreference, Prefix, PythonEnvironment, PythonRequest, PythonVersion, Target,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverEnvironment,
+    DependencyMode, ExcludeNewer, FlatIndex, InstallTarget, OptionsBuilder, PrereleaseMode,
+    PythonRequirement, ResolutionMode, ResolverEnvironment,
 };
 use uv_types::{BuildIsolation, HashStrategy};
 
@@ -40,6 +42,9 @@ pub(crate) async fn pip_sync(
     requirements: &[RequirementsSource],
     constraints: &[RequirementsSource],
     build_constraints: &[RequirementsSource],
+    extras: &ExtrasSpecification,
+    groups: DevGroupsSpecification,
+    install_options: &InstallOptions,
     reinstall: Reinstall,
     link_mode: LinkMode,
     compile: bool,
@@ -79,12 +84,26 @@ pub(crate) async fn pip_sync(
 
     // Initialize a few defaults.
     let overrides = &[];
-    let extras = ExtrasSpecification::default();
     let upgrade = Upgrade::default();
     let resolution_mode = ResolutionMode::default();
     let prerelease_mode = PrereleaseMode::default();
     let dependency_mode = DependencyMode::Direct;
 
+    // If a `uv.lock` was provided, install from the lockfile directly, rather than resolving.
+    let lock = operations::read_lock(requirements, constraints, overrides).await?;
+
+    // Extras and dependency groups can only be selected from a lockfile.
+    if lock.is_none() && !extras.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Requesting extras requires a `uv.lock` file"
+        ));
+    }
+    if lock.is_none() && groups.iter().next().is_some() {
+        return Err(anyhow::anyhow!(
+            "Requesting dependency groups requires a `uv.lock` file"
+        ));
+    }
+
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
         project,
@@ -99,21 +118,25 @@ pub(crate) async fn pip_sync(
         no_binary,
         no_build,
         extras: _,
-    } = operations::read_requirements(
-        requirements,
-        constraints,
-        overrides,
-        &extras,
-        &client_builder,
-    )
-    .await?;
+    } = if lock.is_some() {
+        RequirementsSpecification::default()
+    } else {
+        operations::read_requirements(
+            requirements,
+            constraints,
+            overrides,
+            extras,
+            &client_builder,
+        )
+        .await?
+    };
 
     // Read build constraints.
     let build_constraints =
         operations::read_constraints(build_constraints, &client_builder).await?;
 
     // Validate that the requirements are non-empty.
-    if !allow_empty_requirements {
+    if !allow_empty_requirements && lock.is_none() {
         let num_requirements = requirements.len() + source_trees.len();
         if num_requirements == 0 {
             writeln!(printer.stderr(), "No requirements found (hint: use `--allow-empty-requirements` to clear the environment)")?;
@@ -193,8 +216,30 @@ pub(crate) async fn pip_sync(
         interpreter,
     )?;
 
+    // If installing from a lockfile, the resolution is known upfront.
+    let locked_resolution = if let Some((install_path, lock)) = &lock {
+        operations::validate_lock_python(lock, &marker_env)?;
+        let target = InstallTarget::Lock { install_path, lock };
+        Some(target.to_resolution(
+            &marker_env,
+            &tags,
+            extras,
+            &groups.with_defaults(Vec::new()),
+            &build_options,
+            install_options,
+        )?)
+    } else {
+        None
+    };
+
     // Collect the set of required hashes.
-    let hasher = if let Some(hash_checking) = hash_checking {
+    let hasher = if let Some(resolution) = &locked_resolution {
+        // Verify the hashes recorded in the lockfile.
+        HashStrategy::from_resolution(
+            resolution,
+            hash_checking.unwrap_or(HashCheckingMode::Verify),
+        )?
+    } else if let Some(hash_checking) = hash_checking {
         HashStrategy::from_requirements(
             requirements
                 .iter()
@@ -327,59 +372,67 @@ pub(crate) async fn pip_sync(
         .index_strategy(index_strategy)
         .build();
 
-    let resolution = match operations::resolve(
-        requirements,
-        constraints,
-        overrides,
-        dev,
-        source_trees,
-        project,
-        None,
-        &extras,
-        preferences,
-        site_packages.clone(),
-        &hasher,
-        &reinstall,
-        &upgrade,
-        Some(&tags),
-        ResolverEnvironment::specific(marker_env.clone()),
-        python_requirement,
-        &client,
-        &flat_index,
-        &state.index,
-        &build_dispatch,
-        concurrency,
-        options,
-        Box::new(DefaultResolveLogger),
-        printer,
-    )
-    .await
-    {
-        Ok(resolution) => Resolution::from(resolution),
-        Err(operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err))) => {
-            diagnostics::no_solution(&err);
-            return Ok(ExitStatus::Failure);
-        }
-        Err(operations::Error::Resolve(uv_resolver::ResolveError::DownloadAndBuild(dist, err))) => {
-            diagnostics::download_and_build(dist, err);
-            return Ok(ExitStatus::Failure);
-        }
-        Err(operations::Error::Resolve(uv_resolver::ResolveError::Build(dist, err))) => {
-            diagnostics::build(dist, err);
-            return Ok(ExitStatus::Failure);
-        }
-        Err(operations::Error::Requirements(uv_requirements::Error::DownloadAndBuild(
-            dist,
-            err,
-        ))) => {
-            diagnostics::download_and_build(dist, err);
-            return Ok(ExitStatus::Failure);
-        }
-        Err(operations::Error::Requirements(uv_requirements::Error::Build(dist, err))) => {
-            diagnostics::build(dist, err);
-            return Ok(ExitStatus::Failure);
+    let resolution = if let Some(resolution) = locked_resolution {
+        resolution
+    } else {
+        match operations::resolve(
+            requirements,
+            constraints,
+            overrides,
+            dev,
+            source_trees,
+            project,
+            None,
+            extras,
+            preferences,
+            site_packages.clone(),
+            &hasher,
+            &reinstall,
+            &upgrade,
+            Some(&tags),
+            ResolverEnvironment::specific(marker_env.clone()),
+            python_requirement,
+            Conflicts::empty(),
+            &client,
+            &flat_index,
+            &state.index,
+            &build_dispatch,
+            concurrency,
+            options,
+            Box::new(DefaultResolveLogger),
+            printer,
+        )
+        .await
+        {
+            Ok(resolution) => Resolution::from(resolution),
+            Err(operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err))) => {
+                diagnostics::no_solution(&err);
+                return Ok(ExitStatus::Failure);
+            }
+            Err(operations::Error::Resolve(uv_resolver::ResolveError::DownloadAndBuild(
+                dist,
+                err,
+            ))) => {
+                diagnostics::download_and_build(dist, err);
+                return Ok(ExitStatus::Failure);
+            }
+            Err(operations::Error::Resolve(uv_resolver::ResolveError::Build(dist, err))) => {
+                diagnostics::build(dist, err);
+                return Ok(ExitStatus::Failure);
+            }
+            Err(operations::Error::Requirements(uv_requirements::Error::DownloadAndBuild(
+                dist,
+                err,
+            ))) => {
+                diagnostics::download_and_build(dist, err);
+                return Ok(ExitStatus::Failure);
+            }
+            Err(operations::Error::Requirements(uv_requirements::Error::Build(dist, err))) => {
+                diagnostics::build(dist, err);
+                return Ok(ExitStatus::Failure);
+            }
+            Err(err) => return Err(err.into()),
         }
-        Err(err) => return Err(err.into()),
     };
 
     // Sync the environment.
@@ -0,0 +1,534 @@
+use std::fmt::Write;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result};
+use futures::TryStreamExt;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use tokio_util::compat::FuturesAsyncReadCompatExt;
+use tracing::debug;
+use url::Url;
+
+use uv_cache::{Cache, CacheBucket, Refresh};
+use uv_client::{BaseClientBuilder, Connectivity};
+use uv_configuration::TrustedHost;
+use uv_fs::{LockedFile, Simplified};
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace};
+
+use crate::commands::project::ProjectError;
+use crate::commands::{project, ExitStatus};
+use crate::printer::Printer;
+
+/// The archive of all PyPI advisories published by the OSV project.
+const OSV_PYPI_URL: &str = "https://osv-vulnerabilities.storage.googleapis.com/PyPI/all.zip";
+
+/// The maximum age of the cached advisory database before it's downloaded again.
+const MAX_DATABASE_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Audit the project's `uv.lock` against a database of known vulnerabilities.
+pub(crate) async fn audit(
+    project_dir: &Path,
+    database: Option<PathBuf>,
+    ignore: &[String],
+    connectivity: Connectivity,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Find the workspace.
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+
+    // Read the existing lockfile.
+    let Some(lock) = project::lock::read(&workspace).await? else {
+        return Err(ProjectError::MissingLockfile.into());
+    };
+
+    // Load the advisory database, fetching it if necessary.
+    let database = match database {
+        Some(database) => AdvisoryDatabase::from_dir(&database)?,
+        None => {
+            // Lock the cached database while it's fetched and read, such that a concurrent
+            // `uv audit` can't observe it while it's being replaced.
+            let bucket = cache.bucket(CacheBucket::Advisories);
+            fs_err::tokio::create_dir_all(&bucket).await?;
+            let _lock = LockedFile::acquire(bucket.join(".lock"), bucket.user_display()).await?;
+
+            let database = fetch_database(
+                connectivity,
+                native_tls,
+                allow_insecure_host,
+                cache,
+                printer,
+            )
+            .await?;
+            AdvisoryDatabase::from_dir(&database)?
+        }
+    };
+
+    // Match each package against the database.
+    let mut audited = 0;
+    let mut findings = Vec::new();
+    for package in lock.packages() {
+        // Only packages from a registry correspond to releases in the advisory database; a local
+        // project may share its name with an unrelated package on PyPI.
+        if package.index(workspace.install_path())?.is_none() {
+            continue;
+        }
+        audited += 1;
+
+        let vulnerabilities = database
+            .get(package.name())
+            .iter()
+            .filter(|vulnerability| vulnerability.affects(package.version()))
+            .filter(|vulnerability| !vulnerability.is_ignored(ignore))
+            .collect::<Vec<_>>();
+        if !vulnerabilities.is_empty() {
+            findings.push((package, vulnerabilities));
+        }
+    }
+
+    if findings.is_empty() {
+        let s = if audited == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!("Audited {audited} package{s}; no known vulnerabilities found").dimmed()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for (package, vulnerabilities) in &findings {
+        writeln!(
+            printer.stdout(),
+            "{} {}",
+            package.name().bold(),
+            package.version().bold()
+        )?;
+        for vulnerability in vulnerabilities {
+            match &vulnerability.summary {
+                Some(summary) => {
+                    writeln!(printer.stdout(), "  {}: {summary}", vulnerability.id.red())?
+                }
+                None => writeln!(printer.stdout(), "  {}", vulnerability.id.red())?,
+            }
+            if !vulnerability.aliases.is_empty() {
+                writeln!(
+                    printer.stdout(),
+                    "    Aliases: {}",
+                    vulnerability.aliases.join(", ")
+                )?;
+            }
+            let affected = vulnerability
+                .ranges
+                .iter()
+                .filter(|range| range.contains(package.version()))
+                .map(|range| {
+                    if range.is_empty() {
+                        "*".to_string()
+                    } else {
+                        range.to_string()
+                    }
+                })
+                .collect::<Vec<_>>();
+            if !affected.is_empty() {
+                writeln!(
+                    printer.stdout(),
+                    "    Affected versions: {}",
+                    affected.join(" || ")
+                )?;
+            }
+            if vulnerability.fixed.is_empty() {
+                writeln!(printer.stdout(), "    Fixed in: (no fix available)")?;
+            } else {
+                let fixed = vulnerability
+                    .fixed
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>();
+                writeln!(printer.stdout(), "    Fixed in: {}", fixed.join(", "))?;
+            }
+        }
+    }
+
+    let vulnerabilities = findings
+        .iter()
+        .map(|(_, vulnerabilities)| vulnerabilities.len())
+        .sum::<usize>();
+    let packages = findings.len();
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Found {vulnerabilities} known {} in {packages} {} (audited {audited})",
+            if vulnerabilities == 1 {
+                "vulnerability"
+            } else {
+                "vulnerabilities"
+            },
+            if packages == 1 { "package" } else { "packages" },
+        )
+        .bold()
+    )?;
+
+    Ok(ExitStatus::Failure)
+}
+
+/// Return the path to the advisory database in the cache, downloading it if it's missing, older
+/// than [`MAX_DATABASE_AGE`], or if a refresh was requested.
+///
+/// The caller must hold the lock on the [`CacheBucket::Advisories`] bucket.
+async fn fetch_database(
+    connectivity: Connectivity,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+) -> Result<PathBuf> {
+    let bucket = cache.bucket(CacheBucket::Advisories);
+    let database = bucket.join("pypi");
+
+    // The database is replaced atomically, so its modification time is the time of the download.
+    let age = fs_err::metadata(&database)
+        .ok()
+        .filter(std::fs::Metadata::is_dir)
+        .and_then(|metadata| metadata.modified().ok())
+        .map(|modified| {
+            SystemTime::now()
+                .duration_since(modified)
+                .unwrap_or_default()
+        });
+
+    let refresh = matches!(cache.refresh(), Refresh::All(_));
+    if let Some(age) = age {
+        if !refresh && age <= MAX_DATABASE_AGE {
+            debug!(
+                "Using cached advisory database at: {}",
+                database.user_display()
+            );
+            return Ok(database);
+        }
+    }
+
+    if connectivity.is_offline() {
+        if let Some(age) = age {
+            warn_user!(
+                "Network connectivity is disabled; using the cached advisory database, which was downloaded {} ago",
+                format_age(age)
+            );
+            return Ok(database);
+        }
+        return Err(anyhow::anyhow!(
+            "No advisory database found in the cache, and network connectivity is disabled. Provide a pre-fetched database with `--database`."
+        ));
+    }
+
+    writeln!(
+        printer.stderr(),
+        "{}",
+        "Downloading advisory database...".dimmed()
+    )?;
+
+    let client = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .allow_insecure_host(allow_insecure_host.to_vec())
+        .build();
+
+    let url = Url::parse(OSV_PYPI_URL)?;
+    let response = client
+        .for_host(&url)
+        .get(url.clone())
+        .send()
+        .await
+        .with_context(|| format!("Failed to download advisory database from: {url}"))?
+        .error_for_status()
+        .with_context(|| format!("Failed to download advisory database from: {url}"))?;
+    let reader = response
+        .bytes_stream()
+        .map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+        .into_async_read();
+
+    // Unzip into a temporary directory, then move it into place.
+    fs_err::tokio::create_dir_all(&bucket).await?;
+    let temp_dir = tempfile::tempdir_in(&bucket)?;
+    uv_extract::stream::unzip(reader.compat(), temp_dir.path()).await?;
+
+    // Move the existing database aside, rather than removing it, such that it can be restored if
+    // the new database can't be moved into place. It's removed when `previous` is dropped.
+    let previous = tempfile::tempdir_in(&bucket)?;
+    let previous_database = previous.path().join("pypi");
+    if database.is_dir() {
+        fs_err::tokio::rename(&database, &previous_database).await?;
+    }
+    if let Err(err) = fs_err::tokio::rename(temp_dir.path(), &database).await {
+        if previous_database.is_dir() {
+            fs_err::tokio::rename(&previous_database, &database).await?;
+        }
+        return Err(err.into());
+    }
+
+    Ok(database)
+}
+
+/// Format the age of the advisory database in the largest whole unit, e.g., `3 days`.
+fn format_age(age: Duration) -> String {
+    let hours = age.as_secs() / (60 * 60);
+    let (value, unit) = if hours >= 24 {
+        (hours / 24, "day")
+    } else {
+        (hours, "hour")
+    };
+    let s = if value == 1 { "" } else { "s" };
+    format!("{value} {unit}{s}")
+}
+
+/// A database of vulnerabilities, indexed by package name.
+#[derive(Debug, Default)]
+struct AdvisoryDatabase(FxHashMap<PackageName, Vec<Vulnerability>>);
+
+impl AdvisoryDatabase {
+    /// Load a database from a directory of OSV advisories, one JSON document per file.
+    fn from_dir(path: &Path) -> Result<Self> {
+        let entries = fs_err::read_dir(path).with_context(|| {
+            format!(
+                "Failed to read advisory database at: `{}`",
+                path.user_display()
+            )
+        })?;
+
+        let mut database = Self::default();
+        for entry in entries {
+            let path = entry?.path();
+            if !path
+                .extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("json"))
+            {
+                continue;
+            }
+            let contents = fs_err::read(&path)?;
+            let advisory = match serde_json::from_slice::<Advisory>(&contents) {
+                Ok(advisory) => advisory,
+                Err(err) => {
+                    // A single malformed advisory shouldn't prevent auditing against the rest.
+                    warn_user!(
+                        "Skipping malformed advisory `{}`: {err}",
+                        path.user_display()
+                    );
+                    continue;
+                }
+            };
+            database.insert(advisory);
+        }
+
+        debug!(
+            "Loaded advisories for {} packages from: {}",
+            database.0.len(),
+            path.user_display()
+        );
+
+        Ok(database)
+    }
+
+    /// Add an OSV advisory to the database.
+    fn insert(&mut self, advisory: Advisory) {
+        // Withdrawn advisories are retained in the database, but no longer apply.
+        if advisory.withdrawn.is_some() {
+            return;
+        }
+
+        let mut vulnerabilities = FxHashMap::<PackageName, Vulnerability>::default();
+        for affected in advisory.affected {
+            let Some(package) = affected.package else {
+                continue;
+            };
+            if package.ecosystem != "PyPI" {
+                continue;
+            }
+            let Ok(name) = PackageName::from_str(&package.name) else {
+                debug!(
+                    "Skipping advisory `{}` with invalid package name: {}",
+                    advisory.id, package.name
+                );
+                continue;
+            };
+
+            let vulnerability = vulnerabilities
+                .entry(name)
+                .or_insert_with(|| Vulnerability {
+                    id: advisory.id.clone(),
+                    summary: advisory.summary.clone(),
+                    aliases: advisory.aliases.clone(),
+                    ranges: Vec::new(),
+                    versions: Vec::new(),
+                    fixed: Vec::new(),
+                });
+
+            for range in affected.ranges {
+                if range.kind != RangeKind::Ecosystem {
+                    continue;
+                }
+                vulnerability.extend(&advisory.id, &range.events);
+            }
+
+            vulnerability.versions.extend(
+                affected
+                    .versions
+                    .iter()
+                    .filter_map(|version| Version::from_str(version).ok()),
+            );
+        }
+
+        for (name, mut vulnerability) in vulnerabilities {
+            vulnerability.fixed.sort();
+            vulnerability.fixed.dedup();
+            self.0.entry(name).or_default().push(vulnerability);
+        }
+    }
+
+    /// Return the vulnerabilities that have been reported for a given package.
+    fn get(&self, name: &PackageName) -> &[Vulnerability] {
+        self.0.get(name).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+/// A vulnerability that affects a single package.
+#[derive(Debug)]
+struct Vulnerability {
+    /// The ID of the advisory, e.g., `GHSA-h5c8-rqwp-cp95` or `PYSEC-2024-1`.
+    id: String,
+    /// A one-line summary of the vulnerability.
+    summary: Option<String>,
+    /// Other IDs for the same vulnerability, e.g., `CVE-2024-22195`.
+    aliases: Vec<String>,
+    /// The ranges of affected versions.
+    ranges: Vec<VersionSpecifiers>,
+    /// The affected versions that are enumerated explicitly.
+    versions: Vec<Version>,
+    /// The versions in which the vulnerability was fixed.
+    fixed: Vec<Version>,
+}
+
+impl Vulnerability {
+    /// Add the affected ranges described by a sequence of OSV events.
+    ///
+    /// Each `introduced` event opens a range, which is closed by the following `fixed` or
+    /// `last_affected` event. A range that is never closed affects every later version.
+    fn extend(&mut self, id: &str, events: &[Event]) {
+        // The specifiers for the range that's currently open, if any.
+        let mut open: Option<Vec<VersionSpecifier>> = None;
+        for event in events {
+            let version = match event {
+                Event::Introduced(version)
+                | Event::Fixed(version)
+                | Event::LastAffected(version)
+                | Event::Limit(version) => version,
+            };
+            // OSV uses `0` to indicate that every prior version is affected.
+            if matches!(event, Event::Introduced(_)) && version == "0" {
+                open = Some(Vec::new());
+                continue;
+            }
+            let Ok(version) = Version::from_str(version) else {
+                debug!("Skipping invalid version in advisory `{id}`: {version}");
+                continue;
+            };
+            match event {
+                Event::Introduced(_) => {
+                    open = Some(vec![VersionSpecifier::greater_than_equal_version(version)]);
+                }
+                Event::Fixed(_) => {
+                    if let Some(mut range) = open.take() {
+                        range.push(VersionSpecifier::less_than_version(version.clone()));
+                        self.ranges.push(range.into_iter().collect());
+                    }
+                    self.fixed.push(version);
+                }
+                Event::LastAffected(_) => {
+                    if let Some(mut range) = open.take() {
+                        range.push(VersionSpecifier::less_than_equal_version(version));
+                        self.ranges.push(range.into_iter().collect());
+                    }
+                }
+                Event::Limit(_) => {}
+            }
+        }
+        if let Some(range) = open {
+            self.ranges.push(range.into_iter().collect());
+        }
+    }
+
+    /// Returns `true` if the given version is affected by this vulnerability.
+    fn affects(&self, version: &Version) -> bool {
+        self.versions.contains(version) || self.ranges.iter().any(|range| range.contains(version))
+    }
+
+    /// Returns `true` if this vulnerability was explicitly ignored, by its ID or any alias.
+    fn is_ignored(&self, ignore: &[String]) -> bool {
+        ignore
+            .iter()
+            .any(|id| *id == self.id || self.aliases.contains(id))
+    }
+}
+
+/// An advisory in the [OSV format](https://ossf.github.io/osv-schema/).
+#[derive(Debug, Deserialize)]
+struct Advisory {
+    id: String,
+    #[serde(default)]
+    summary: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    withdrawn: Option<String>,
+    #[serde(default)]
+    affected: Vec<Affected>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Affected {
+    #[serde(default)]
+    package: Option<AffectedPackage>,
+    #[serde(default)]
+    ranges: Vec<Range>,
+    #[serde(default)]
+    versions: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AffectedPackage {
+    ecosystem: String,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Range {
+    #[serde(rename = "type")]
+    kind: RangeKind,
+    #[serde(default)]
+    events: Vec<Event>,
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum RangeKind {
+    Ecosystem,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Event {
+    Introduced(String),
+    Fixed(String),
+    LastAffected(String),
+    Limit(String),
+}
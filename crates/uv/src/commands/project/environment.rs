@@ -13,6 +13,7 @@ use uv_client::Connectivity;
 use uv_configuration::{Concurrency, TrustedHost};
 use uv_distribution_types::Resolution;
 use uv_python::{Interpreter, PythonEnvironment};
+use uv_types::HashStrategy;
 
 /// A [`PythonEnvironment`] stored in the cache.
 #[derive(Debug)]
@@ -112,6 +113,7 @@ impl CachedEnvironment {
         sync_environment(
             venv,
             &resolution,
+            &HashStrategy::default(),
             settings.as_ref().into(),
             state,
             install,
@@ -13,7 +13,7 @@ use uv_configuration::{
 };
 use uv_normalize::PackageName;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
-use uv_resolver::{InstallTarget, RequirementsTxtExport};
+use uv_resolver::{CycloneDxExport, InstallTarget, PylockTomlExport, RequirementsTxtExport};
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace};
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
@@ -217,6 +217,36 @@ pub(crate) async fn export(
             }
             write!(writer, "{export}")?;
         }
+        ExportFormat::PylockToml => {
+            let export = PylockTomlExport::from_lock(
+                target,
+                &extras,
+                &dev.with_defaults(defaults),
+                editable,
+                &install_options,
+            )?;
+
+            if include_header {
+                writeln!(
+                    writer,
+                    "{}",
+                    "# This file was autogenerated by uv via the following command:".green()
+                )?;
+                writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
+            }
+            write!(writer, "{}", export.to_toml()?)?;
+        }
+        ExportFormat::CycloneDx1_5 => {
+            let export = CycloneDxExport::from_lock(
+                target,
+                &extras,
+                &dev.with_defaults(defaults),
+                &install_options,
+            )?;
+
+            // JSON doesn't support comments, so the header is omitted.
+            write!(writer, "{}", export.to_json()?)?;
+        }
     }
 
     writer.commit().await?;
@@ -9,6 +9,7 @@ use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
 
 use uv_cache::Cache;
+use uv_cli::LockDiffFormat;
 use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, ExtrasSpecification, LowerBound, Reinstall, TrustedHost, Upgrade,
@@ -22,13 +23,14 @@ use uv_distribution_types::{
 use uv_git::ResolvedRepositoryReference;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
-use uv_pypi_types::{Requirement, SupportedEnvironments};
+use uv_pypi_types::{Conflicts, Requirement, SupportedEnvironments};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
 use uv_requirements::ExtrasResolver;
 use uv_resolver::{
-    FlatIndex, InMemoryIndex, Lock, LockVersion, Options, OptionsBuilder, PythonRequirement,
-    RequiresPython, ResolverEnvironment, ResolverManifest, SatisfiesResult, VERSION,
+    FlatIndex, InMemoryIndex, Lock, LockDiff, LockVersion, Options, OptionsBuilder,
+    PythonRequirement, RequiresPython, ResolverEnvironment, ResolverManifest, SatisfiesResult,
+    VERSION,
 };
 use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
@@ -75,6 +77,7 @@ pub(crate) async fn lock(
     locked: bool,
     frozen: bool,
     dry_run: bool,
+    diff: Option<LockDiffFormat>,
     python: Option<String>,
     settings: ResolverSettings,
     python_preference: PythonPreference,
@@ -161,6 +164,18 @@ pub(crate) async fn lock(
                 }
             }
 
+            // If requested, display the changes to the lockfile.
+            if let Some(format) = diff {
+                let diff = match &lock {
+                    LockResult::Changed(previous, lock) => LockDiff::new(previous.as_ref(), lock),
+                    LockResult::Unchanged(_) => LockDiff::default(),
+                };
+                match format {
+                    LockDiffFormat::Text => write!(printer.stdout(), "{diff}")?,
+                    LockDiffFormat::Json => write!(printer.stdout(), "{}", diff.to_json()?)?,
+                }
+            }
+
             Ok(ExitStatus::Success)
         }
         Err(ProjectError::Operation(pip::operations::Error::Resolve(
@@ -402,6 +417,12 @@ async fn do_lock(
         environments
     };
 
+    // Collect the list of required environments.
+    let required_environments = workspace.required_environments();
+
+    // Collect the conflicting extras and groups.
+    let conflicts = workspace.conflicts();
+
     // Determine the supported Python range. If no range is defined, and warn and default to the
     // current minor version.
     let requires_python = find_requires_python(workspace);
@@ -482,6 +503,7 @@ async fn do_lock(
         .prerelease_mode(prerelease)
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
+        .required_environments(required_environments.cloned().unwrap_or_default())
         .build();
     let hasher = HashStrategy::Generate;
 
@@ -537,6 +559,8 @@ async fn do_lock(
             &constraints,
             &overrides,
             environments,
+            required_environments,
+            &conflicts,
             dependency_metadata,
             interpreter,
             &requires_python,
@@ -654,6 +678,7 @@ async fn do_lock(
                 None,
                 resolver_env,
                 python_requirement,
+                conflicts.clone(),
                 &client,
                 &flat_index,
                 &state.index,
@@ -688,7 +713,14 @@ async fn do_lock(
                         .cloned()
                         .map(SupportedEnvironments::into_markers)
                         .unwrap_or_default(),
-                );
+                )
+                .with_required_environments(
+                    required_environments
+                        .cloned()
+                        .map(SupportedEnvironments::into_markers)
+                        .unwrap_or_default(),
+                )
+                .with_conflicts(conflicts);
 
             Ok(LockResult::Changed(previous, lock))
         }
@@ -719,6 +751,8 @@ impl ValidatedLock {
         constraints: &[Requirement],
         overrides: &[Requirement],
         environments: Option<&SupportedEnvironments>,
+        required_environments: Option<&SupportedEnvironments>,
+        conflicts: &Conflicts,
         dependency_metadata: &DependencyMetadata,
         interpreter: &Interpreter,
         requires_python: &RequiresPython,
@@ -826,6 +860,33 @@ impl ValidatedLock {
             return Ok(Self::Versions(lock));
         }
 
+        // If the set of required environments has changed, we have to perform a clean resolution.
+        let expected = lock.simplified_required_environments();
+        let actual = required_environments
+            .map(SupportedEnvironments::as_markers)
+            .unwrap_or_default()
+            .iter()
+            .cloned()
+            .map(|marker| lock.simplify_environment(marker))
+            .collect::<Vec<_>>();
+        if expected != actual {
+            debug!(
+                "Ignoring existing lockfile due to change in required environments: `{:?}` vs. `{:?}`",
+                expected, actual
+            );
+            return Ok(Self::Versions(lock));
+        }
+
+        // If the conflicting extras or groups have changed, we have to perform a clean resolution.
+        if lock.conflicts() != conflicts {
+            debug!(
+                "Ignoring existing lockfile due to change in conflicting extras and groups: `{:?}` vs. `{:?}`",
+                lock.conflicts(),
+                conflicts
+            );
+            return Ok(Self::Versions(lock));
+        }
+
         // If the user provided at least one index URL (from the command line, or from a configuration
         // file), don't use the existing lockfile if it references any registries that are no longer
         // included in the current configuration.
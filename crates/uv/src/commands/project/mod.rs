@@ -3,13 +3,14 @@ use std::path::{Path, PathBuf};
 
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use tracing::debug;
 
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DevGroupsSpecification, ExtrasSpecification, GroupsSpecification,
-    LowerBound, Reinstall, TrustedHost, Upgrade,
+    Concurrency, Constraints, DevGroupsManifest, DevGroupsSpecification, ExtrasSpecification,
+    GroupsSpecification, LowerBound, Reinstall, TrustedHost, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
@@ -22,7 +23,7 @@ use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::{GroupName, PackageName, DEV_DEPENDENCIES};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::MarkerTreeContents;
-use uv_pypi_types::Requirement;
+use uv_pypi_types::{ConflictPackage, ConflictSet, Conflicts, Requirement};
 use uv_python::{
     EnvironmentPreference, Interpreter, InvalidEnvironmentKind, PythonDownloads, PythonEnvironment,
     PythonInstallation, PythonPreference, PythonRequest, PythonVariant, PythonVersionFile,
@@ -31,8 +32,8 @@ use uv_python::{
 use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSpecification};
 use uv_resolver::{
-    FlatIndex, Lock, OptionsBuilder, PythonRequirement, RequiresPython, ResolutionGraph,
-    ResolverEnvironment,
+    FlatIndex, InstallTarget, Lock, OptionsBuilder, PythonRequirement, RequiresPython,
+    ResolutionGraph, ResolverEnvironment,
 };
 use uv_scripts::Pep723Item;
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
@@ -49,6 +50,7 @@ use crate::printer::Printer;
 use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings, ResolverSettingsRef};
 
 pub(crate) mod add;
+pub(crate) mod audit;
 pub(crate) mod environment;
 pub(crate) mod export;
 pub(crate) mod init;
@@ -80,6 +82,13 @@ pub(crate) enum ProjectError {
     #[error("The current Python platform is not compatible with the lockfile's supported environments: {0}")]
     LockedPlatformIncompatibility(String),
 
+    #[error(
+        "{} are incompatible with the declared conflicts: {{{}}}",
+        format_conflicting_packages(_1),
+        _0.iter().map(|item| format!("`{item}`")).join(", ")
+    )]
+    ConflictIncompatibility(ConflictSet, Vec<ConflictPackage>),
+
     #[error("The requested interpreter resolved to Python {0}, which is incompatible with the project's Python requirement: `{1}`")]
     RequestedPythonProjectIncompatibility(Version, RequiresPython),
 
@@ -205,6 +214,66 @@ pub(crate) enum ProjectError {
     Anyhow(#[from] anyhow::Error),
 }
 
+/// Returns an error if the requested extras and dependency groups enable two or more items from
+/// any set of conflicts declared in the lockfile.
+pub(crate) fn detect_conflicts(
+    target: InstallTarget,
+    extras: &ExtrasSpecification,
+    dev: &DevGroupsManifest,
+) -> Result<(), ProjectError> {
+    // The extras and groups are requested for each of the packages that are installed, so an
+    // item is only enabled if it belongs to one of them.
+    let packages = target.packages().collect::<FxHashSet<_>>();
+    for set in target.lock().conflicts().iter() {
+        let mut enabled: Vec<ConflictPackage> = vec![];
+        for item in set.iter() {
+            if !packages.contains(item.package()) {
+                continue;
+            }
+            let is_enabled = match item.conflict() {
+                ConflictPackage::Extra(extra) => extras.contains(extra),
+                ConflictPackage::Group(group) => dev.iter().any(|name| name == group),
+            };
+            if is_enabled {
+                enabled.push(item.conflict().clone());
+            }
+        }
+        if enabled.len() >= 2 {
+            return Err(ProjectError::ConflictIncompatibility(set.clone(), enabled));
+        }
+    }
+    Ok(())
+}
+
+/// Format a list of conflicting extras and groups for display, e.g., ``Extras `foo` and `bar` ``.
+fn format_conflicting_packages(conflicts: &[ConflictPackage]) -> String {
+    let kind = if conflicts
+        .iter()
+        .all(|conflict| matches!(conflict, ConflictPackage::Extra(_)))
+    {
+        "Extras"
+    } else if conflicts
+        .iter()
+        .all(|conflict| matches!(conflict, ConflictPackage::Group(_)))
+    {
+        "Groups"
+    } else {
+        "Extras and groups"
+    };
+    let names = conflicts
+        .iter()
+        .map(|conflict| match conflict {
+            ConflictPackage::Extra(extra) => format!("`{extra}`"),
+            ConflictPackage::Group(group) => format!("`{group}`"),
+        })
+        .collect::<Vec<_>>();
+    match names.as_slice() {
+        [] => kind.to_string(),
+        [name] => format!("{kind} {name}"),
+        [rest @ .., last] => format!("{kind} {} and {last}", rest.join(", ")),
+    }
+}
+
 /// Compute the `Requires-Python` bound for the [`Workspace`].
 ///
 /// For a [`Workspace`] with multiple packages, the `Requires-Python` bound is the union of the
@@ -956,7 +1025,7 @@ pub(crate) async fn resolve_environment<'a>(
         no_build_isolation_package,
         exclude_newer,
         link_mode,
-        upgrade: _,
+        upgrade,
         build_options,
         sources,
     } = settings;
@@ -1022,17 +1091,18 @@ pub(crate) async fn resolve_environment<'a>(
     let build_constraints = Constraints::default();
     let build_hasher = HashStrategy::default();
 
+    // If an existing lockfile exists, build up a set of preferences, omitting any packages that
+    // the user requested to upgrade.
+    let LockedRequirements { preferences, git } = spec
+        .lock
+        .map(|lock| read_lock_requirements(lock, upgrade))
+        .unwrap_or_default();
+
     // When resolving from an interpreter, we assume an empty environment, so reinstalls and
     // upgrades aren't relevant.
     let reinstall = Reinstall::default();
     let upgrade = Upgrade::default();
 
-    // If an existing lockfile exists, build up a set of preferences.
-    let LockedRequirements { preferences, git } = spec
-        .lock
-        .map(|lock| read_lock_requirements(lock, &upgrade))
-        .unwrap_or_default();
-
     // Populate the Git resolver.
     for ResolvedRepositoryReference { reference, sha } in git {
         debug!("Inserting Git reference into resolver: `{reference:?}` at `{sha}`");
@@ -1091,6 +1161,7 @@ pub(crate) async fn resolve_environment<'a>(
         Some(tags),
         ResolverEnvironment::specific(marker_env),
         python_requirement,
+        Conflicts::empty(),
         &client,
         &flat_index,
         &state.index,
@@ -1104,9 +1175,13 @@ pub(crate) async fn resolve_environment<'a>(
 }
 
 /// Sync a [`PythonEnvironment`] with a set of resolved requirements.
+///
+/// The `hasher` determines the hashes that distributions are verified against, e.g., those recorded
+/// in a lockfile.
 pub(crate) async fn sync_environment(
     venv: PythonEnvironment,
     resolution: &Resolution,
+    hasher: &HashStrategy,
     settings: InstallerSettingsRef<'_>,
     state: &SharedState,
     logger: Box<dyn InstallLogger>,
@@ -1172,7 +1247,6 @@ pub(crate) async fn sync_environment(
     let build_constraints = Constraints::default();
     let build_hasher = HashStrategy::default();
     let dry_run = false;
-    let hasher = HashStrategy::default();
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
@@ -1180,7 +1254,7 @@ pub(crate) async fn sync_environment(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+        FlatIndex::from_entries(entries, Some(tags), hasher, build_options)
     };
 
     // Create a build dispatch.
@@ -1219,7 +1293,7 @@ pub(crate) async fn sync_environment(
         compile_bytecode,
         index_locations,
         config_setting,
-        &hasher,
+        hasher,
         tags,
         &client,
         &state.in_flight,
@@ -1246,6 +1320,8 @@ pub(crate) struct EnvironmentUpdate {
     pub(crate) environment: PythonEnvironment,
     /// The [`Changelog`] of changes made to the environment.
     pub(crate) changelog: Changelog,
+    /// The [`Lock`] for the resolution of the environment, if the environment was re-resolved.
+    pub(crate) lock: Option<Lock>,
 }
 
 impl EnvironmentUpdate {
@@ -1256,9 +1332,12 @@ impl EnvironmentUpdate {
 }
 
 /// Update a [`PythonEnvironment`] to satisfy a set of [`RequirementsSource`]s.
+///
+/// If the environment is re-resolved, the resolution is returned as a [`Lock`], with any relative
+/// paths resolved against the root of the environment.
 pub(crate) async fn update_environment(
     venv: PythonEnvironment,
-    spec: RequirementsSpecification,
+    spec: EnvironmentSpecification<'_>,
     settings: &ResolverInstallerSettings,
     state: &SharedState,
     resolve: Box<dyn ResolveLogger>,
@@ -1270,7 +1349,7 @@ pub(crate) async fn update_environment(
     cache: &Cache,
     printer: Printer,
 ) -> anyhow::Result<EnvironmentUpdate> {
-    warn_on_requirements_txt_setting(&spec, settings.as_ref().into());
+    warn_on_requirements_txt_setting(&spec.requirements, settings.as_ref().into());
 
     let ResolverInstallerSettings {
         index_locations,
@@ -1299,7 +1378,7 @@ pub(crate) async fn update_environment(
         overrides,
         source_trees,
         ..
-    } = spec;
+    } = spec.requirements;
 
     // Determine markers to use for resolution.
     let interpreter = venv.interpreter();
@@ -1324,6 +1403,7 @@ pub(crate) async fn update_environment(
                 return Ok(EnvironmentUpdate {
                     environment: venv,
                     changelog: Changelog::default(),
+                    lock: None,
                 });
             }
             SatisfiesResult::Unsatisfied(requirement) => {
@@ -1375,7 +1455,19 @@ pub(crate) async fn update_environment(
     let dry_run = false;
     let extras = ExtrasSpecification::default();
     let hasher = HashStrategy::default();
-    let preferences = Vec::default();
+
+    // If an existing lockfile exists, build up a set of preferences, omitting any packages that
+    // the user requested to upgrade.
+    let LockedRequirements { preferences, git } = spec
+        .lock
+        .map(|lock| read_lock_requirements(lock, upgrade))
+        .unwrap_or_default();
+
+    // Populate the Git resolver.
+    for ResolvedRepositoryReference { reference, sha } in git {
+        debug!("Inserting Git reference into resolver: `{reference:?}` at `{sha}`");
+        state.git.insert(reference, sha);
+    }
 
     // Determine the tags to use for resolution.
     let tags = venv.interpreter().tags()?;
@@ -1433,6 +1525,7 @@ pub(crate) async fn update_environment(
         Some(tags),
         ResolverEnvironment::specific(marker_env.clone()),
         python_requirement,
+        Conflicts::empty(),
         &client,
         &flat_index,
         &state.index,
@@ -1444,10 +1537,14 @@ pub(crate) async fn update_environment(
     )
     .await
     {
-        Ok(resolution) => Resolution::from(resolution),
+        Ok(resolution) => resolution,
         Err(err) => return Err(err.into()),
     };
 
+    // Record the resolution, before converting it for installation.
+    let lock = Lock::from_resolution_graph(&resolution, venv.root())?;
+    let resolution = Resolution::from(resolution);
+
     // Sync the environment.
     let changelog = pip::operations::install(
         &resolution,
@@ -1479,6 +1576,7 @@ pub(crate) async fn update_environment(
     Ok(EnvironmentUpdate {
         environment: venv,
         changelog,
+        lock: Some(lock),
     })
 }
 
@@ -1,18 +1,27 @@
 use anyhow::{Context, Result};
+use std::collections::BTreeSet;
 use std::fmt::Write;
-use std::path::Path;
+use std::iter::Peekable;
+use std::path::{Path, PathBuf};
+use std::str::Chars;
 
+use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use tracing::debug;
 use uv_cache::Cache;
 use uv_client::Connectivity;
 use uv_configuration::{
     Concurrency, DevGroupsManifest, EditableMode, ExtrasSpecification, InstallOptions, LowerBound,
     TrustedHost,
 };
+use uv_distribution_types::{InstalledDist, Name};
 use uv_fs::Simplified;
+use uv_install_wheel::read_record_file;
+use uv_installer::SitePackages;
 use uv_normalize::DEV_DEPENDENCIES;
-use uv_pep508::PackageName;
-use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_pep508::{PackageName, Requirement};
+use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_resolver::InstallTarget;
 use uv_scripts::Pep723Script;
 use uv_warnings::{warn_user, warn_user_once};
@@ -37,6 +46,8 @@ pub(crate) async fn remove(
     frozen: bool,
     no_sync: bool,
     packages: Vec<PackageName>,
+    unused: bool,
+    dry_run: bool,
     dependency_type: DependencyType,
     package: Option<PackageName>,
     python: Option<String>,
@@ -101,6 +112,67 @@ pub(crate) async fn remove(
         ),
     }?;
 
+    // If requested, identify the dependencies that are never imported.
+    let packages = if unused {
+        let Target::Project(project) = &target else {
+            unreachable!("`--unused` is not supported for Python scripts");
+        };
+
+        let (unused, uncertain) = find_unused_dependencies(project, cache)?;
+        if unused.is_empty() {
+            writeln!(printer.stderr(), "No unused dependencies found")?;
+            return Ok(ExitStatus::Success);
+        }
+
+        // If the imports of any source file are unknown, a dependency may only appear unused, so
+        // report the dependencies rather than removing them.
+        if !uncertain.is_empty() {
+            for path in &uncertain {
+                warn_user!(
+                    "Unable to determine the imports of `{}`; unused dependencies will not be removed",
+                    path.user_display()
+                );
+            }
+            writeln!(
+                printer.stderr(),
+                "Found {} possibly unused {}:",
+                unused.len(),
+                if unused.len() == 1 {
+                    "dependency"
+                } else {
+                    "dependencies"
+                }
+            )?;
+            for name in &unused {
+                writeln!(printer.stderr(), " {} {}", "?".yellow(), name.bold())?;
+            }
+            return Ok(ExitStatus::Success);
+        }
+
+        writeln!(
+            printer.stderr(),
+            "{} {} unused {}:",
+            if dry_run { "Would remove" } else { "Removing" },
+            unused.len(),
+            if unused.len() == 1 {
+                "dependency"
+            } else {
+                "dependencies"
+            }
+        )?;
+        for name in &unused {
+            writeln!(printer.stderr(), " {} {}", "-".red(), name.bold())?;
+        }
+
+        if dry_run {
+            return Ok(ExitStatus::Success);
+        }
+
+        unused.into_iter().collect()
+    } else {
+        packages
+    };
+
     for package in packages {
         match dependency_type {
             DependencyType::Production => {
@@ -368,3 +440,443 @@ fn warn_if_present(name: &PackageName, pyproject: &PyProjectTomlMut) {
         }
     }
 }
+
+/// Returns the dependencies in `project.dependencies` that are never imported by the project's
+/// Python source files, along with the source files whose imports couldn't be determined.
+///
+/// The top-level modules provided by each dependency are read from the project environment;
+/// dependencies that aren't installed are assumed to be used.
+fn find_unused_dependencies(
+    project: &VirtualProject,
+    cache: &Cache,
+) -> Result<(BTreeSet<PackageName>, BTreeSet<PathBuf>)> {
+    let Some(dependencies) = project
+        .pyproject_toml()
+        .project
+        .as_ref()
+        .and_then(|project| project.dependencies.as_ref())
+    else {
+        return Ok((BTreeSet::new(), BTreeSet::new()));
+    };
+
+    let venv = match PythonEnvironment::from_root(project.workspace().venv(), cache) {
+        Ok(venv) => venv,
+        Err(uv_python::Error::MissingEnvironment(_)) => {
+            anyhow::bail!(
+                "No project environment found at `{}`; run `{}` to install the project's dependencies before removing unused dependencies",
+                project.workspace().venv().user_display().cyan(),
+                "uv sync".green()
+            );
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let site_packages = SitePackages::from_environment(&venv)?;
+
+    let imports = find_imports(project.root())?;
+
+    let mut unused = BTreeSet::new();
+    for dependency in dependencies {
+        let Ok(requirement) = dependency.parse::<Requirement>() else {
+            continue;
+        };
+
+        // Ignore references to the project itself (e.g., to include its own extras).
+        if project.project_name() == Some(&requirement.name) {
+            continue;
+        }
+
+        let installed = site_packages.get_packages(&requirement.name);
+        if installed.is_empty() {
+            debug!(
+                "Skipping `{}`, which is not installed in the project environment",
+                requirement.name
+            );
+            continue;
+        }
+
+        let mut modules = FxHashSet::default();
+        for dist in installed {
+            modules.extend(top_level_modules(dist)?);
+        }
+        debug!(
+            "Found top-level modules for `{}`: {}",
+            requirement.name,
+            modules
+                .iter()
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .join(", ")
+        );
+
+        if modules.is_disjoint(&imports.modules) {
+            unused.insert(requirement.name);
+        }
+    }
+
+    Ok((unused, imports.uncertain))
+}
+
+/// The top-level modules imported by a project's Python source files.
+#[derive(Debug, Default)]
+struct Imports {
+    /// The imported top-level modules.
+    modules: FxHashSet<String>,
+    /// The source files whose imports couldn't be fully determined, e.g., as they import a module
+    /// by a computed name.
+    uncertain: BTreeSet<PathBuf>,
+}
+
+/// Returns the top-level modules imported by the Python source files in the given directory.
+///
+/// Hidden directories and virtual environments are skipped.
+fn find_imports(root: &Path) -> Result<Imports> {
+    let mut imports = Imports::default();
+
+    let walker = walkdir::WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|entry| {
+            if !entry.file_type().is_dir() || entry.depth() == 0 {
+                return true;
+            }
+            let name = entry.file_name().to_string_lossy();
+            !(name.starts_with('.')
+                || name == "__pycache__"
+                || entry.path().join("pyvenv.cfg").is_file())
+        });
+    for entry in walker {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        if !entry
+            .path()
+            .extension()
+            .is_some_and(|extension| extension == "py" || extension == "pyi")
+        {
+            continue;
+        }
+        let Ok(source) = fs_err::read_to_string(entry.path()) else {
+            debug!(
+                "Unable to read non-UTF-8 source file: {}",
+                entry.path().user_display()
+            );
+            imports.uncertain.insert(entry.into_path());
+            continue;
+        };
+        if !parse_imports(&source, &mut imports.modules) {
+            debug!(
+                "Unable to determine all imports of: {}",
+                entry.path().user_display()
+            );
+            imports.uncertain.insert(entry.into_path());
+        }
+    }
+
+    Ok(imports)
+}
+
+/// Collect the top-level modules imported by a Python source file.
+///
+/// Includes `import` and `from ... import` statements, along with calls to `__import__` and
+/// `importlib.import_module` with a literal module name. Relative imports are ignored, as they
+/// refer to the project itself.
+///
+/// Returns `false` if the imports couldn't be fully determined, e.g., if the source can't be
+/// tokenized, or a module is imported dynamically by a computed name.
+fn parse_imports(source: &str, imports: &mut FxHashSet<String>) -> bool {
+    let Some(statements) = tokenize(source) else {
+        return false;
+    };
+
+    let mut complete = true;
+    for tokens in &statements {
+        let mut index = 0;
+        while let Some(token) = tokens.get(index) {
+            index += 1;
+            let Token::Name(name) = token else {
+                continue;
+            };
+            match name.as_str() {
+                // e.g., `from foo.bar import baz`
+                "from" => {
+                    let Some((module, next)) = dotted_name(tokens, index) else {
+                        continue;
+                    };
+                    // Skip `raise ... from ...` and `yield from ...`.
+                    if !matches!(tokens.get(next), Some(Token::Name(name)) if name == "import") {
+                        continue;
+                    }
+                    if !module.starts_with('.') {
+                        insert_module(&module, imports);
+                    }
+                    // The remainder of the statement lists the imported names.
+                    break;
+                }
+                // e.g., `import foo.bar as baz, qux`
+                "import" => {
+                    while let Some((module, next)) = dotted_name(tokens, index) {
+                        insert_module(&module, imports);
+                        index = next;
+                        if matches!(tokens.get(index), Some(Token::Name(name)) if name == "as") {
+                            index += 2;
+                        }
+                        if tokens.get(index) != Some(&Token::Op(',')) {
+                            break;
+                        }
+                        index += 1;
+                    }
+                    break;
+                }
+                // e.g., `importlib.import_module("foo.bar")` or `__import__("foo")`
+                "import_module" | "__import__" => {
+                    if let (
+                        Some(Token::Op('(')),
+                        Some(Token::String(Some(module))),
+                        Some(Token::Op(',' | ')')),
+                    ) = (
+                        tokens.get(index),
+                        tokens.get(index + 1),
+                        tokens.get(index + 2),
+                    ) {
+                        if !module.starts_with('.') {
+                            insert_module(module, imports);
+                        }
+                    } else {
+                        // The module name is computed, or the function is called indirectly.
+                        complete = false;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    complete
+}
+
+/// Parse a (possibly relative) dotted module name, e.g., `foo.bar` or `..foo`, starting at the
+/// given token.
+///
+/// Returns the module name, along with the index of the token that follows it.
+fn dotted_name(tokens: &[Token], mut index: usize) -> Option<(String, usize)> {
+    let mut module = String::new();
+    while tokens.get(index) == Some(&Token::Op('.')) {
+        module.push('.');
+        index += 1;
+    }
+    while let Some(Token::Name(name)) = tokens.get(index) {
+        if name == "import" {
+            break;
+        }
+        module.push_str(name);
+        index += 1;
+        if tokens.get(index) != Some(&Token::Op('.')) {
+            break;
+        }
+        module.push('.');
+        index += 1;
+    }
+    if module.is_empty() {
+        None
+    } else {
+        Some((module, index))
+    }
+}
+
+/// A token in a Python source file, as relevant to finding its imports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// An identifier, keyword, or number.
+    Name(String),
+    /// A string literal, with its contents, or `None` for an f-string.
+    String(Option<String>),
+    /// Any other operator or delimiter, e.g., `.`, `,` or `(`.
+    Op(char),
+}
+
+/// Split a Python source file into its logical lines (i.e., statements), each as a sequence of
+/// tokens.
+///
+/// Comments are dropped, and statements are joined across lines within brackets and after a
+/// backslash. Returns `None` if the source can't be tokenized, e.g., due to an unterminated string.
+fn tokenize(source: &str) -> Option<Vec<Vec<Token>>> {
+    let mut statements = Vec::new();
+    let mut statement = Vec::new();
+    let mut depth = 0usize;
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            // Skip comments, up to the end of the line.
+            '#' => while chars.next_if(|&c| c != '\n').is_some() {},
+            // A backslash joins the line with the next.
+            '\\' => {
+                chars.next_if_eq(&'\r');
+                chars.next_if_eq(&'\n')?;
+            }
+            '\n' | ';' if depth == 0 => {
+                if !statement.is_empty() {
+                    statements.push(std::mem::take(&mut statement));
+                }
+            }
+            '(' | '[' | '{' => {
+                depth += 1;
+                statement.push(Token::Op(c));
+            }
+            ')' | ']' | '}' => {
+                depth = depth.saturating_sub(1);
+                statement.push(Token::Op(c));
+            }
+            '\'' | '"' => statement.push(Token::String(Some(string(c, &mut chars)?))),
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut name = String::from(c);
+                while let Some(c) = chars.next_if(|&c| c.is_alphanumeric() || c == '_') {
+                    name.push(c);
+                }
+                // A string prefix, e.g., `rb"..."` or `f'...'`.
+                if name.len() <= 2
+                    && name
+                        .chars()
+                        .all(|c| matches!(c.to_ascii_lowercase(), 'r' | 'b' | 'u' | 'f'))
+                {
+                    if let Some(quote) = chars.next_if(|&c| c == '\'' || c == '"') {
+                        let contents = string(quote, &mut chars)?;
+                        let formatted = name.contains(['f', 'F']);
+                        statement.push(Token::String((!formatted).then_some(contents)));
+                        continue;
+                    }
+                }
+                statement.push(Token::Name(name));
+            }
+            c if c.is_whitespace() => {}
+            c => statement.push(Token::Op(c)),
+        }
+    }
+    if !statement.is_empty() {
+        statements.push(statement);
+    }
+    Some(statements)
+}
+
+/// Consume the remainder of a string literal that opens with the given quote, returning its
+/// contents.
+///
+/// Returns `None` if the string is unterminated.
+fn string(quote: char, chars: &mut Peekable<Chars>) -> Option<String> {
+    let triple = if chars.next_if_eq(&quote).is_some() {
+        if chars.next_if_eq(&quote).is_none() {
+            // An empty string, e.g., `""`.
+            return Some(String::new());
+        }
+        true
+    } else {
+        false
+    };
+
+    let mut contents = String::new();
+    loop {
+        match chars.next()? {
+            '\\' => {
+                contents.push('\\');
+                contents.push(chars.next()?);
+            }
+            c if c == quote => {
+                if !triple {
+                    return Some(contents);
+                }
+                if chars.next_if_eq(&quote).is_some() {
+                    if chars.next_if_eq(&quote).is_some() {
+                        return Some(contents);
+                    }
+                    contents.push(quote);
+                }
+                contents.push(quote);
+            }
+            '\n' if !triple => return None,
+            c => contents.push(c),
+        }
+    }
+}
+
+/// Insert the top-level module of a (possibly dotted) module path.
+fn insert_module(module: &str, imports: &mut FxHashSet<String>) {
+    let module = module.split('.').next().unwrap_or(module);
+    if is_identifier(module) {
+        imports.insert(module.to_string());
+    }
+}
+
+/// Returns the top-level modules provided by an installed distribution.
+///
+/// The modules are read from `top_level.txt` (if present) and from the files in the `RECORD`. The
+/// distribution name itself is always included, to account for distributions that can't be
+/// introspected (e.g., editable installs).
+fn top_level_modules(dist: &InstalledDist) -> Result<FxHashSet<String>> {
+    let mut modules = FxHashSet::default();
+    modules.insert(dist.name().as_str().replace('-', "_"));
+
+    if !dist.path().is_dir() {
+        return Ok(modules);
+    }
+
+    match fs_err::read_to_string(dist.path().join("top_level.txt")) {
+        Ok(top_level) => {
+            for line in top_level.lines() {
+                let module = line.trim().split('/').next().unwrap_or_default();
+                if is_identifier(module) {
+                    modules.insert(module.to_string());
+                }
+            }
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    match fs_err::File::open(dist.path().join("RECORD")) {
+        Ok(mut record) => {
+            for entry in read_record_file(&mut record)? {
+                if let Some(module) = record_module(&entry.path) {
+                    modules.insert(module.to_string());
+                }
+            }
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    Ok(modules)
+}
+
+/// Returns the top-level module for a path in a `RECORD` file, if any.
+///
+/// For example, `foo/__init__.py` and `foo.cpython-312-x86_64-linux-gnu.so` both provide `foo`,
+/// while `foo-stubs/__init__.pyi` provides stubs for `foo`.
+fn record_module(path: &str) -> Option<&str> {
+    let module = if let Some((directory, _)) = path.split_once('/') {
+        if directory == "__pycache__"
+            || directory.ends_with(".dist-info")
+            || directory.ends_with(".data")
+        {
+            return None;
+        }
+        directory.strip_suffix("-stubs").unwrap_or(directory)
+    } else {
+        let (module, extension) = path.split_once('.')?;
+        if !matches!(
+            extension.rsplit('.').next(),
+            Some("py" | "pyi" | "so" | "pyd")
+        ) {
+            return None;
+        }
+        module
+    };
+    is_identifier(module).then_some(module)
+}
+
+/// Returns `true` if the string is a valid Python identifier (restricted to ASCII).
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
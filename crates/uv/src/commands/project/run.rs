@@ -45,8 +45,9 @@ use crate::commands::pip::operations::Modifications;
 use crate::commands::project::environment::CachedEnvironment;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::{
-    default_dependency_groups, validate_requires_python, validate_script_requires_python,
-    DependencyGroupsTarget, EnvironmentSpecification, ProjectError, ScriptPython, WorkspacePython,
+    default_dependency_groups, detect_conflicts, validate_requires_python,
+    validate_script_requires_python, DependencyGroupsTarget, EnvironmentSpecification,
+    ProjectError, ScriptPython, WorkspacePython,
 };
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{diagnostics, project, ExitStatus, SharedState};
@@ -695,13 +696,19 @@ pub(crate) async fn run(
                     },
                 };
 
+                let dev = dev.with_defaults(defaults);
+
+                // Validate that the requested extras and groups don't enable any declared
+                // conflicts.
+                detect_conflicts(target, &extras, &dev)?;
+
                 let install_options = InstallOptions::default();
 
                 match project::sync::do_sync(
                     target,
                     &venv,
                     &extras,
-                    &dev.with_defaults(defaults),
+                    &dev,
                     editable,
                     install_options,
                     Modifications::Sufficient,
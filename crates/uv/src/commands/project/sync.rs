@@ -32,7 +32,7 @@ use crate::commands::pip::operations;
 use crate::commands::pip::operations::Modifications;
 use crate::commands::project::lock::{do_safe_lock, LockMode};
 use crate::commands::project::{
-    default_dependency_groups, DependencyGroupsTarget, ProjectError, SharedState,
+    default_dependency_groups, detect_conflicts, DependencyGroupsTarget, ProjectError, SharedState,
 };
 use crate::commands::{diagnostics, project, ExitStatus};
 use crate::printer::Printer;
@@ -212,12 +212,18 @@ pub(crate) async fn sync(
         },
     };
 
+    // Determine the dependency groups to include.
+    let dev = dev.with_defaults(defaults);
+
+    // Validate that the requested extras and groups don't enable any declared conflicts.
+    detect_conflicts(target, &extras, &dev)?;
+
     // Perform the sync operation.
     match do_sync(
         target,
         &venv,
         &extras,
-        &dev.with_defaults(defaults),
+        &dev,
         editable,
         install_options,
         modifications,
@@ -370,7 +376,9 @@ pub(super) async fn do_sync(
     }
 
     // Populate credentials from the workspace.
-    store_credentials_from_workspace(target.workspace());
+    if let Some(workspace) = target.workspace() {
+        store_credentials_from_workspace(workspace);
+    }
 
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
@@ -6,6 +6,7 @@ use futures::{stream, StreamExt};
 
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
+use uv_cli::TreeFormat;
 use uv_client::{Connectivity, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, DevGroupsSpecification, LowerBound, TargetTriple, TrustedHost,
@@ -42,6 +43,7 @@ pub(crate) async fn tree(
     no_dedupe: bool,
     invert: bool,
     outdated: bool,
+    format: TreeFormat,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
     python: Option<String>,
@@ -203,7 +205,10 @@ pub(crate) async fn tree(
         invert,
     );
 
-    print!("{tree}");
+    match format {
+        TreeFormat::Text => print!("{tree}"),
+        TreeFormat::Json => print!("{}", tree.to_json()?),
+    }
 
     Ok(ExitStatus::Success)
 }
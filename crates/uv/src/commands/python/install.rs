@@ -123,6 +123,15 @@ pub(crate) async fn install(
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
 
+    let client = uv_client::BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .allow_insecure_host(allow_insecure_host.to_vec())
+        .build();
+
+    // Load any custom Python downloads, which must occur before the requests are resolved
+    ManagedPythonDownload::load_custom_from_env(&client).await?;
+
     // Resolve the requests
     let mut is_default_install = false;
     let requests: Vec<_> = if targets.is_empty() {
@@ -217,11 +226,6 @@ pub(crate) async fn install(
         .collect::<Vec<_>>();
 
     // Download and unpack the Python versions concurrently
-    let client = uv_client::BaseClientBuilder::new()
-        .connectivity(connectivity)
-        .native_tls(native_tls)
-        .allow_insecure_host(allow_insecure_host.to_vec())
-        .build();
     let reporter = PythonDownloadReporter::new(printer, downloads.len() as u64);
     let mut tasks = FuturesUnordered::new();
     for download in &downloads {
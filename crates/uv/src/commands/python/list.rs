@@ -5,8 +5,10 @@ use anyhow::Result;
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
 use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::TrustedHost;
 use uv_fs::Simplified;
-use uv_python::downloads::PythonDownloadRequest;
+use uv_python::downloads::{ManagedPythonDownload, PythonDownloadRequest};
 use uv_python::{
     find_python_installations, DiscoveryError, EnvironmentPreference, PythonDownloads,
     PythonInstallation, PythonNotFound, PythonPreference, PythonRequest, PythonSource,
@@ -31,6 +33,9 @@ pub(crate) async fn list(
     all_platforms: bool,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
+    native_tls: bool,
+    connectivity: Connectivity,
+    allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -54,6 +59,16 @@ pub(crate) async fn list(
         // Include pre-release versions
         .map(|request| request.with_prereleases(true));
 
+        // Load any custom Python downloads, which must occur before any downloads are queried
+        if download_request.is_some() {
+            let client = uv_client::BaseClientBuilder::new()
+                .connectivity(connectivity)
+                .native_tls(native_tls)
+                .allow_insecure_host(allow_insecure_host.to_vec())
+                .build();
+            ManagedPythonDownload::load_custom_from_env(&client).await?;
+        }
+
         let downloads = download_request
             .as_ref()
             .map(uv_python::downloads::PythonDownloadRequest::iter_downloads)
@@ -0,0 +1,412 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::TrustedHost;
+use uv_fs::Simplified;
+use uv_python::downloads::{DownloadResult, ManagedPythonDownload, PythonDownloadRequest};
+use uv_python::managed::{
+    python_executable_dir, ManagedPythonInstallation, ManagedPythonInstallations,
+};
+use uv_python::{Interpreter, PyVenvConfiguration, PythonDownloads, PythonRequest};
+use uv_static::EnvVars;
+use uv_tool::InstalledTools;
+use uv_virtualenv::Prompt;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject};
+
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::{elapsed, ExitStatus};
+use crate::printer::Printer;
+
+/// Upgrade managed Python versions to the latest patch version.
+pub(crate) async fn upgrade(
+    project_dir: &Path,
+    targets: Vec<String>,
+    remove_old: bool,
+    python_downloads: PythonDownloads,
+    native_tls: bool,
+    connectivity: Connectivity,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    let requests = targets
+        .iter()
+        .map(|target| PythonRequest::parse(target.as_str()))
+        .map(|request| {
+            let download_request = PythonDownloadRequest::from_request(&request)
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Cannot upgrade managed Python for request: {request}")
+                })?
+                // Always include pre-releases in upgrades
+                .with_prereleases(true);
+            Ok((request, download_request))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Read the existing installations, lock the directory for the duration
+    let installations = ManagedPythonInstallations::from_settings()?.init()?;
+    let installations_dir = installations.root();
+    let cache_dir = installations.cache();
+    let _lock = installations.lock().await?;
+    let existing_installations: Vec<_> = installations.find_all()?.collect();
+
+    // Find the installations that match the requests
+    let mut matching_installations = Vec::new();
+    if requests.is_empty() {
+        matching_installations.extend(existing_installations.iter());
+    } else {
+        for (request, download_request) in &requests {
+            let mut found = false;
+            for installation in existing_installations
+                .iter()
+                .filter(|installation| download_request.satisfied_by_key(installation.key()))
+            {
+                found = true;
+                if !matching_installations.contains(&installation) {
+                    matching_installations.push(installation);
+                }
+            }
+            if !found {
+                writeln!(
+                    printer.stderr(),
+                    "No existing installations found for: {}",
+                    request.cyan()
+                )?;
+            }
+        }
+    }
+
+    if matching_installations.is_empty() {
+        writeln!(printer.stderr(), "No Python installations found")?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    let client = uv_client::BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .allow_insecure_host(allow_insecure_host.to_vec())
+        .build();
+
+    // Load any custom Python downloads, which must occur before any upgrades are found
+    ManagedPythonDownload::load_custom_from_env(&client).await?;
+
+    // Find the latest patch version for each installation
+    let upgrades = matching_installations
+        .into_iter()
+        .filter_map(|installation| {
+            if let Some(download) = ManagedPythonDownload::find_upgrade(installation.key()) {
+                debug!(
+                    "Found upgrade `{}` for `{}`",
+                    download.key().green(),
+                    installation.key().green()
+                );
+                Some((installation, download))
+            } else {
+                debug!("`{}` is already up-to-date", installation.key().green());
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if upgrades.is_empty() {
+        if requests.is_empty() {
+            writeln!(printer.stderr(), "All installed versions are up-to-date")?;
+        } else {
+            writeln!(printer.stderr(), "All requested versions are up-to-date")?;
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    // The latest patch version may already be installed
+    let mut upgraded: FxHashMap<_, _> = existing_installations
+        .iter()
+        .filter(|installation| {
+            upgrades
+                .iter()
+                .any(|(_, download)| download.key() == installation.key())
+        })
+        .map(|installation| (installation.key().clone(), installation.clone()))
+        .collect();
+
+    let downloads = upgrades
+        .iter()
+        .map(|(_, download)| *download)
+        .filter(|download| !upgraded.contains_key(download.key()))
+        // Ensure we only download each version once
+        .unique_by(|download| download.key())
+        .collect::<Vec<_>>();
+
+    // Check if Python downloads are banned
+    if matches!(python_downloads, PythonDownloads::Never) && !downloads.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "Python downloads are not allowed (`python-downloads = \"never\"`). Change to `python-downloads = \"manual\"` to allow explicit upgrades.",
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Download and unpack the Python versions concurrently
+    let reporter = PythonDownloadReporter::new(printer, downloads.len() as u64);
+    let mut tasks = FuturesUnordered::new();
+    for download in &downloads {
+        tasks.push(async {
+            (
+                download.key(),
+                download
+                    .fetch(
+                        &client,
+                        installations_dir,
+                        &cache_dir,
+                        false,
+                        Some(&reporter),
+                    )
+                    .await,
+            )
+        });
+    }
+
+    let mut errors = vec![];
+    while let Some((key, result)) = tasks.next().await {
+        match result {
+            Ok(download) => {
+                let path = match download {
+                    // We should only encounter already-available during concurrent installs
+                    DownloadResult::AlreadyAvailable(path) => path,
+                    DownloadResult::Fetched(path) => path,
+                };
+
+                let installation = ManagedPythonInstallation::new(path)?;
+                installation.ensure_externally_managed()?;
+                installation.ensure_canonical_executables()?;
+                upgraded.insert(installation.key().clone(), installation);
+            }
+            Err(err) => {
+                errors.push((key, anyhow::Error::new(err)));
+            }
+        }
+    }
+
+    // Lock the tool environments, if any, for the duration of the upgrade
+    let installed_tools = InstalledTools::from_settings()?;
+    let _tools_lock = match installed_tools.lock().await {
+        Ok(lock) => Some(lock),
+        Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err.into()),
+    };
+
+    // Collect the environments that may have been created from an upgraded installation
+    let environments = find_environments(project_dir, &installed_tools).await?;
+
+    let bin = python_executable_dir()?;
+    let mut upgraded_environments = Vec::new();
+    let mut removed = Vec::new();
+    for (installation, download) in &upgrades {
+        let Some(upgrade) = upgraded.get(download.key()) else {
+            continue;
+        };
+
+        // Update the executable, if it points to the previous patch version
+        let target = bin.join(installation.key().versioned_executable_name());
+        if installation.is_bin_link(&target) {
+            fs_err::remove_file(&target)?;
+            upgrade.create_bin_link(&target)?;
+            debug!(
+                "Updated executable at `{}` to `{}`",
+                target.simplified_display(),
+                upgrade.key(),
+            );
+        }
+
+        // Update any environments that were created from the previous patch version
+        let mut interpreter = None;
+        let mut repointed = true;
+        for root in &environments {
+            let Ok(cfg) = PyVenvConfiguration::parse(root.join("pyvenv.cfg")) else {
+                continue;
+            };
+            if !is_created_from(&cfg, installation) {
+                continue;
+            }
+
+            debug!(
+                "Updating environment at `{}` from `{}` to `{}`",
+                root.simplified_display(),
+                installation.key(),
+                upgrade.key()
+            );
+            let interpreter = match &mut interpreter {
+                Some(interpreter) => interpreter,
+                None => interpreter.insert(Interpreter::query(upgrade.executable(), cache)?),
+            };
+            let prompt = cfg
+                .prompt()
+                .map_or(Prompt::None, |prompt| Prompt::Static(prompt.to_string()));
+            let result = uv_virtualenv::create_venv(
+                root,
+                interpreter.clone(),
+                prompt,
+                cfg.include_system_site_packages(),
+                true,
+                cfg.is_relocatable(),
+                cfg.is_seed(),
+            );
+            match result {
+                Ok(_) => upgraded_environments.push((root, upgrade.key())),
+                Err(err) => {
+                    repointed = false;
+                    warn_user!(
+                        "Failed to update environment at `{}` to use Python {}: {err}",
+                        root.user_display().cyan(),
+                        upgrade.key().version()
+                    );
+                }
+            }
+        }
+
+        // If requested, remove the previous patch version, unless an environment still uses it
+        if remove_old {
+            if repointed {
+                fs_err::tokio::remove_dir_all(installation.path()).await?;
+                debug!("Removed `{}`", installation.key());
+                removed.push(installation.key());
+            } else {
+                warn_user!(
+                    "Skipping removal of `{}`, which is still used by at least one environment",
+                    installation.key().green()
+                );
+            }
+        }
+    }
+
+    let changes = upgrades
+        .iter()
+        .filter(|(_, download)| upgraded.contains_key(download.key()))
+        .collect::<Vec<_>>();
+
+    if let [(installation, download)] = changes.as_slice() {
+        // Ex) "Upgraded Python 3.12.4 to 3.12.7 in 1.68s"
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Upgraded {} {}",
+                format!(
+                    "Python {} to {}",
+                    installation.key().version(),
+                    download.key().version()
+                )
+                .bold(),
+                format!("in {}", elapsed(start.elapsed())).dimmed()
+            )
+            .dimmed()
+        )?;
+    } else if !changes.is_empty() {
+        // Ex) "Upgraded 2 versions in 1.68s"
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Upgraded {} {}",
+                format!("{} versions", changes.len()).bold(),
+                format!("in {}", elapsed(start.elapsed())).dimmed()
+            )
+            .dimmed()
+        )?;
+    }
+
+    for key in changes
+        .iter()
+        .map(|(_, download)| download.key())
+        .unique()
+        .sorted_unstable()
+    {
+        writeln!(printer.stderr(), " {} {}", "+".green(), key.bold())?;
+    }
+    for key in removed.iter().sorted_unstable() {
+        writeln!(printer.stderr(), " {} {}", "-".red(), key.bold())?;
+    }
+
+    for (root, key) in upgraded_environments {
+        writeln!(
+            printer.stderr(),
+            "Updated environment at `{}` to use Python {}",
+            root.user_display().cyan(),
+            key.version()
+        )?;
+    }
+
+    if !errors.is_empty() {
+        for (key, err) in errors
+            .into_iter()
+            .sorted_unstable_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b))
+        {
+            writeln!(
+                printer.stderr(),
+                "{}: Failed to install {}",
+                "error".red().bold(),
+                key.green()
+            )?;
+            for err in err.chain() {
+                writeln!(
+                    printer.stderr(),
+                    "  {}: {}",
+                    "Caused by".red().bold(),
+                    err.to_string().trim()
+                )?;
+            }
+        }
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Find the virtual environments that should be updated when upgrading a Python installation.
+///
+/// Includes the tool environments, the project virtual environment, and the active virtual
+/// environment.
+async fn find_environments(
+    project_dir: &Path,
+    installed_tools: &InstalledTools,
+) -> Result<Vec<PathBuf>> {
+    let mut environments = Vec::new();
+
+    if installed_tools.root().is_dir() {
+        for (name, _) in installed_tools.tools()? {
+            environments.push(installed_tools.tool_dir(&name));
+        }
+    }
+
+    match VirtualProject::discover(project_dir, &DiscoveryOptions::default()).await {
+        Ok(project) => environments.push(project.workspace().venv()),
+        Err(err) => debug!("Failed to discover virtual project: {err}"),
+    }
+
+    if let Some(venv) = std::env::var_os(EnvVars::VIRTUAL_ENV).filter(|venv| !venv.is_empty()) {
+        environments.push(std::path::absolute(PathBuf::from(venv))?);
+    }
+
+    Ok(environments.into_iter().unique().collect())
+}
+
+/// Returns `true` if the virtual environment was created from the given installation.
+fn is_created_from(cfg: &PyVenvConfiguration, installation: &ManagedPythonInstallation) -> bool {
+    let Some(home) = cfg.home() else {
+        return false;
+    };
+    home.starts_with(installation.path())
+        || fs_err::canonicalize(installation.path()).is_ok_and(|path| home.starts_with(path))
+}
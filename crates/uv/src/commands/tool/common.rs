@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::path::Path;
 use std::{collections::BTreeSet, ffi::OsString};
 
 use anyhow::{bail, Context};
@@ -14,6 +15,7 @@ use uv_installer::SitePackages;
 use uv_pep508::PackageName;
 use uv_pypi_types::Requirement;
 use uv_python::PythonEnvironment;
+use uv_resolver::Lock;
 use uv_settings::ToolOptions;
 use uv_shell::Shell;
 use uv_tool::{entrypoint_paths, tool_executable_dir, InstalledTools, Tool, ToolEntrypoint};
@@ -70,6 +72,7 @@ pub(crate) fn install_executables(
     force: bool,
     python: Option<String>,
     requirements: Vec<Requirement>,
+    lock: Option<&Lock>,
     printer: Printer,
 ) -> anyhow::Result<ExitStatus> {
     let site_packages = SitePackages::from_environment(environment)?;
@@ -192,6 +195,11 @@ pub(crate) fn install_executables(
     );
     installed_tools.add_tool_receipt(name, tool)?;
 
+    // Persist the resolution, such that it can be reused on subsequent reinstalls and upgrades.
+    if let Some(lock) = lock {
+        installed_tools.add_tool_lock(name, lock)?;
+    }
+
     // If the executable directory isn't on the user's PATH, warn.
     if !Shell::contains_path(&executable_directory) {
         if let Some(shell) = Shell::from_env() {
@@ -226,6 +234,20 @@ pub(crate) fn install_executables(
     Ok(ExitStatus::Success)
 }
 
+/// Verify that a resolution matches the lockfile provided via `--locked`.
+///
+/// The lockfiles are compared by the set of locked packages, each identified by its name, version,
+/// and source.
+pub(crate) fn verify_locked(path: &Path, locked: &Lock, lock: &Lock) -> anyhow::Result<()> {
+    if !locked.has_same_packages(lock) {
+        bail!(
+            "The lockfile at `{}` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv tool install` without `--locked`, and copy the resulting `uv.lock` from the tool directory.",
+            path.user_display()
+        );
+    }
+    Ok(())
+}
+
 /// Displays a hint if an executable matching the package name can be found in a dependency of the package.
 fn hint_executable_from_dependency(
     name: &PackageName,
@@ -1,13 +1,17 @@
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use anyhow::{bail, Result};
 use owo_colors::OwoColorize;
-use tracing::{debug, trace};
+use tracing::{debug, trace, warn};
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::{Concurrency, TrustedHost, Upgrade};
+use uv_configuration::{
+    Concurrency, DevGroupsSpecification, ExtrasSpecification, HashCheckingMode, InstallOptions,
+    TrustedHost, Upgrade,
+};
 use uv_distribution_types::UnresolvedRequirementSpecification;
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
@@ -17,8 +21,10 @@ use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_resolver::{InstallTarget, Lock};
 use uv_settings::{ResolverInstallerOptions, ToolOptions};
 use uv_tool::InstalledTools;
+use uv_types::HashStrategy;
 use uv_warnings::warn_user;
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
@@ -27,7 +33,7 @@ use crate::commands::project::{
     resolve_environment, resolve_names, sync_environment, update_environment,
     EnvironmentSpecification,
 };
-use crate::commands::tool::common::remove_entrypoints;
+use crate::commands::tool::common::{remove_entrypoints, verify_locked};
 use crate::commands::tool::Target;
 use crate::commands::{reporters::PythonDownloadReporter, tool::common::install_executables};
 use crate::commands::{ExitStatus, SharedState};
@@ -42,6 +48,7 @@ pub(crate) async fn install(
     with: &[RequirementsSource],
     python: Option<String>,
     force: bool,
+    locked: Option<PathBuf>,
     options: ResolverInstallerOptions,
     settings: ResolverInstallerSettings,
     python_preference: PythonPreference,
@@ -249,6 +256,11 @@ pub(crate) async fn install(
     let installed_tools = InstalledTools::from_settings()?.init()?;
     let _lock = installed_tools.lock().await?;
 
+    // Read the lockfile provided via `--locked`, if any.
+    let locked = locked
+        .map(|path| uv_tool::read_lock(&path).map(|lock| (path, lock)))
+        .transpose()?;
+
     // Find the existing receipt, if it exists. If the receipt is present but malformed, we'll
     // remove the environment and continue with the install.
     //
@@ -279,9 +291,25 @@ pub(crate) async fn install(
             }
         };
 
+    // Read the lockfile for the existing installation, if any. If the lockfile is malformed, we'll
+    // re-resolve from scratch.
+    let existing_tool_lock = if existing_tool_receipt.is_some() {
+        installed_tools
+            .get_tool_lock(&from.name)
+            .unwrap_or_else(|err| {
+                warn!("Ignoring invalid lockfile for `{}`: {err}", from.name);
+                None
+            })
+    } else {
+        None
+    };
+
+    // If the user provided a lockfile, we always create a new environment, such that we can
+    // validate the resolution before modifying the existing installation.
     let existing_environment =
         installed_tools
             .get_environment(&from.name, &cache)?
+            .filter(|_| locked.is_none())
             .filter(|environment| {
                 if environment.uses(&interpreter) {
                     trace!(
@@ -343,14 +371,20 @@ pub(crate) async fn install(
         ..spec
     };
 
+    // Prefer the versions pinned by the provided lockfile, or by the existing installation.
+    let preferred_lock = locked
+        .as_ref()
+        .map(|(_, lock)| lock)
+        .or(existing_tool_lock.as_ref());
+
     // TODO(zanieb): Build the environment in the cache directory then copy into the tool directory.
     // This lets us confirm the environment is valid before removing an existing install. However,
     // entrypoints always contain an absolute path to the relevant Python interpreter, which would
     // be invalidated by moving the environment.
-    let environment = if let Some(environment) = existing_environment {
-        let environment = update_environment(
+    let (environment, lock) = if let Some(environment) = existing_environment {
+        let update = update_environment(
             environment,
-            spec,
+            EnvironmentSpecification::from(spec).with_lock(preferred_lock),
             &settings,
             &state,
             Box::new(DefaultResolveLogger),
@@ -362,8 +396,7 @@ pub(crate) async fn install(
             &cache,
             printer,
         )
-        .await?
-        .into_environment();
+        .await?;
 
         // At this point, we updated the existing environment, so we should remove any of its
         // existing executables.
@@ -371,12 +404,12 @@ pub(crate) async fn install(
             remove_entrypoints(&existing_receipt);
         }
 
-        environment
+        (update.environment, update.lock)
     } else {
         // If we're creating a new environment, ensure that we can resolve the requirements prior
         // to removing any existing tools.
         let resolution = resolve_environment(
-            EnvironmentSpecification::from(spec),
+            EnvironmentSpecification::from(spec).with_lock(preferred_lock),
             &interpreter,
             settings.as_ref().into(),
             &state,
@@ -390,6 +423,36 @@ pub(crate) async fn install(
         )
         .await?;
 
+        // Record the resolution, with any relative paths resolved against the tool directory.
+        let lock = Lock::from_resolution_graph(&resolution, &installed_tools.tool_dir(&from.name))?;
+
+        // If the user provided a lockfile, ensure that the resolution matches it, then install the
+        // locked packages directly, verifying their hashes.
+        let (resolution, hasher) = if let Some((path, locked)) = locked.as_ref() {
+            verify_locked(path, locked, &lock)?;
+
+            let install_path = std::path::absolute(path)?
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default();
+            let target = InstallTarget::Lock {
+                install_path: &install_path,
+                lock: locked,
+            };
+            let resolution = target.to_resolution(
+                &interpreter.resolver_marker_environment(),
+                interpreter.tags()?,
+                &ExtrasSpecification::None,
+                &DevGroupsSpecification::default().with_defaults(Vec::new()),
+                &settings.build_options,
+                &InstallOptions::default(),
+            )?;
+            let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+            (resolution, hasher)
+        } else {
+            (resolution.into(), HashStrategy::default())
+        };
+
         let environment = installed_tools.create_environment(&from.name, interpreter)?;
 
         // At this point, we removed any existing environment, so we should remove any of its
@@ -399,9 +462,10 @@ pub(crate) async fn install(
         }
 
         // Sync the environment with the resolved requirements.
-        sync_environment(
+        let environment = sync_environment(
             environment,
-            &resolution.into(),
+            &resolution,
+            &hasher,
             settings.as_ref().into(),
             &state,
             Box::new(DefaultInstallLogger),
@@ -417,7 +481,9 @@ pub(crate) async fn install(
             // If we failed to sync, remove the newly created environment.
             debug!("Failed to sync environment; removing `{}`", from.name);
             let _ = installed_tools.remove_environment(&from.name);
-        })?
+        })?;
+
+        (environment, Some(lock))
     };
 
     install_executables(
@@ -428,6 +494,7 @@ pub(crate) async fn install(
         force || invalid_tool_receipt,
         python,
         requirements,
+        lock.as_ref(),
         printer,
     )
 }
@@ -1,21 +1,45 @@
 use std::fmt::Write;
 
 use anyhow::Result;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
 
-use uv_cache::Cache;
+use uv_cache::{Cache, Refresh};
+use uv_cache_info::Timestamp;
+use uv_cli::ToolListFormat;
+use uv_client::{BaseClient, BaseClientBuilder, Connectivity, RegistryClientBuilder};
+use uv_configuration::TrustedHost;
+use uv_distribution_filename::DistFilename;
+use uv_distribution_types::IndexCapabilities;
 use uv_fs::Simplified;
-use uv_tool::InstalledTools;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_python::Interpreter;
+use uv_resolver::RequiresPython;
+use uv_settings::{Combine, ResolverInstallerOptions};
+use uv_tool::{InstalledTools, Tool};
 use uv_warnings::warn_user;
 
+use crate::commands::pip::latest::LatestClient;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
 
 /// List installed tools.
+#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn list(
     show_paths: bool,
     show_version_specifiers: bool,
+    outdated: bool,
+    format: ToolListFormat,
+    filesystem: ResolverInstallerOptions,
+    connectivity: Connectivity,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -23,8 +47,7 @@ pub(crate) async fn list(
     let _lock = match installed_tools.lock().await {
         Ok(lock) => lock,
         Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
-            writeln!(printer.stderr(), "No tools installed")?;
-            return Ok(ExitStatus::Success);
+            return no_tools(format, printer);
         }
         Err(err) => return Err(err.into()),
     };
@@ -33,10 +56,11 @@ pub(crate) async fn list(
     tools.sort_by_key(|(name, _)| name.clone());
 
     if tools.is_empty() {
-        writeln!(printer.stderr(), "No tools installed")?;
-        return Ok(ExitStatus::Success);
+        return no_tools(format, printer);
     }
 
+    // Collect the valid tools, along with the interpreters of their environments.
+    let mut installed = Vec::new();
     for (name, tool) in tools {
         // Skip invalid tools
         let Ok(tool) = tool else {
@@ -56,6 +80,114 @@ pub(crate) async fn list(
             }
         };
 
+        // The interpreter is only required to determine the latest version, or for JSON output.
+        let interpreter = if outdated || matches!(format, ToolListFormat::Json) {
+            match installed_tools.get_environment(&name, cache) {
+                Ok(Some(environment)) => Some(environment.into_interpreter()),
+                // Without an environment, we can't determine the latest version of the tool.
+                Ok(None) if outdated => continue,
+                Ok(None) => None,
+                Err(e) => {
+                    writeln!(printer.stderr(), "{e}")?;
+                    continue;
+                }
+            }
+        } else {
+            None
+        };
+
+        installed.push((name, tool, version, interpreter));
+    }
+
+    // Determine the latest version of each tool, reporting any failures.
+    let mut errors = Vec::new();
+    let latest = if outdated {
+        // Share a single client (and refreshed cache) across all tools.
+        let cache = cache.clone().with_refresh(Refresh::All(Timestamp::now()));
+        let client = BaseClientBuilder::new()
+            .connectivity(connectivity)
+            .native_tls(native_tls)
+            .allow_insecure_host(allow_insecure_host.to_vec())
+            .build();
+
+        let (filesystem, client, cache) = (&filesystem, &client, &cache);
+        let mut fetches = installed
+            .iter()
+            .filter_map(|(name, tool, _, interpreter)| {
+                let interpreter = interpreter.as_ref()?;
+                Some(async move {
+                    let latest = find_latest(
+                        name,
+                        tool,
+                        interpreter,
+                        filesystem,
+                        connectivity,
+                        allow_insecure_host,
+                        client,
+                        cache,
+                    )
+                    .await;
+                    (name, latest)
+                })
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        let mut latest = FxHashMap::default();
+        while let Some((name, result)) = fetches.next().await {
+            match result {
+                Ok(version) => {
+                    latest.insert(name.clone(), version);
+                }
+                Err(err) => {
+                    errors.push((name.clone(), err));
+                }
+            }
+        }
+        latest
+    } else {
+        FxHashMap::default()
+    };
+
+    let mut entries = Vec::new();
+    for (name, tool, version, interpreter) in installed {
+        // Skip any tools that are up-to-date, or for which the latest version couldn't be
+        // determined.
+        let latest = if outdated {
+            let Some(latest) = latest.get(&name).cloned().flatten() else {
+                continue;
+            };
+            if latest <= version {
+                continue;
+            }
+            Some(latest)
+        } else {
+            None
+        };
+
+        if matches!(format, ToolListFormat::Json) {
+            entries.push(ToolEntry {
+                with: tool
+                    .requirements()
+                    .iter()
+                    .filter(|req| req.name != name)
+                    .map(ToString::to_string)
+                    .collect(),
+                entrypoints: tool
+                    .entrypoints()
+                    .iter()
+                    .map(|entrypoint| EntrypointEntry {
+                        name: entrypoint.name.clone(),
+                        path: entrypoint.install_path.simplified_display().to_string(),
+                    })
+                    .collect(),
+                python: interpreter.map(|interpreter| interpreter.python_version().clone()),
+                name,
+                version,
+                latest_version: latest,
+            });
+            continue;
+        }
+
         let version_specifier = if show_version_specifiers {
             let specifiers = tool
                 .requirements()
@@ -69,18 +201,24 @@ pub(crate) async fn list(
             String::new()
         };
 
+        let latest = if let Some(latest) = latest {
+            format!(" [latest: {latest}]")
+        } else {
+            String::new()
+        };
+
         if show_paths {
             writeln!(
                 printer.stdout(),
                 "{} ({})",
-                format!("{name} v{version}{version_specifier}").bold(),
+                format!("{name} v{version}{version_specifier}{latest}").bold(),
                 installed_tools.tool_dir(&name).simplified_display().cyan(),
             )?;
         } else {
             writeln!(
                 printer.stdout(),
                 "{}",
-                format!("{name} v{version}{version_specifier}").bold()
+                format!("{name} v{version}{version_specifier}{latest}").bold()
             )?;
         }
 
@@ -99,5 +237,112 @@ pub(crate) async fn list(
         }
     }
 
+    if matches!(format, ToolListFormat::Json) {
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&entries)?)?;
+    }
+
+    if !errors.is_empty() {
+        for (name, err) in errors
+            .into_iter()
+            .sorted_unstable_by(|(name_a, _), (name_b, _)| name_a.cmp(name_b))
+        {
+            writeln!(
+                printer.stderr(),
+                "{}: Failed to determine the latest version of {}",
+                "error".red().bold(),
+                name.green()
+            )?;
+            for err in err.chain() {
+                writeln!(
+                    printer.stderr(),
+                    "  {}: {}",
+                    "Caused by".red().bold(),
+                    err.to_string().trim()
+                )?;
+            }
+        }
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Report that no tools are installed.
+fn no_tools(format: ToolListFormat, printer: Printer) -> Result<ExitStatus> {
+    match format {
+        ToolListFormat::Text => writeln!(printer.stderr(), "No tools installed")?,
+        ToolListFormat::Json => writeln!(printer.stdout(), "[]")?,
+    }
     Ok(ExitStatus::Success)
 }
+
+/// Find the latest version of a tool.
+///
+/// Respects the index and settings with which the tool was installed, falling back to the user's
+/// settings.
+async fn find_latest(
+    name: &PackageName,
+    tool: &Tool,
+    interpreter: &Interpreter,
+    filesystem: &ResolverInstallerOptions,
+    connectivity: Connectivity,
+    allow_insecure_host: &[TrustedHost],
+    client: &BaseClient,
+    cache: &Cache,
+) -> Result<Option<Version>> {
+    let settings = ResolverInstallerSettings::from(
+        ResolverInstallerOptions::from(tool.options().clone()).combine(filesystem.clone()),
+    );
+
+    let capabilities = IndexCapabilities::default();
+
+    // Initialize the registry client, reusing the shared client.
+    let client = RegistryClientBuilder::new(cache.clone())
+        .connectivity(connectivity)
+        .allow_insecure_host(allow_insecure_host.to_vec())
+        .index_urls(settings.index_locations.index_urls())
+        .index_strategy(settings.index_strategy)
+        .keyring(settings.keyring_provider)
+        .wrap_existing(client);
+
+    // Determine the platform tags.
+    let tags = interpreter.tags()?;
+    let requires_python =
+        RequiresPython::greater_than_equal_version(interpreter.python_full_version());
+
+    // Initialize the client to fetch the latest version of the tool.
+    let client = LatestClient {
+        client: &client,
+        capabilities: &capabilities,
+        prerelease: settings.prerelease,
+        exclude_newer: settings.exclude_newer,
+        tags: Some(tags),
+        requires_python: &requires_python,
+    };
+
+    Ok(client
+        .find_latest(name, None)
+        .await?
+        .as_ref()
+        .map(DistFilename::version)
+        .cloned())
+}
+
+/// An installed tool, as displayed by `uv tool list --format json`.
+#[derive(Debug, Serialize)]
+struct ToolEntry {
+    name: PackageName,
+    version: Version,
+    python: Option<Version>,
+    entrypoints: Vec<EntrypointEntry>,
+    with: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest_version: Option<Version>,
+}
+
+/// An executable provided by an installed tool.
+#[derive(Debug, Serialize)]
+struct EntrypointEntry {
+    name: String,
+    path: String,
+}
@@ -0,0 +1,123 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::{Concurrency, TrustedHost};
+use uv_python::{PythonDownloads, PythonPreference};
+use uv_requirements::RequirementsSource;
+use uv_settings::{Combine, ResolverInstallerOptions};
+use uv_tool::{InstalledTools, ToolManifest};
+
+use crate::commands::tool::install::install;
+use crate::commands::tool::uninstall::uninstall;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
+
+/// Install the tools declared in a tool manifest.
+pub(crate) async fn sync(
+    manifest: PathBuf,
+    exact: bool,
+    args: ResolverInstallerOptions,
+    filesystem: ResolverInstallerOptions,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    allow_insecure_host: &[TrustedHost],
+    cache: Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let manifest = ToolManifest::from_path(&manifest)?;
+
+    let mut status = ExitStatus::Success;
+    for tool in manifest.tools() {
+        debug!("Syncing tool `{}`", tool.name);
+
+        // Resolve the appropriate settings, preferring: CLI > manifest > user.
+        let options = args.clone().combine(
+            ResolverInstallerOptions::from(tool.options.clone()).combine(filesystem.clone()),
+        );
+        let settings = ResolverInstallerSettings::from(options.clone());
+
+        let with = tool
+            .with
+            .iter()
+            .cloned()
+            .map(RequirementsSource::from_with_package)
+            .collect::<Vec<_>>();
+
+        // Installing the tool is a no-op if the existing installation matches the manifest, and
+        // otherwise updates the environment and receipt in-place.
+        let result = Box::pin(install(
+            tool.requirement(),
+            false,
+            None,
+            &with,
+            tool.python.clone(),
+            false,
+            None,
+            options,
+            settings,
+            python_preference,
+            python_downloads,
+            connectivity,
+            concurrency,
+            native_tls,
+            allow_insecure_host,
+            cache.clone(),
+            printer,
+        ))
+        .await;
+
+        // Report any failures, but continue syncing the remaining tools.
+        match result {
+            Ok(ExitStatus::Success) => {}
+            Ok(result) => status = result,
+            Err(err) => {
+                writeln!(
+                    printer.stderr(),
+                    "{}: Failed to sync {}",
+                    "error".red().bold(),
+                    tool.name.green()
+                )?;
+                for err in err.chain() {
+                    writeln!(
+                        printer.stderr(),
+                        "  {}: {}",
+                        "Caused by".red().bold(),
+                        err.to_string().trim()
+                    )?;
+                }
+                status = ExitStatus::Failure;
+            }
+        }
+    }
+
+    // If requested, uninstall any tools that aren't declared in the manifest.
+    if exact {
+        let installed_tools = InstalledTools::from_settings()?.init()?;
+        let extraneous = {
+            let _lock = installed_tools.lock().await?;
+            installed_tools
+                .tools()?
+                .into_iter()
+                .map(|(name, _)| name)
+                .filter(|name| !manifest.tools().iter().any(|tool| tool.name == *name))
+                .collect::<Vec<_>>()
+        };
+
+        // Note that `uninstall` removes _all_ tools if no names are provided.
+        if !extraneous.is_empty() {
+            uninstall(extraneous, printer).await?;
+        }
+    }
+
+    Ok(status)
+}
@@ -3,7 +3,7 @@ use std::{collections::BTreeSet, fmt::Write};
 use anyhow::Result;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use tracing::debug;
+use tracing::{debug, warn};
 
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity};
@@ -14,14 +14,17 @@ use uv_python::{
     PythonRequest,
 };
 use uv_requirements::RequirementsSpecification;
+use uv_resolver::Lock;
 use uv_settings::{Combine, ResolverInstallerOptions, ToolOptions};
 use uv_tool::InstalledTools;
+use uv_types::HashStrategy;
 
 use crate::commands::pip::loggers::{
     DefaultInstallLogger, SummaryResolveLogger, UpgradeInstallLogger,
 };
 use crate::commands::project::{
-    resolve_environment, sync_environment, update_environment, EnvironmentUpdate,
+    resolve_environment, sync_environment, update_environment, EnvironmentSpecification,
+    EnvironmentUpdate,
 };
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::tool::common::remove_entrypoints;
@@ -254,17 +257,27 @@ async fn upgrade_tool(
     let requirements = existing_tool_receipt.requirements();
     let spec = RequirementsSpecification::from_requirements(requirements.to_vec());
 
+    // Read the existing lockfile, if any. Unless a package is being upgraded, we'll prefer the
+    // locked versions.
+    let existing_tool_lock = installed_tools.get_tool_lock(name).unwrap_or_else(|err| {
+        warn!("Ignoring invalid lockfile for `{name}`: {err}");
+        None
+    });
+
     // Initialize any shared state.
     let state = SharedState::default();
 
     // Check if we need to create a new environment — if so, resolve it first, then
     // install the requested tool
-    let (environment, outcome) = if let Some(interpreter) =
+    let (environment, lock, outcome) = if let Some(interpreter) =
         interpreter.filter(|interpreter| !environment.uses(interpreter))
     {
         // If we're using a new interpreter, re-create the environment for each tool.
         let resolution = resolve_environment(
-            RequirementsSpecification::from_requirements(requirements.to_vec()).into(),
+            EnvironmentSpecification::from(RequirementsSpecification::from_requirements(
+                requirements.to_vec(),
+            ))
+            .with_lock(existing_tool_lock.as_ref()),
             interpreter,
             settings.as_ref().into(),
             &state,
@@ -278,11 +291,14 @@ async fn upgrade_tool(
         )
         .await?;
 
+        let lock = Lock::from_resolution_graph(&resolution, &installed_tools.tool_dir(name))?;
+
         let environment = installed_tools.create_environment(name, interpreter.clone())?;
 
         let environment = sync_environment(
             environment,
             &resolution.into(),
+            &HashStrategy::default(),
             settings.as_ref().into(),
             &state,
             Box::new(DefaultInstallLogger),
@@ -295,7 +311,7 @@ async fn upgrade_tool(
         )
         .await?;
 
-        (environment, UpgradeOutcome::UpgradeEnvironment)
+        (environment, Some(lock), UpgradeOutcome::UpgradeEnvironment)
     } else {
         // Otherwise, upgrade the existing environment.
         // TODO(zanieb): Build the environment in the cache directory then copy into the tool
@@ -303,9 +319,10 @@ async fn upgrade_tool(
         let EnvironmentUpdate {
             environment,
             changelog,
+            lock,
         } = update_environment(
             environment,
-            spec,
+            EnvironmentSpecification::from(spec).with_lock(existing_tool_lock.as_ref()),
             &settings,
             &state,
             Box::new(SummaryResolveLogger),
@@ -327,7 +344,7 @@ async fn upgrade_tool(
             UpgradeOutcome::UpgradeDependencies
         };
 
-        (environment, outcome)
+        (environment, lock, outcome)
     };
 
     if matches!(
@@ -347,10 +364,16 @@ async fn upgrade_tool(
             true,
             existing_tool_receipt.python().to_owned(),
             requirements.to_vec(),
+            None,
             printer,
         )?;
     }
 
+    // Persist the resolution, even if the tool itself was unchanged.
+    if let Some(lock) = lock {
+        installed_tools.add_tool_lock(name, &lock)?;
+    }
+
     Ok(outcome)
 }
 
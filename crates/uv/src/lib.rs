@@ -7,18 +7,18 @@ use std::path::Path;
 use std::process::ExitCode;
 
 use anstream::eprintln;
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use clap::error::{ContextKind, ContextValue};
 use clap::{CommandFactory, Parser};
 use owo_colors::OwoColorize;
 use settings::PipTreeSettings;
 use tokio::task::spawn_blocking;
 use tracing::{debug, instrument};
-use uv_cache::{Cache, Refresh};
+use uv_cache::{Cache, Eviction, Refresh, RemoteCache};
 use uv_cache_info::Timestamp;
 use uv_cli::{
-    compat::CompatArgs, BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands,
-    PipCommand, PipNamespace, ProjectCommand,
+    compat::CompatArgs, AuthCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
+    CacheNamespace, Cli, Commands, PipCommand, PipNamespace, ProjectCommand,
 };
 use uv_cli::{PythonCommand, PythonNamespace, ToolCommand, ToolNamespace, TopLevelArgs};
 #[cfg(feature = "self-update")]
@@ -279,7 +279,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     show_settings!(cache_settings, false);
 
     // Configure the cache.
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_remote(cache_settings.remote_cache_url.map(RemoteCache::new));
 
     let result = match *cli.command {
         Commands::Help(args) => commands::help(
@@ -414,6 +415,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &requirements,
                 &constraints,
                 &build_constraints,
+                &args.settings.extras,
+                args.dev,
+                &args.install_options,
                 args.settings.reinstall,
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
@@ -498,6 +502,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.constraints_from_workspace,
                 args.overrides_from_workspace,
                 &args.settings.extras,
+                args.dev,
+                &args.install_options,
                 args.settings.resolution,
                 args.settings.prerelease,
                 args.settings.dependency_mode,
@@ -683,6 +689,39 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 printer,
             )
         }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Login(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthLoginSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_login(
+                &args.service,
+                args.username,
+                args.password,
+                &args.index_locations,
+                printer,
+            )
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Logout(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthLogoutSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_logout(&args.service, &args.index_locations, printer)
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Token(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthTokenSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_token(&args.service, &args.index_locations, printer)
+        }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Clean(args),
         })
@@ -694,7 +733,21 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             command: CacheCommand::Prune(args),
         }) => {
             show_settings!(args);
-            commands::cache_prune(args.ci, &cache, printer)
+            commands::cache_prune(
+                args.ci,
+                Eviction {
+                    max_size: args.max_size,
+                    older_than: args.older_than,
+                },
+                &cache,
+                printer,
+            )
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Stats(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_stats(args.format, &cache, printer)
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
@@ -955,6 +1008,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &requirements,
                 args.python,
                 args.force,
+                args.locked,
                 args.options,
                 args.settings,
                 globals.python_preference,
@@ -981,6 +1035,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::tool_list(
                 args.show_paths,
                 args.show_version_specifiers,
+                args.outdated,
+                args.format,
+                args.filesystem,
+                globals.connectivity,
+                globals.native_tls,
+                &globals.allow_insecure_host,
                 &cache,
                 printer,
             )
@@ -1012,6 +1072,37 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             ))
             .await
         }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Sync(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolSyncSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let settings = settings::ResolverInstallerSettings::from(args.args.clone());
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(settings.reinstall))
+                    .combine(Refresh::from(settings.upgrade)),
+            );
+
+            Box::pin(commands::tool_sync(
+                args.manifest,
+                args.exact,
+                args.args,
+                args.filesystem,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.connectivity,
+                globals.concurrency,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                cache,
+                printer,
+            ))
+            .await
+        }
         Commands::Tool(ToolNamespace {
             command: ToolCommand::Uninstall(args),
         }) => {
@@ -1053,6 +1144,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.all_platforms,
                 globals.python_preference,
                 globals.python_downloads,
+                globals.native_tls,
+                globals.connectivity,
+                &globals.allow_insecure_host,
                 &cache,
                 printer,
             )
@@ -1080,6 +1174,29 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             )
             .await
         }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Upgrade(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonUpgradeSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::python_upgrade(
+                &project_dir,
+                args.targets,
+                args.remove_old,
+                globals.python_downloads,
+                globals.native_tls,
+                globals.connectivity,
+                &globals.allow_insecure_host,
+                &cache,
+                printer,
+            )
+            .await
+        }
         Commands::Python(PythonNamespace {
             command: PythonCommand::Uninstall(args),
         }) => {
@@ -1165,8 +1282,63 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 trusted_publishing,
                 keyring_provider,
                 check_url,
+                index,
+                index_locations,
             } = PublishSettings::resolve(args, filesystem);
 
+            // If an index was provided by name, use its publish URL, check URL, and credentials.
+            let (publish_url, check_url, username, password) = if let Some(index_name) = index {
+                debug!("Publishing with index {index_name}");
+                let index = index_locations
+                    .allowed_indexes()
+                    .into_iter()
+                    .find(|index| {
+                        index
+                            .name
+                            .as_ref()
+                            .is_some_and(|name| name.as_ref() == index_name)
+                    })
+                    .with_context(|| {
+                        let mut index_names: Vec<String> = index_locations
+                            .allowed_indexes()
+                            .into_iter()
+                            .filter_map(|index| index.name.as_ref())
+                            .map(ToString::to_string)
+                            .collect();
+                        index_names.sort();
+                        if index_names.is_empty() {
+                            format!("No indexes were found, can't use index: `{index_name}`")
+                        } else {
+                            let index_names = index_names.join("`, `");
+                            format!(
+                                "Index not found: `{index_name}`. Found indexes: `{index_names}`"
+                            )
+                        }
+                    })?;
+                let publish_url = index
+                    .publish_url
+                    .clone()
+                    .with_context(|| format!("Index is missing a publish URL: `{index_name}`"))?;
+
+                // Prefer explicitly provided credentials over those configured for the index.
+                let (username, password) = if username.is_none() && password.is_none() {
+                    if let Some(credentials) = index.credentials() {
+                        let username = credentials.username().map(ToString::to_string);
+                        let password = credentials.password().map(ToString::to_string);
+                        uv_auth::store_credentials(index.raw_url(), credentials);
+                        (username, password)
+                    } else {
+                        (None, None)
+                    }
+                } else {
+                    (username, password)
+                };
+
+                (publish_url, Some(index.url.clone()), username, password)
+            } else {
+                (publish_url, check_url, username, password)
+            };
+
             commands::publish(
                 files,
                 publish_url,
@@ -1397,6 +1569,7 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.dry_run,
+                args.diff,
                 args.python,
                 args.settings,
                 globals.python_preference,
@@ -1489,6 +1662,8 @@ async fn run_project(
                 args.frozen,
                 args.no_sync,
                 args.packages,
+                args.unused,
+                args.dry_run,
                 args.dependency_type,
                 args.package,
                 args.python,
@@ -1526,6 +1701,7 @@ async fn run_project(
                 args.no_dedupe,
                 args.invert,
                 args.outdated,
+                args.format,
                 args.python_version,
                 args.python_platform,
                 args.python,
@@ -1579,6 +1755,26 @@ async fn run_project(
             )
             .await
         }
+        ProjectCommand::Audit(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuditSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::audit(
+                project_dir,
+                args.database,
+                &args.ignore,
+                globals.connectivity,
+                globals.native_tls,
+                &globals.allow_insecure_host,
+                &cache,
+                printer,
+            )
+            .await
+        }
     }
 }
 
@@ -9,14 +9,16 @@ use uv_cache::{CacheArgs, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
     options::{flag, resolver_installer_options, resolver_options},
-    AuthorFrom, BuildArgs, ExportArgs, PublishArgs, PythonDirArgs, ToolUpgradeArgs,
+    AuditArgs, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, AuthorFrom, BuildArgs, ExportArgs,
+    PublishArgs, PythonDirArgs, ToolListFormat, ToolSyncArgs, ToolUpgradeArgs,
 };
 use uv_cli::{
-    AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs, SyncArgs, ToolDirArgs,
-    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
+    AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs,
+    LockDiffFormat, Maybe, PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs,
+    PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs,
+    PythonInstallArgs, PythonListArgs, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs,
+    RemoveArgs, RunArgs, SyncArgs, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
+    ToolUninstallArgs, TreeArgs, TreeFormat, VenvArgs,
 };
 use uv_client::Connectivity;
 use uv_configuration::{
@@ -159,6 +161,7 @@ impl GlobalSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) remote_cache_url: Option<Url>,
 }
 
 impl CacheSettings {
@@ -172,6 +175,9 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            remote_cache_url: env(env::REMOTE_CACHE_URL).or_else(|| {
+                workspace.and_then(|workspace| workspace.globals.remote_cache_url.clone())
+            }),
         }
     }
 }
@@ -443,6 +449,7 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) options: ResolverInstallerOptions,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
+    pub(crate) locked: Option<PathBuf>,
     pub(crate) editable: bool,
 }
 
@@ -459,6 +466,7 @@ impl ToolInstallSettings {
             with_requirements,
             installer,
             force,
+            locked,
             build,
             refresh,
             python,
@@ -490,6 +498,7 @@ impl ToolInstallSettings {
                 .collect(),
             python: python.and_then(Maybe::into_option),
             force,
+            locked,
             editable,
             refresh: Refresh::from(refresh),
             options,
@@ -543,28 +552,80 @@ impl ToolUpgradeSettings {
     }
 }
 
+/// The resolved settings to use for a `tool sync` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ToolSyncSettings {
+    pub(crate) manifest: PathBuf,
+    pub(crate) exact: bool,
+    pub(crate) refresh: Refresh,
+    pub(crate) args: ResolverInstallerOptions,
+    pub(crate) filesystem: ResolverInstallerOptions,
+}
+
+impl ToolSyncSettings {
+    /// Resolve the [`ToolSyncSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolSyncArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolSyncArgs {
+            manifest,
+            exact,
+            installer,
+            build,
+            refresh,
+        } = args;
+
+        let args = resolver_installer_options(installer, build);
+        let filesystem = filesystem
+            .map(FilesystemOptions::into_options)
+            .map(|options| options.top_level)
+            .unwrap_or_default();
+
+        Self {
+            manifest,
+            exact,
+            refresh: Refresh::from(refresh),
+            args,
+            filesystem,
+        }
+    }
+}
+
 /// The resolved settings to use for a `tool list` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub(crate) struct ToolListSettings {
     pub(crate) show_paths: bool,
     pub(crate) show_version_specifiers: bool,
+    pub(crate) outdated: bool,
+    pub(crate) format: ToolListFormat,
+    pub(crate) filesystem: ResolverInstallerOptions,
 }
 
 impl ToolListSettings {
     /// Resolve the [`ToolListSettings`] from the CLI and filesystem configuration.
     #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: ToolListArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+    pub(crate) fn resolve(args: ToolListArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let ToolListArgs {
             show_paths,
             show_version_specifiers,
+            outdated,
+            format,
             python_preference: _,
             no_python_downloads: _,
         } = args;
 
+        let filesystem = filesystem
+            .map(FilesystemOptions::into_options)
+            .map(|options| options.top_level)
+            .unwrap_or_default();
+
         Self {
             show_paths,
             show_version_specifiers,
+            outdated,
+            format,
+            filesystem,
         }
     }
 }
@@ -689,6 +750,30 @@ impl PythonInstallSettings {
     }
 }
 
+/// The resolved settings to use for a `python upgrade` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct PythonUpgradeSettings {
+    pub(crate) targets: Vec<String>,
+    pub(crate) remove_old: bool,
+}
+
+impl PythonUpgradeSettings {
+    /// Resolve the [`PythonUpgradeSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PythonUpgradeArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let PythonUpgradeArgs {
+            targets,
+            remove_old,
+        } = args;
+
+        Self {
+            targets,
+            remove_old,
+        }
+    }
+}
+
 /// The resolved settings to use for a `python uninstall` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -856,6 +941,7 @@ pub(crate) struct LockSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) dry_run: bool,
+    pub(crate) diff: Option<LockDiffFormat>,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
@@ -869,6 +955,8 @@ impl LockSettings {
             locked,
             frozen,
             dry_run,
+            diff,
+            diff_format,
             resolver,
             build,
             refresh,
@@ -879,6 +967,7 @@ impl LockSettings {
             locked,
             frozen,
             dry_run,
+            diff: diff.then_some(diff_format),
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
@@ -1027,6 +1116,8 @@ pub(crate) struct RemoveSettings {
     pub(crate) frozen: bool,
     pub(crate) no_sync: bool,
     pub(crate) packages: Vec<PackageName>,
+    pub(crate) unused: bool,
+    pub(crate) dry_run: bool,
     pub(crate) dependency_type: DependencyType,
     pub(crate) package: Option<PackageName>,
     pub(crate) script: Option<PathBuf>,
@@ -1043,6 +1134,8 @@ impl RemoveSettings {
             dev,
             optional,
             packages,
+            unused,
+            dry_run,
             group,
             no_sync,
             locked,
@@ -1070,6 +1163,8 @@ impl RemoveSettings {
             frozen,
             no_sync,
             packages,
+            unused,
+            dry_run,
             dependency_type,
             package,
             script,
@@ -1097,6 +1192,7 @@ pub(crate) struct TreeSettings {
     pub(crate) no_dedupe: bool,
     pub(crate) invert: bool,
     pub(crate) outdated: bool,
+    pub(crate) format: TreeFormat,
     pub(crate) python_version: Option<PythonVersion>,
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) python: Option<String>,
@@ -1109,6 +1205,7 @@ impl TreeSettings {
         let TreeArgs {
             tree,
             universal,
+            format,
             dev,
             only_dev,
             no_dev,
@@ -1137,6 +1234,7 @@ impl TreeSettings {
             no_dedupe: tree.no_dedupe,
             invert: tree.invert,
             outdated: tree.outdated,
+            format,
             python_version,
             python_platform,
             python: python.and_then(Maybe::into_option),
@@ -1145,6 +1243,23 @@ impl TreeSettings {
     }
 }
 
+/// The resolved settings to use for an `audit` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct AuditSettings {
+    pub(crate) database: Option<PathBuf>,
+    pub(crate) ignore: Vec<String>,
+}
+
+impl AuditSettings {
+    /// Resolve the [`AuditSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: AuditArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let AuditArgs { database, ignore } = args;
+
+        Self { database, ignore }
+    }
+}
+
 /// The resolved settings to use for an `export` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
 #[derive(Debug, Clone)]
@@ -1391,6 +1506,8 @@ pub(crate) struct PipSyncSettings {
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraint: Vec<PathBuf>,
     pub(crate) build_constraint: Vec<PathBuf>,
+    pub(crate) dev: DevGroupsSpecification,
+    pub(crate) install_options: InstallOptions,
     pub(crate) dry_run: bool,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
@@ -1403,6 +1520,13 @@ impl PipSyncSettings {
             src_file,
             constraint,
             build_constraint,
+            extra,
+            all_extras,
+            no_all_extras,
+            group,
+            no_install_project,
+            no_install_workspace,
+            no_install_package,
             installer,
             refresh,
             require_hashes,
@@ -1440,6 +1564,19 @@ impl PipSyncSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            dev: DevGroupsSpecification::from_args(
+                false,
+                false,
+                false,
+                group,
+                Vec::new(),
+                Vec::new(),
+            ),
+            install_options: InstallOptions::new(
+                no_install_project,
+                no_install_workspace,
+                no_install_package,
+            ),
             dry_run,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
@@ -1449,6 +1586,8 @@ impl PipSyncSettings {
                     break_system_packages: flag(break_system_packages, no_break_system_packages),
                     target,
                     prefix,
+                    extra,
+                    all_extras: flag(all_extras, no_all_extras),
                     require_hashes: flag(require_hashes, no_require_hashes),
                     verify_hashes: flag(verify_hashes, no_verify_hashes),
                     no_build: flag(no_build, build),
@@ -1483,6 +1622,8 @@ pub(crate) struct PipInstallSettings {
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) modifications: Modifications,
+    pub(crate) dev: DevGroupsSpecification,
+    pub(crate) install_options: InstallOptions,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -1500,6 +1641,10 @@ impl PipInstallSettings {
             extra,
             all_extras,
             no_all_extras,
+            group,
+            no_install_project,
+            no_install_workspace,
+            no_install_package,
             installer,
             refresh,
             no_deps,
@@ -1581,6 +1726,19 @@ impl PipInstallSettings {
             } else {
                 Modifications::Sufficient
             },
+            dev: DevGroupsSpecification::from_args(
+                false,
+                false,
+                false,
+                group,
+                Vec::new(),
+                Vec::new(),
+            ),
+            install_options: InstallOptions::new(
+                no_install_project,
+                no_install_workspace,
+                no_install_package,
+            ),
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2651,6 +2809,8 @@ pub(crate) struct PublishSettings {
     pub(crate) trusted_publishing: TrustedPublishing,
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) check_url: Option<IndexUrl>,
+    pub(crate) index: Option<String>,
+    pub(crate) index_locations: IndexLocations,
 }
 
 impl PublishSettings {
@@ -2667,7 +2827,9 @@ impl PublishSettings {
             trusted_publishing,
         } = publish;
         let ResolverInstallerOptions {
-            keyring_provider, ..
+            keyring_provider,
+            index,
+            ..
         } = top_level;
 
         // Tokens are encoded in the same way as username/password
@@ -2693,10 +2855,93 @@ impl PublishSettings {
                 .combine(keyring_provider)
                 .unwrap_or_default(),
             check_url: args.check_url,
+            index: args.index,
+            index_locations: IndexLocations::new(index.unwrap_or_default(), Vec::new(), false),
         }
     }
 }
 
+/// The resolved settings to use for an invocation of the `uv auth login` CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthLoginSettings {
+    pub(crate) service: String,
+    pub(crate) username: Option<String>,
+    pub(crate) password: Option<String>,
+    pub(crate) index_locations: IndexLocations,
+}
+
+impl AuthLoginSettings {
+    /// Resolve the [`AuthLoginSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthLoginArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let AuthLoginArgs {
+            service,
+            username,
+            password,
+            token,
+        } = args;
+
+        // Tokens are encoded in the same way as username/password
+        let (username, password) = if let Some(token) = token {
+            (Some("__token__".to_string()), Some(token))
+        } else {
+            (username, password)
+        };
+
+        Self {
+            service,
+            username,
+            password,
+            index_locations: auth_index_locations(filesystem),
+        }
+    }
+}
+
+/// The resolved settings to use for an invocation of the `uv auth logout` CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthLogoutSettings {
+    pub(crate) service: String,
+    pub(crate) index_locations: IndexLocations,
+}
+
+impl AuthLogoutSettings {
+    /// Resolve the [`AuthLogoutSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthLogoutArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let AuthLogoutArgs { service } = args;
+
+        Self {
+            service,
+            index_locations: auth_index_locations(filesystem),
+        }
+    }
+}
+
+/// The resolved settings to use for an invocation of the `uv auth token` CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthTokenSettings {
+    pub(crate) service: String,
+    pub(crate) index_locations: IndexLocations,
+}
+
+impl AuthTokenSettings {
+    /// Resolve the [`AuthTokenSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthTokenArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let AuthTokenArgs { service } = args;
+
+        Self {
+            service,
+            index_locations: auth_index_locations(filesystem),
+        }
+    }
+}
+
+/// Return the [`IndexLocations`] against which `uv auth` resolves named services.
+fn auth_index_locations(filesystem: Option<FilesystemOptions>) -> IndexLocations {
+    let Options { top_level, .. } = filesystem
+        .map(FilesystemOptions::into_options)
+        .unwrap_or_default();
+    IndexLocations::new(top_level.index.unwrap_or_default(), Vec::new(), false)
+}
+
 // Environment variables that are not exposed as CLI arguments.
 mod env {
     use uv_static::EnvVars;
@@ -2710,6 +2955,8 @@ mod env {
     pub(super) const CONCURRENT_INSTALLS: (&str, &str) =
         (EnvVars::UV_CONCURRENT_INSTALLS, "a non-zero integer");
 
+    pub(super) const REMOTE_CACHE_URL: (&str, &str) = (EnvVars::UV_REMOTE_CACHE_URL, "a URL");
+
     pub(super) const UV_PYTHON_DOWNLOADS: (&str, &str) = (
         EnvVars::UV_PYTHON_DOWNLOADS,
         "one of 'auto', 'true', 'manual', 'never', or 'false'",
@@ -0,0 +1,177 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Audit a lockfile against a local OSV advisory database.
+#[test]
+fn audit_database() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    let database = context.temp_dir.child("advisories");
+
+    // An advisory that affects `anyio==3.7.0`.
+    database.child("GHSA-0000-0000-0001.json").write_str(
+        r#"{
+            "id": "GHSA-0000-0000-0001",
+            "summary": "Example vulnerability in anyio",
+            "aliases": ["CVE-0000-0001"],
+            "affected": [
+                {
+                    "package": {"ecosystem": "PyPI", "name": "AnyIO"},
+                    "ranges": [
+                        {
+                            "type": "ECOSYSTEM",
+                            "events": [{"introduced": "0"}, {"fixed": "4.4.0"}]
+                        }
+                    ]
+                }
+            ]
+        }"#,
+    )?;
+
+    // An advisory that doesn't affect `idna==3.6`.
+    database.child("PYSEC-0000-2.json").write_str(
+        r#"{
+            "id": "PYSEC-0000-2",
+            "affected": [
+                {
+                    "package": {"ecosystem": "PyPI", "name": "idna"},
+                    "ranges": [
+                        {
+                            "type": "ECOSYSTEM",
+                            "events": [{"introduced": "3.7"}, {"fixed": "3.8"}]
+                        }
+                    ]
+                }
+            ]
+        }"#,
+    )?;
+
+    // A withdrawn advisory that would otherwise affect `sniffio==1.3.1`.
+    database.child("PYSEC-0000-3.json").write_str(
+        r#"{
+            "id": "PYSEC-0000-3",
+            "withdrawn": "2024-01-01T00:00:00Z",
+            "affected": [
+                {
+                    "package": {"ecosystem": "PyPI", "name": "sniffio"},
+                    "versions": ["1.3.1"]
+                }
+            ]
+        }"#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.audit().arg("--database").arg("advisories"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    anyio 3.7.0
+      GHSA-0000-0000-0001: Example vulnerability in anyio
+        Aliases: CVE-0000-0001
+        Affected versions: <4.4.0
+        Fixed in: 4.4.0
+
+    ----- stderr -----
+    Found 1 known vulnerability in 1 package (audited 3)
+    "###);
+
+    // Ignore the advisory by its alias.
+    uv_snapshot!(context.filters(), context.audit().arg("--database").arg("advisories").arg("--ignore").arg("CVE-0000-0001"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 3 packages; no known vulnerabilities found
+    "###);
+
+    // A malformed advisory is skipped with a warning.
+    database.child("broken.json").write_str("{")?;
+
+    uv_snapshot!(context.filters(), context.audit().arg("--database").arg("advisories").arg("--ignore").arg("CVE-0000-0001"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Skipping malformed advisory `advisories/broken.json`: EOF while parsing an object at line 1 column 1
+    Audited 3 packages; no known vulnerabilities found
+    "###);
+
+    Ok(())
+}
+
+/// Without a pre-fetched database, auditing offline requires a cached copy.
+#[test]
+fn audit_offline() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.audit().arg("--offline"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No advisory database found in the cache, and network connectivity is disabled. Provide a pre-fetched database with `--database`.
+    "###);
+
+    Ok(())
+}
+
+/// Auditing requires an existing lockfile.
+#[test]
+fn audit_missing_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context.temp_dir.child("advisories").create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.audit().arg("--database").arg("advisories"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    "###);
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+fn auth_login_token_logout() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    let credentials_dir = context.temp_dir.child("credentials");
+
+    uv_snapshot!(context.filters(), context.command()
+        .arg("auth")
+        .arg("login")
+        .arg("https://example.com/simple")
+        .arg("--username")
+        .arg("public")
+        .arg("--password")
+        .arg("heron")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for https://example.com in: credentials/credentials.toml
+    "###);
+
+    // Credentials are stored per realm.
+    let contents = fs_err::read_to_string(credentials_dir.child("credentials.toml"))?;
+    insta::assert_snapshot!(contents, @r###"
+    [credentials."https://example.com"]
+    username = "public"
+    password = "heron"
+    "###);
+
+    // The password is retrievable for any URL in the realm.
+    uv_snapshot!(context.filters(), context.command()
+        .arg("auth")
+        .arg("token")
+        .arg("example.com")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    heron
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.command()
+        .arg("auth")
+        .arg("logout")
+        .arg("https://example.com/files")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed credentials for https://example.com
+    "###);
+
+    uv_snapshot!(context.filters(), context.command()
+        .arg("auth")
+        .arg("token")
+        .arg("example.com")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No credentials found for: `https://example.com`
+    "###);
+
+    uv_snapshot!(context.filters(), context.command()
+        .arg("auth")
+        .arg("logout")
+        .arg("example.com")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No credentials found for: `https://example.com`
+    "###);
+
+    Ok(())
+}
+
+/// Credentials can be stored for an index by name.
+#[test]
+fn auth_login_index() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    let credentials_dir = context.temp_dir.child("credentials");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "https://pypi.example.com:8443/simple"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.command()
+        .arg("auth")
+        .arg("login")
+        .arg("internal")
+        .arg("--token")
+        .arg("pypi-token")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for https://pypi.example.com:8443 in: credentials/credentials.toml
+    "###);
+
+    let contents = fs_err::read_to_string(credentials_dir.child("credentials.toml"))?;
+    insta::assert_snapshot!(contents, @r###"
+    [credentials."https://pypi.example.com:8443"]
+    username = "__token__"
+    password = "pypi-token"
+    "###);
+
+    uv_snapshot!(context.filters(), context.command()
+        .arg("auth")
+        .arg("token")
+        .arg("internal")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pypi-token
+
+    ----- stderr -----
+    "###);
+
+    // Without a terminal, the password must be provided.
+    uv_snapshot!(context.filters(), context.command()
+        .arg("auth")
+        .arg("login")
+        .arg("internal")
+        .arg("--username")
+        .arg("public")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No password provided; did you mean to provide `--password` or `--token`?
+    "###);
+
+    Ok(())
+}
@@ -52,3 +52,73 @@ fn uv_backend_direct() -> Result<()> {
 
     Ok(())
 }
+
+/// Test that the editable built by the build backend can be installed and imported.
+#[test]
+fn uv_backend_direct_editable() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let uv_backend = Path::new("../../scripts/packages/uv_backend");
+
+    let temp_dir = TempDir::new()?;
+
+    uv_snapshot!(context
+        .build_backend()
+        .arg("build-editable")
+        .arg(temp_dir.path())
+        .current_dir(uv_backend), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    uv_backend-0.1.0-py3-none-any.whl
+
+    ----- stderr -----
+    "###);
+
+    context
+        .pip_install()
+        .arg(temp_dir.path().join("uv_backend-0.1.0-py3-none-any.whl"))
+        .assert()
+        .success();
+
+    uv_snapshot!(context
+        .run()
+        .arg("python")
+        .arg("-c")
+        .arg("import uv_backend\nuv_backend.greet()")
+        // Python on windows
+        .env(EnvVars::PYTHONUTF8, "1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello 👋
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// Test that the `get_requires_for_build_*` hooks return an empty list.
+#[test]
+fn uv_backend_direct_get_requires() {
+    let context = TestContext::new("3.12");
+    let uv_backend = Path::new("../../scripts/packages/uv_backend");
+
+    for hook in [
+        "get-requires-for-build-sdist",
+        "get-requires-for-build-wheel",
+        "get-requires-for-build-editable",
+    ] {
+        uv_snapshot!(context
+            .build_backend()
+            .arg(hook)
+            .current_dir(uv_backend), @r###"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        []
+
+        ----- stderr -----
+        "###);
+    }
+}
@@ -182,7 +182,8 @@ fn prune_stale_symlink() -> Result<()> {
     DEBUG uv [VERSION] ([COMMIT] DATE)
     Pruning cache at: [CACHE_DIR]/
     DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
-    Removed 44 files ([SIZE])
+    DEBUG Removing dangling access record: [CACHE_DIR]/access-v0/[ENTRY]
+    Removed 45 files ([SIZE])
     "###);
 
     Ok(())
@@ -357,6 +358,7 @@ fn prune_stale_revision() -> Result<()> {
     Pruning cache at: [CACHE_DIR]/
     DEBUG Removing dangling source revision: [CACHE_DIR]/sdists-v6/[ENTRY]
     DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
+    DEBUG Removing dangling access record: [CACHE_DIR]/access-v0/[ENTRY]
     Removed [N] files ([SIZE])
     "###);
 
@@ -388,3 +390,142 @@ fn prune_stale_revision() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache prune --older-than` should evict any unzipped wheels that haven't been used recently.
+#[test]
+fn prune_older_than() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    let filters: Vec<_> = std::iter::once((r"Removed \d+ files", "Removed [N] files"))
+        .chain(context.filters())
+        .collect();
+
+    // Install a requirement, to populate the cache. Copy the files, such that the environment
+    // doesn't reference the cache.
+    uv_snapshot!(&filters, context.pip_install().arg("-r").arg("requirements.txt").arg("--link-mode").arg("copy"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    // The wheel was used recently, so it should be retained.
+    uv_snapshot!(&filters, context.prune().arg("--older-than").arg("1w"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    // Any entry is older than zero seconds.
+    uv_snapshot!(&filters, context.prune().arg("--older-than").arg("0s"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    "###);
+
+    // Reinstalling should require re-downloading the wheel.
+    uv_snapshot!(&filters, context.pip_install().arg("-r").arg("requirements.txt").arg("--link-mode").arg("copy").arg("--reinstall"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    "###);
+
+    // Invalid durations should be rejected.
+    uv_snapshot!(&filters, context.prune().arg("--older-than").arg("30"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '30' for '--older-than <OLDER_THAN>': missing duration unit in `30`; expected one of `s`, `m`, `h`, `d`, or `w`
+
+    For more information, try '--help'.
+    "###);
+
+    Ok(())
+}
+
+/// `cache prune --max-size` should never evict unzipped wheels that are hardlinked into an
+/// existing environment.
+#[test]
+#[cfg(unix)]
+fn prune_max_size_linked() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    let filters: Vec<_> = std::iter::once((r"Removed \d+ files", "Removed [N] files"))
+        .chain(context.filters())
+        .chain([
+            // The cache entry does not have a stable key, so we filter it out
+            (
+                r"\[CACHE_DIR\](\\|\/)(.*?)(\\|\/).*",
+                "[CACHE_DIR]/$2/[ENTRY]",
+            ),
+        ])
+        .collect();
+
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("hardlink")
+        .assert()
+        .success();
+
+    // The wheel is hardlinked into the environment, so it should be retained.
+    uv_snapshot!(&filters, context.prune().arg("--max-size").arg("0").arg("--verbose"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    Pruning cache at: [CACHE_DIR]/
+    DEBUG Retaining referenced cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
+    No unused entries found
+    "###);
+
+    // Once the environment is removed, the wheel can be evicted.
+    fs_err::remove_dir_all(&context.venv)?;
+
+    uv_snapshot!(&filters, context.prune().arg("--max-size").arg("0").arg("--verbose"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    Pruning cache at: [CACHE_DIR]/
+    DEBUG Evicting cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
+    DEBUG Removing dangling cache symlink: [CACHE_DIR]/wheels-v3/[ENTRY]
+    Removed [N] files ([SIZE])
+    "###);
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::TestContext;
+
+/// `cache stats` should report the packages and unzipped wheels in the cache.
+#[test]
+fn cache_stats_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Add an unzipped wheel that isn't referenced by any cache entry.
+    context
+        .cache_dir
+        .child("archive-v0")
+        .child("dangling")
+        .child("file.txt")
+        .write_str("dangling")?;
+
+    let output = context.cache_stats().arg("--format").arg("json").output()?;
+    assert!(output.status.success());
+
+    let stats: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+
+    // The installed wheel is referenced, but the dangling archive is not.
+    assert_eq!(stats["archives"]["count"], 2);
+    assert_eq!(
+        stats["archives"]["unreferenced"],
+        serde_json::json!([{ "id": "dangling", "bytes": 8 }])
+    );
+
+    // The installed wheel, including the unzipped wheel referenced by its pointer, is attributed
+    // to its package.
+    assert_eq!(stats["packages"][0]["name"], "iniconfig");
+    let archive_bytes = stats["archives"]["bytes"].as_u64().unwrap() - 8;
+    assert!(stats["packages"][0]["bytes"].as_u64().unwrap() > archive_bytes);
+
+    // Every bucket is included in the total size.
+    let buckets = stats["buckets"].as_array().unwrap();
+    assert!(buckets.iter().any(|bucket| bucket["name"] == "archive-v0"));
+    let total = buckets
+        .iter()
+        .map(|bucket| bucket["bytes"].as_u64().unwrap())
+        .sum::<u64>();
+    assert!(stats["total_bytes"].as_u64().unwrap() >= total);
+
+    Ok(())
+}
@@ -672,6 +672,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python upgrade` command with options shared across scenarios.
+    pub fn python_upgrade(&self) -> Command {
+        let mut command = self.new_command();
+        let managed = self.temp_dir.join("managed");
+        let bin = self.temp_dir.join("bin");
+        self.add_shared_args(&mut command, true);
+        command
+            .arg("python")
+            .arg("upgrade")
+            .env(EnvVars::UV_PYTHON_INSTALL_DIR, managed)
+            .env(EnvVars::UV_PYTHON_BIN_DIR, bin)
+            .env(EnvVars::UV_TOOL_DIR, self.temp_dir.join("tools"))
+            .current_dir(&self.temp_dir);
+        command
+    }
+
     /// Create a `uv python uninstall` command with options shared across scenarios.
     pub fn python_uninstall(&self) -> Command {
         let mut command = self.new_command();
@@ -739,6 +755,15 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv tool sync` command with options shared across scenarios.
+    pub fn tool_sync(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("tool").arg("sync");
+        self.add_shared_args(&mut command, false);
+        command.env(EnvVars::UV_EXCLUDE_NEWER, EXCLUDE_NEWER);
+        command
+    }
+
     /// Create a `uv tool list` command with options shared across scenarios.
     pub fn tool_list(&self) -> Command {
         let mut command = self.new_command();
@@ -787,6 +812,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv audit` command with options shared across scenarios.
+    pub fn audit(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("audit");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv cache clean` command.
     pub fn clean(&self) -> Command {
         let mut command = self.new_command();
@@ -803,6 +836,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache stats` command.
+    pub fn cache_stats(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("stats");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
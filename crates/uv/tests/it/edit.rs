@@ -3548,6 +3548,189 @@ fn remove_registry() -> Result<()> {
     Ok(())
 }
 
+/// Remove the dependencies that are never imported by the project.
+#[test]
+fn remove_unused() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig==2.0.0"]
+    "#})?;
+
+    // `iniconfig` is only referenced in a comment, and via a relative import.
+    context.temp_dir.child("main.py").write_str(indoc! {r"
+        from anyio import run  # import iniconfig
+        from . import iniconfig
+    "})?;
+
+    // An environment is required to determine the modules provided by each dependency.
+    uv_snapshot!(context.filters(), context.remove().arg("--unused"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No project environment found at `.venv`; run `uv sync` to install the project's dependencies before removing unused dependencies
+    "###);
+
+    context.sync().assert().success();
+
+    uv_snapshot!(context.filters(), context.remove().arg("--unused").arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would remove 1 unused dependency:
+     - iniconfig
+    "###);
+
+    uv_snapshot!(context.filters(), context.remove().arg("--unused"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removing 1 unused dependency:
+     - iniconfig
+    Resolved 4 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+     - iniconfig==2.0.0
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio==3.7.0",
+        ]
+        "###
+        );
+    });
+
+    uv_snapshot!(context.filters(), context.remove().arg("--unused"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No unused dependencies found
+    "###);
+
+    Ok(())
+}
+
+/// Imports that span multiple lines, or that use a literal module name with `import_module`, are
+/// recognized as uses of a dependency.
+#[test]
+fn remove_unused_multiline_and_dynamic_imports() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "idna==3.6", "iniconfig==2.0.0", "sniffio==1.3.1"]
+    "#})?;
+
+    // `idna` is only referenced in a docstring.
+    context.temp_dir.child("main.py").write_str(indoc! {r#"
+        """Unlike `import idna`, which is never executed."""
+        import importlib
+        import anyio, \
+            iniconfig
+
+        sniffio = importlib.import_module(
+            "sniffio"
+        )
+    "#})?;
+
+    context.sync().assert().success();
+
+    uv_snapshot!(context.filters(), context.remove().arg("--unused").arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would remove 1 unused dependency:
+     - idna
+    "###);
+
+    Ok(())
+}
+
+/// If a module is imported by a computed name, the unused dependencies are reported, but not
+/// removed.
+#[test]
+fn remove_unused_uncertain_imports() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig==2.0.0"]
+    "#})?;
+
+    context.temp_dir.child("main.py").write_str(indoc! {r#"
+        import importlib
+        import anyio
+
+        name = "ini" + "config"
+        module = importlib.import_module(name)
+    "#})?;
+
+    context.sync().assert().success();
+
+    uv_snapshot!(context.filters(), context.remove().arg("--unused"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Unable to determine the imports of `main.py`; unused dependencies will not be removed
+    Found 1 possibly unused dependency:
+     ? iniconfig
+    "###);
+
+    // The dependency is retained.
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig==2.0.0"]
+        "###
+        );
+    });
+
+    Ok(())
+}
+
 #[test]
 fn add_preserves_indentation_in_pyproject_toml() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -1299,3 +1299,413 @@ fn export_group() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn pylock_toml() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("pylock.toml"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --format pylock.toml
+    lock-version = "1.0"
+    requires-python = ">=3.12"
+    created-by = "uv"
+
+    [[packages]]
+    name = "anyio"
+    version = "3.7.0"
+    index = "https://pypi.org/simple"
+
+    [packages.sdist]
+    name = "anyio-3.7.0.tar.gz"
+    url = "https://files.pythonhosted.org/packages/c6/b3/fefbf7e78ab3b805dec67d698dc18dd505af7a18a8dd08868c9b4fa736b5/anyio-3.7.0.tar.gz"
+    size = 142737
+    hashes = { sha256 = "275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce" }
+
+    [[packages.wheels]]
+    name = "anyio-3.7.0-py3-none-any.whl"
+    url = "https://files.pythonhosted.org/packages/68/fe/7ce1926952c8a403b35029e194555558514b365ad77d75125f521a2bec62/anyio-3.7.0-py3-none-any.whl"
+    size = 80873
+    hashes = { sha256 = "eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0" }
+
+    [[packages]]
+    name = "idna"
+    version = "3.6"
+    index = "https://pypi.org/simple"
+
+    [packages.sdist]
+    name = "idna-3.6.tar.gz"
+    url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz"
+    size = 175426
+    hashes = { sha256 = "9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca" }
+
+    [[packages.wheels]]
+    name = "idna-3.6-py3-none-any.whl"
+    url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl"
+    size = 61567
+    hashes = { sha256 = "c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f" }
+
+    [[packages]]
+    name = "project"
+
+    [packages.directory]
+    path = "."
+    editable = true
+
+    [[packages]]
+    name = "sniffio"
+    version = "1.3.1"
+    index = "https://pypi.org/simple"
+
+    [packages.sdist]
+    name = "sniffio-1.3.1.tar.gz"
+    url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz"
+    size = 20372
+    hashes = { sha256 = "f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc" }
+
+    [[packages.wheels]]
+    name = "sniffio-1.3.1-py3-none-any.whl"
+    url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl"
+    size = 10235
+    hashes = { sha256 = "2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2" }
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn cyclonedx() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain(
+            encoded_temp_dir_filters(&context)
+                .iter()
+                .map(|(pattern, replacement)| (pattern.as_str(), replacement.as_str())),
+        )
+        .collect::<Vec<_>>();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        license = "MIT"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(filters, context.export().arg("--format").arg("cyclonedx1.5"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "bomFormat": "CycloneDX",
+      "specVersion": "1.5",
+      "version": 1,
+      "metadata": {
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "name": "uv"
+            }
+          ]
+        }
+      },
+      "components": [
+        {
+          "type": "library",
+          "bom-ref": "pkg:pypi/anyio@3.7.0",
+          "name": "anyio",
+          "version": "3.7.0",
+          "purl": "pkg:pypi/anyio@3.7.0",
+          "externalReferences": [
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/c6/b3/fefbf7e78ab3b805dec67d698dc18dd505af7a18a8dd08868c9b4fa736b5/anyio-3.7.0.tar.gz",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce"
+                }
+              ]
+            },
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/68/fe/7ce1926952c8a403b35029e194555558514b365ad77d75125f521a2bec62/anyio-3.7.0-py3-none-any.whl",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "type": "library",
+          "bom-ref": "pkg:pypi/idna@3.6",
+          "name": "idna",
+          "version": "3.6",
+          "purl": "pkg:pypi/idna@3.6",
+          "externalReferences": [
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca"
+                }
+              ]
+            },
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "type": "library",
+          "bom-ref": "pkg:pypi/project@0.1.0?download_url=[TEMP_DIR_URL]",
+          "name": "project",
+          "version": "0.1.0",
+          "purl": "pkg:pypi/project@0.1.0?download_url=[TEMP_DIR_URL]",
+          "licenses": [
+            {
+              "expression": "MIT"
+            }
+          ]
+        },
+        {
+          "type": "library",
+          "bom-ref": "pkg:pypi/sniffio@1.3.1",
+          "name": "sniffio",
+          "version": "1.3.1",
+          "purl": "pkg:pypi/sniffio@1.3.1",
+          "externalReferences": [
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc"
+                }
+              ]
+            },
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2"
+                }
+              ]
+            }
+          ]
+        }
+      ],
+      "dependencies": [
+        {
+          "ref": "pkg:pypi/anyio@3.7.0",
+          "dependsOn": [
+            "pkg:pypi/idna@3.6",
+            "pkg:pypi/sniffio@1.3.1"
+          ]
+        },
+        {
+          "ref": "pkg:pypi/idna@3.6",
+          "dependsOn": []
+        },
+        {
+          "ref": "pkg:pypi/project@0.1.0?download_url=[TEMP_DIR_URL]",
+          "dependsOn": [
+            "pkg:pypi/anyio@3.7.0"
+          ]
+        },
+        {
+          "ref": "pkg:pypi/sniffio@1.3.1",
+          "dependsOn": []
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Local packages must not share a package URL with the index release of the same name and
+/// version.
+#[test]
+fn cyclonedx_path_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain(
+            encoded_temp_dir_filters(&context)
+                .iter()
+                .map(|(pattern, replacement)| (pattern.as_str(), replacement.as_str())),
+        )
+        .collect::<Vec<_>>();
+
+    // A local package with the same name and version as a release on PyPI.
+    let dependency = context.temp_dir.child("iniconfig");
+    dependency.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "iniconfig"
+        version = "2.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv.sources]
+        iniconfig = { path = "iniconfig" }
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(filters, context.export().arg("--format").arg("cyclonedx1.5"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "bomFormat": "CycloneDX",
+      "specVersion": "1.5",
+      "version": 1,
+      "metadata": {
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "name": "uv"
+            }
+          ]
+        }
+      },
+      "components": [
+        {
+          "type": "library",
+          "bom-ref": "pkg:pypi/iniconfig@2.0.0?download_url=[TEMP_DIR_URL]%2Finiconfig",
+          "name": "iniconfig",
+          "version": "2.0.0",
+          "purl": "pkg:pypi/iniconfig@2.0.0?download_url=[TEMP_DIR_URL]%2Finiconfig"
+        },
+        {
+          "type": "library",
+          "bom-ref": "pkg:pypi/project@0.1.0?download_url=[TEMP_DIR_URL]",
+          "name": "project",
+          "version": "0.1.0",
+          "purl": "pkg:pypi/project@0.1.0?download_url=[TEMP_DIR_URL]"
+        }
+      ],
+      "dependencies": [
+        {
+          "ref": "pkg:pypi/iniconfig@2.0.0?download_url=[TEMP_DIR_URL]%2Finiconfig",
+          "dependsOn": []
+        },
+        {
+          "ref": "pkg:pypi/project@0.1.0?download_url=[TEMP_DIR_URL]",
+          "dependsOn": [
+            "pkg:pypi/iniconfig@2.0.0?download_url=[TEMP_DIR_URL]%2Finiconfig"
+          ]
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Returns filters for the percent-encoded `file://` URL of the temporary directory, as it
+/// appears in package URLs.
+fn encoded_temp_dir_filters(context: &TestContext) -> Vec<(String, String)> {
+    [
+        context.temp_dir.to_path_buf(),
+        context.temp_dir.canonicalize().unwrap(),
+    ]
+    .into_iter()
+    .map(|path| {
+        let url = url::Url::from_file_path(path).unwrap();
+        let encoded = url
+            .as_str()
+            .bytes()
+            .map(|byte| {
+                if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                    char::from(byte).to_string()
+                } else {
+                    format!("%{byte:02X}")
+                }
+            })
+            .collect::<String>();
+        (regex::escape(&encoded), "[TEMP_DIR_URL]".to_string())
+    })
+    .collect()
+}
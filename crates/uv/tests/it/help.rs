@@ -22,12 +22,14 @@ fn help() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      audit                      Audit the project's lockfile for known vulnerabilities
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage authentication
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Display uv's version
@@ -103,12 +105,14 @@ fn help_flag() {
       lock     Update the project's lockfile
       export   Export the project's lockfile to an alternate format
       tree     Display the project's dependency tree
+      audit    Audit the project's lockfile for known vulnerabilities
       tool     Run and install commands provided by Python packages
       python   Manage Python versions and installations
       pip      Manage Python packages with a pip-compatible interface
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      auth     Manage authentication
       cache    Manage uv's cache
       self     Manage the uv executable
       version  Display uv's version
@@ -182,12 +186,14 @@ fn help_short_flag() {
       lock     Update the project's lockfile
       export   Export the project's lockfile to an alternate format
       tree     Display the project's dependency tree
+      audit    Audit the project's lockfile for known vulnerabilities
       tool     Run and install commands provided by Python packages
       python   Manage Python versions and installations
       pip      Manage Python packages with a pip-compatible interface
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      auth     Manage authentication
       cache    Manage uv's cache
       self     Manage the uv executable
       version  Display uv's version
@@ -293,6 +299,7 @@ fn help_subcommand() {
     Commands:
       list       List the available Python installations
       install    Download and install Python versions
+      upgrade    Upgrade installed Python versions to the latest patch version
       find       Search for a Python installation
       pin        Pin to a specific Python version
       dir        Show the uv Python installation directory
@@ -656,6 +663,7 @@ fn help_flag_subcommand() {
     Commands:
       list       List the available Python installations
       install    Download and install Python versions
+      upgrade    Upgrade installed Python versions to the latest patch version
       find       Search for a Python installation
       pin        Pin to a specific Python version
       dir        Show the uv Python installation directory
@@ -791,6 +799,7 @@ fn help_unknown_subcommand() {
         lock
         export
         tree
+        audit
         tool
         python
         pip
@@ -818,6 +827,7 @@ fn help_unknown_subcommand() {
         lock
         export
         tree
+        audit
         tool
         python
         pip
@@ -872,12 +882,14 @@ fn help_with_global_option() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      audit                      Audit the project's lockfile for known vulnerabilities
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage authentication
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Display uv's version
@@ -988,12 +1000,14 @@ fn help_with_no_pager() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      audit                      Audit the project's lockfile for known vulnerabilities
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage authentication
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Display uv's version
@@ -1369,6 +1369,183 @@ fn lock_project_extra() -> Result<()> {
     Ok(())
 }
 
+/// Lock a project with conflicting extras. Without declaring the conflict, resolution should fail;
+/// once declared, each extra should be resolved separately.
+#[test]
+fn lock_conflicting_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.optional-dependencies]
+        project1 = ["iniconfig==1.1.1"]
+        project2 = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because project[project2] depends on iniconfig==2.0.0 and project[project1] depends on iniconfig==1.1.1, we can conclude that project[project1] and project[project2] are incompatible.
+          And because your project requires project[project1] and project[project2], we can conclude that your project's requirements are unsatisfiable.
+    "###);
+
+    // Declare the conflict.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.optional-dependencies]
+        project1 = ["iniconfig==1.1.1"]
+        project2 = ["iniconfig==2.0.0"]
+
+        [tool.uv]
+        conflicts = [
+            [
+              { extra = "project1" },
+              { extra = "project2" },
+            ],
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+        conflicts = [[
+            { package = "project", extra = "project1" },
+            { package = "project", extra = "project2" },
+        ]]
+        resolution-markers = [
+            "extra != 'extra-7-project-project1' and extra == 'extra-7-project-project2'",
+            "extra != 'extra-7-project-project2'",
+        ]
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "1.1.1"
+        source = { registry = "https://pypi.org/simple" }
+        resolution-markers = [
+            "extra != 'extra-7-project-project2'",
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/23/a2/97899f6bd0e873fed3a7e67ae8d3a08b21799430fb4da15cfedf10d6e2c2/iniconfig-1.1.1.tar.gz", hash = "sha256:bc3af051d7d14b2ee5ef9969666def0cd1a000e121eaea580d4a313df4b37f32", size = 8104 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/9b/dd/b3c12c6d707058fa947864b67f0c4e0c39ef8610988d7baea9578f3c48f3/iniconfig-1.1.1-py2.py3-none-any.whl", hash = "sha256:011e24c64b7f47f6ebd835bb12a743f2fbe9a26d4cecaa7f53bc4f35ee9da8b3", size = 4990 },
+        ]
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        resolution-markers = [
+            "extra != 'extra-7-project-project1' and extra == 'extra-7-project-project2'",
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+
+        [package.optional-dependencies]
+        project1 = [
+            { name = "iniconfig", version = "1.1.1", source = { registry = "https://pypi.org/simple" }, marker = "extra != 'extra-7-project-project2'" },
+        ]
+        project2 = [
+            { name = "iniconfig", version = "2.0.0", source = { registry = "https://pypi.org/simple" }, marker = "extra != 'extra-7-project-project1' and extra == 'extra-7-project-project2'" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "iniconfig", marker = "extra == 'project1'", specifier = "==1.1.1" },
+            { name = "iniconfig", marker = "extra == 'project2'", specifier = "==2.0.0" },
+        ]
+        "###
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    // Install each extra from the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--extra").arg("project1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.1.1
+    "###);
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--extra").arg("project2"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==1.1.1
+     + iniconfig==2.0.0
+    "###);
+
+    // Requesting both extras should fail.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--extra").arg("project1").arg("--extra").arg("project2"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Extras `project1` and `project2` are incompatible with the declared conflicts: {`project[project1]`, `project[project2]`}
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn lock_project_with_overrides() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -7936,6 +8113,258 @@ fn lock_find_links_local_wheel() -> Result<()> {
     Ok(())
 }
 
+/// Lock with a flat index defined via `[[tool.uv.index]]`, pinned via `tool.uv.sources`.
+#[test]
+fn lock_flat_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Populate the flat index.
+    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+
+    for entry in fs_err::read_dir(context.workspace_root.join("scripts/links"))? {
+        let entry = entry?;
+        let path = entry.path();
+        if path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .is_some_and(|file_name| file_name.starts_with("tqdm-"))
+        {
+            let dest = context
+                .temp_dir
+                .join("links")
+                .join(path.file_name().unwrap());
+            fs_err::copy(&path, &dest)?;
+        }
+    }
+
+    let workspace = context.temp_dir.child("workspace");
+
+    let pyproject_toml = workspace.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["tqdm==1000.0.0"]
+
+        [tool.uv.sources]
+        tqdm = {{ index = "links" }}
+
+        [[tool.uv.index]]
+        name = "links"
+        url = "{}"
+        format = "flat"
+        explicit = true
+        "#,
+        context.temp_dir.join("links/").portable_display(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 2 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(workspace.join("uv.lock")).unwrap();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "tqdm" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "tqdm", specifier = "==1000.0.0", index = "file://[TEMP_DIR]/links/" }]
+
+        [[package]]
+        name = "tqdm"
+        version = "1000.0.0"
+        source = { registry = "../links" }
+        wheels = [
+            { path = "tqdm-1000.0.0-py3-none-any.whl" },
+        ]
+        "###
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // Install from the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    "###);
+
+    Ok(())
+}
+
+/// Lock with `required-environments`, which should skip versions that lack a compatible wheel for
+/// any of the required environments.
+#[test]
+fn lock_required_environments() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Populate the `--find-links` entries. `maturin==2.0.0` only includes a `linux_x86_64` wheel,
+    // while `maturin==1.4.0` includes a pure-Python wheel.
+    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+
+    for entry in fs_err::read_dir(context.workspace_root.join("scripts/links"))? {
+        let entry = entry?;
+        let path = entry.path();
+        if path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .is_some_and(|file_name| file_name.starts_with("maturin-"))
+        {
+            let dest = context
+                .temp_dir
+                .join("links")
+                .join(path.file_name().unwrap());
+            fs_err::copy(&path, &dest)?;
+        }
+    }
+
+    let workspace = context.temp_dir.child("workspace");
+
+    let pyproject_toml = workspace.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["maturin"]
+
+        [tool.uv]
+        no-index = true
+        find-links = ["{}"]
+        required-environments = ["sys_platform == 'linux' and platform_machine == 'aarch64'"]
+        "#,
+        context.temp_dir.join("links/").portable_display(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 2 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(workspace.join("uv.lock")).unwrap();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+        required-markers = [
+            "platform_machine == 'aarch64' and sys_platform == 'linux'",
+        ]
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "maturin"
+        version = "1.4.0"
+        source = { registry = "../links" }
+        wheels = [
+            { path = "maturin-1.4.0-py3-none-any.whl" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "maturin" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "maturin" }]
+        "###
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").current_dir(&workspace), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // Require a version that lacks a compatible wheel.
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["maturin==2.0.0"]
+
+        [tool.uv]
+        no-index = true
+        find-links = ["{}"]
+        required-environments = ["sys_platform == 'linux' and platform_machine == 'aarch64'"]
+        "#,
+        context.temp_dir.join("links/").portable_display(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+      × No solution found when resolving dependencies:
+      ╰─▶ Because maturin==2.0.0 has no wheels for the required environment `platform_machine == 'aarch64' and sys_platform == 'linux'` and your project depends on maturin==2.0.0, we can conclude that your project's requirements are unsatisfiable.
+    "###);
+
+    Ok(())
+}
+
 /// Lock a local source distribution via `--find-links`.
 #[test]
 fn lock_find_links_local_sdist() -> Result<()> {
@@ -17081,6 +17510,93 @@ fn lock_dry_run_noop() -> Result<()> {
     Ok(())
 }
 
+/// Display the changes to the lockfile with `--diff`.
+#[test]
+fn lock_diff() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==4.3.0", "iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--diff"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    + iniconfig v2.0.0 (registry+https://pypi.org/simple)
+    ~ anyio v3.7.0 -> v4.3.0
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Updated anyio v3.7.0 -> v4.3.0
+    Added iniconfig v2.0.0
+    "###);
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==4.3.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--dry-run").arg("--diff").arg("--diff-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "added": [],
+      "removed": [
+        {
+          "name": "iniconfig",
+          "version": "2.0.0",
+          "source": "registry+https://pypi.org/simple"
+        }
+      ],
+      "changed": []
+    }
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Remove iniconfig v2.0.0
+    "###);
+
+    context.lock().assert().success();
+
+    // Without changes, the report is empty.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--diff"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn lock_group_include() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -3,6 +3,11 @@
 
 pub(crate) mod common;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod audit;
+
+mod auth;
+
 mod branching_urls;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -17,6 +22,9 @@ mod cache_clean;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_stats;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod ecosystem;
 
@@ -75,6 +83,9 @@ mod python_install;
 #[cfg(feature = "python")]
 mod python_pin;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod remote_cache;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod run;
 
@@ -99,6 +110,9 @@ mod tool_list;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_run;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod tool_sync;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_uninstall;
 
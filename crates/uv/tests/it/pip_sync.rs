@@ -5665,3 +5665,170 @@ fn semicolon_no_space() -> Result<()> {
 
     Ok(())
 }
+
+/// Install the locked packages from a `uv.lock`, without a resolution.
+#[test]
+fn sync_uv_lock() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [dependency-groups]
+        test = ["iniconfig"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Install from the lockfile alone, omitting the project itself.
+    fs::remove_file(&pyproject_toml)?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("uv.lock")
+        .arg("--no-install-project")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    // Include a dependency group.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("uv.lock")
+        .arg("--no-install-project")
+        .arg("--group")
+        .arg("test"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Dependency groups require a lockfile.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--group")
+        .arg("test"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Requesting dependency groups requires a `uv.lock` file
+    "###
+    );
+
+    // The lockfile's `requires-python` must include the target interpreter.
+    let lock = context.read("uv.lock");
+    context.temp_dir.child("uv.lock").write_str(&lock.replace(
+        r#"requires-python = ">=3.12""#,
+        r#"requires-python = ">=3.13""#,
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("uv.lock")
+        .arg("--no-install-project"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The current Python version (3.12.[X]) is not compatible with the locked Python requirement: `>=3.13`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Install the locked packages from a `uv.lock`, including an extra of the project.
+#[test]
+fn sync_uv_lock_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [project.optional-dependencies]
+        test = ["iniconfig"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Install from the lockfile alone, omitting the project itself.
+    fs::remove_file(&pyproject_toml)?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("uv.lock")
+        .arg("--no-install-project")
+        .arg("--extra")
+        .arg("test"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+    "###
+    );
+
+    // Extras require a lockfile.
+    context
+        .temp_dir
+        .child("requirements.txt")
+        .write_str("iniconfig")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--extra")
+        .arg("test"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Requesting extras requires a `uv.lock` file
+    "###
+    );
+
+    Ok(())
+}
@@ -1,3 +1,6 @@
+use assert_fs::prelude::*;
+use indoc::indoc;
+
 use crate::common::{uv_snapshot, TestContext};
 use uv_static::EnvVars;
 
@@ -162,3 +165,57 @@ fn skip_existing_redirect() {
     "###
     );
 }
+
+/// Publish to an index by name, which must be defined with a `publish-url`.
+#[test]
+fn publish_index() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "https://example.com/simple"
+
+        [[tool.uv.index]]
+        name = "testpypi"
+        url = "https://test.pypi.org/simple/"
+        publish-url = "https://test.pypi.org/legacy/"
+    "#})?;
+
+    // An index that doesn't exist.
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--index")
+        .arg("missing")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    error: Index not found: `missing`. Found indexes: `internal`, `testpypi`
+    "###
+    );
+
+    // An index without a publish URL.
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--index")
+        .arg("internal")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    error: Index is missing a publish URL: `internal`
+    "###
+    );
+
+    Ok(())
+}
@@ -1,11 +1,13 @@
 use std::{path::Path, process::Command};
 
+use assert_cmd::assert::OutputAssertExt;
 use assert_fs::{
     assert::PathAssert,
-    prelude::{FileTouch, PathChild},
+    prelude::{FileTouch, FileWriteStr, PathChild},
 };
 use predicates::prelude::predicate;
 use uv_fs::Simplified;
+use uv_static::EnvVars;
 
 use crate::common::{uv_snapshot, TestContext};
 
@@ -324,6 +326,95 @@ fn python_install_preview_upgrade() {
     });
 }
 
+#[test]
+fn python_upgrade() {
+    let context = TestContext::new_with_versions(&[]).with_filtered_python_keys();
+
+    let bin_python = context
+        .temp_dir
+        .child("bin")
+        .child(format!("python3.12{}", std::env::consts::EXE_SUFFIX));
+
+    // Install 3.12.6
+    uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("3.12.6"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.6 in [TIME]
+     + cpython-3.12.6-[PLATFORM]
+    "###);
+
+    // Create a virtual environment from 3.12.6
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12.6")
+        .env(
+            EnvVars::UV_PYTHON_INSTALL_DIR,
+            context.temp_dir.join("managed"),
+        )
+        .assert()
+        .success();
+
+    // Upgrading a version that isn't installed should fail
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("3.11"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    No existing installations found for: Python 3.11
+    No Python installations found
+    "###);
+
+    // Upgrade to the latest patch version, updating the executable and the virtual environment
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("3.12").arg("--remove-old"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Upgraded Python 3.12.6 to 3.12.7 in [TIME]
+     + cpython-3.12.7-[PLATFORM]
+     - cpython-3.12.6-[PLATFORM]
+    Updated environment at `.venv` to use Python 3.12.7
+    "###);
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        insta::assert_snapshot!(
+            read_link_path(&bin_python), @"[TEMP_DIR]/managed/cpython-3.12.7-[PLATFORM]"
+        );
+    });
+
+    context
+        .venv
+        .child("pyvenv.cfg")
+        .assert(predicate::str::contains("version_info = 3.12.7"));
+
+    // The previous patch version should be removed
+    assert!(!fs_err::read_dir(context.temp_dir.child("managed"))
+        .unwrap()
+        .any(|entry| entry
+            .unwrap()
+            .file_name()
+            .to_string_lossy()
+            .starts_with("cpython-3.12.6")));
+
+    // Upgrading again should be a no-op
+    uv_snapshot!(context.filters(), context.python_upgrade(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    All installed versions are up-to-date
+    "###);
+}
+
 #[test]
 fn python_install_freethreaded() {
     let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_python_keys();
@@ -431,6 +522,106 @@ fn python_install_invalid_request() {
     "###);
 }
 
+#[test]
+fn python_install_custom_downloads() -> anyhow::Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let downloads_json = context.temp_dir.child("downloads.json");
+    downloads_json.write_str(indoc::indoc! {r#"
+        {
+          "cpython-3.12.99-linux-x86_64-gnu": {
+            "name": "cpython",
+            "arch": "x86_64",
+            "os": "linux",
+            "libc": "gnu",
+            "major": 3,
+            "minor": 12,
+            "patch": 99,
+            "prerelease": "",
+            "url": "https://python.example.com/cpython-3.12.99-x86_64-unknown-linux-gnu-install_only.tar.gz",
+            "sha256": "0000000000000000000000000000000000000000000000000000000000000000",
+            "variant": null
+          },
+          "cpython-3.13.99-darwin-aarch64-none": {
+            "name": "cpython",
+            "arch": "aarch64",
+            "os": "darwin",
+            "libc": "none",
+            "major": 3,
+            "minor": 13,
+            "patch": 99,
+            "prerelease": "",
+            "url": "https://python.example.com/cpython-3.13.99-aarch64-apple-darwin-install_only.tar.gz",
+            "sha256": null,
+            "variant": null
+          }
+        }
+    "#})?;
+
+    // The custom downloads should replace the built-in downloads.
+    uv_snapshot!(context.filters(), context.command()
+        .arg("python")
+        .arg("list")
+        .arg("--all-platforms")
+        .env(EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL, downloads_json.as_os_str())
+        .env(EnvVars::UV_PYTHON_DOWNLOADS_JSON_REPLACE, "1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.13.99-macos-aarch64-none    <download available>
+    cpython-3.12.99-linux-x86_64-gnu      <download available>
+
+    ----- stderr -----
+    "###);
+
+    // Invalid downloads should be rejected.
+    downloads_json.write_str(indoc::indoc! {r#"
+        {
+          "jython-3.12.99-linux-x86_64-gnu": {
+            "name": "jython",
+            "arch": "x86_64",
+            "os": "linux",
+            "libc": "gnu",
+            "major": 3,
+            "minor": 12,
+            "patch": 99,
+            "prerelease": "",
+            "url": "https://python.example.com/jython-3.12.99.tar.gz",
+            "sha256": null,
+            "variant": null
+          }
+        }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.command()
+        .arg("python")
+        .arg("list")
+        .arg("--all-platforms")
+        .env(EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL, downloads_json.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid Python download `jython-3.12.99-linux-x86_64-gnu`: invalid implementation: Unknown Python implementation `jython`
+    "###);
+
+    // Commands that don't query the downloads should not load them.
+    let tool_dir = context.temp_dir.child("tools");
+    uv_snapshot!(context.filters(), context.tool_list()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL, downloads_json.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No tools installed
+    "###);
+
+    Ok(())
+}
+
 fn read_link_path(path: &Path) -> String {
     if cfg!(unix) {
         path.read_link()
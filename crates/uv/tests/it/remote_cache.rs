@@ -0,0 +1,144 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Wheels built from source distributions should be uploaded to the remote cache, and reused by
+/// subsequent installs with an empty local cache.
+#[tokio::test]
+async fn remote_cache_round_trip() -> Result<()> {
+    // Start with an empty remote cache.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let context = TestContext::new("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("source-distribution==0.0.1")?;
+
+    // The source distribution is built locally, then uploaded.
+    uv_snapshot!(context.pip_sync()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .env(EnvVars::UV_REMOTE_CACHE_URL, server.uri())
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + source-distribution==0.0.1
+    "###
+    );
+
+    // The entry contains the wheel filename and digest, followed by the wheel itself.
+    let requests = server.received_requests().await.unwrap();
+    let upload = requests
+        .iter()
+        .find(|request| request.method.as_str() == "PUT")
+        .unwrap();
+    assert!(upload.body.starts_with(b"source_distribution-0.0.1-"));
+    server.verify().await;
+
+    // Serve the uploaded entry from a fresh remote cache.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(upload.url.path()))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(upload.body.clone()))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    // With an empty local cache, the wheel is fetched from the remote cache instead of being
+    // built (and so isn't uploaded again).
+    let context = TestContext::new("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("source-distribution==0.0.1")?;
+
+    uv_snapshot!(context.pip_sync()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .env(EnvVars::UV_REMOTE_CACHE_URL, server.uri())
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + source-distribution==0.0.1
+    "###
+    );
+
+    context
+        .assert_command("import source_distribution")
+        .success();
+
+    server.verify().await;
+
+    // Serve a corrupted copy of the entry, which should fail digest verification.
+    let mut corrupted = upload.body.clone();
+    *corrupted.last_mut().unwrap() ^= 0xff;
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(upload.url.path()))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(corrupted))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // The corrupted entry is discarded, so the wheel is built locally (and uploaded again).
+    let context = TestContext::new("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("source-distribution==0.0.1")?;
+
+    uv_snapshot!(context.pip_sync()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .env(EnvVars::UV_REMOTE_CACHE_URL, server.uri())
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + source-distribution==0.0.1
+    "###
+    );
+
+    context
+        .assert_command("import source_distribution")
+        .success();
+
+    server.verify().await;
+
+    Ok(())
+}
@@ -66,6 +66,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -116,6 +117,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -213,6 +217,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -263,6 +268,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -361,6 +369,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -411,6 +420,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -541,6 +553,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -591,6 +604,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -690,6 +706,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -819,6 +836,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -869,6 +887,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -991,6 +1012,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1041,6 +1063,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                     Index {
@@ -1069,6 +1094,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -1168,6 +1196,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1218,6 +1247,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: Some(
                             Cli,
                         ),
@@ -1248,6 +1280,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                     Index {
@@ -1276,6 +1311,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -1398,6 +1436,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1449,6 +1488,9 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        format: Flat,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -1569,6 +1611,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1704,6 +1747,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1754,6 +1798,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                     Index {
@@ -1782,6 +1829,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -1879,6 +1929,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1929,6 +1980,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                     Index {
@@ -1957,6 +2011,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -2078,6 +2135,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2203,6 +2261,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2328,6 +2387,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2455,6 +2515,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2601,6 +2662,7 @@ fn resolve_tool() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -2686,6 +2748,7 @@ fn resolve_tool() -> anyhow::Result<()> {
             },
         },
         force: false,
+        locked: None,
         editable: false,
     }
 
@@ -2758,6 +2821,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2911,6 +2975,7 @@ fn resolve_both() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2961,6 +3026,9 @@ fn resolve_both() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -3085,6 +3153,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3135,6 +3204,9 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -3229,7 +3301,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `dependency-metadata`, `config-settings`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `publish-url`, `trusted-publishing`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `environments`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dev-dependencies`
+    unknown field `project`, expected one of `native-tls`, `offline`, `no-cache`, `cache-dir`, `remote-cache-url`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `dependency-metadata`, `config-settings`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `publish-url`, `trusted-publishing`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `environments`, `workspace`, `sources`, `managed`, `package`, `conflicts`, `required-environments`, `default-groups`, `dev-dependencies`, `build-backend`
     "###
     );
 
@@ -3333,6 +3405,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3461,6 +3534,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3608,6 +3682,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3747,6 +3822,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3797,6 +3873,9 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: Some(
                             Cli,
                         ),
@@ -3827,6 +3906,9 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -3924,6 +4006,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3974,6 +4057,9 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4004,6 +4090,9 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -4107,6 +4196,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -4157,6 +4247,9 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4187,6 +4280,9 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -4285,6 +4381,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -4335,6 +4432,9 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4365,6 +4465,9 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -4470,6 +4573,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -4520,6 +4624,9 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4550,6 +4657,9 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -4648,6 +4758,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache_url: None,
     }
     PipCompileSettings {
         src_file: [
@@ -4698,6 +4809,9 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4728,6 +4842,9 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        format: Simple,
+                        authenticate: Auto,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -365,6 +365,107 @@ fn tool_install_version() {
     "###);
 }
 
+/// Test installing a tool from the lockfile of an existing installation.
+#[test]
+fn tool_install_locked() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Prepared 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    // We should have a lockfile alongside the receipt.
+    let lock = tool_dir.child("black").child("uv.lock");
+    lock.assert(predicate::str::contains(indoc! {r#"
+        [[package]]
+        name = "black"
+        version = "24.3.0"
+    "#}));
+
+    // Install `black` into another tool directory from the lockfile, even though newer versions
+    // are available. The locked wheels are re-fetched, since the cached wheels weren't hashed.
+    let other_tool_dir = context.temp_dir.child("other-tools");
+    let other_bin_dir = context.temp_dir.child("other-bin");
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .arg("--locked")
+        .arg(lock.as_os_str())
+        .env(EnvVars::UV_EXCLUDE_NEWER, "2024-08-01T00:00:00Z")
+        .env(EnvVars::UV_TOOL_DIR, other_tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, other_bin_dir.as_os_str())
+        .env(EnvVars::PATH, other_bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Prepared 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     + black==24.3.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    other_tool_dir
+        .child("black")
+        .child("uv.lock")
+        .assert(predicate::path::exists());
+
+    // Requesting a version that isn't in the lockfile should fail.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black==24.2.0")
+        .arg("--locked")
+        .arg(lock.as_os_str())
+        .env(EnvVars::UV_TOOL_DIR, other_tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, other_bin_dir.as_os_str())
+        .env(EnvVars::PATH, other_bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    error: The lockfile at `tools/black/uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv tool install` without `--locked`, and copy the resulting `uv.lock` from the tool directory.
+    "###);
+
+    // The existing installation should be unchanged.
+    uv_snapshot!(context.filters(), Command::new("black").arg("--version").env(EnvVars::PATH, other_bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.3.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    "###);
+}
+
 /// Test an editable installation of a tool.
 #[test]
 fn tool_install_editable() {
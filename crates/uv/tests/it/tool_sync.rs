@@ -0,0 +1,157 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::{FileWriteStr, PathChild};
+use indoc::indoc;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+fn tool_sync() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `flask`, which isn't declared in the manifest.
+    context
+        .tool_install()
+        .arg("flask")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    let manifest = context.temp_dir.child("tools.toml");
+    manifest.write_str(indoc! {r#"
+        [[tool]]
+        name = "black"
+        version = "==24.2.0"
+    "#})?;
+
+    // The declared tool should be installed.
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("tools.toml")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Prepared 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     + black==24.2.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    // Syncing again should be a no-op.
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("tools.toml")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    `black==24.2.0` is already installed
+    "###);
+
+    // Changing the version specifiers should upgrade the tool.
+    manifest.write_str(indoc! {r#"
+        [[tool]]
+        name = "black"
+        version = ">=24.3"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("tools.toml")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - black==24.2.0
+     + black==24.3.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    // With `--exact`, undeclared tools should be uninstalled.
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("tools.toml")
+        .arg("--exact")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    `black>=24.3` is already installed
+    Uninstalled 1 executable: flask
+    "###);
+
+    uv_snapshot!(context.filters(), context.tool_list()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black v24.3.0
+    - black
+    - blackd
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn tool_sync_duplicate() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let manifest = context.temp_dir.child("tools.toml");
+    manifest.write_str(indoc! {r#"
+        [[tool]]
+        name = "black"
+
+        [[tool]]
+        name = "black"
+        version = "==24.2.0"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("tools.toml")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Tool `black` is declared multiple times in the tool manifest at tools.toml
+    "###);
+
+    Ok(())
+}
@@ -270,6 +270,140 @@ fn outdated() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.0.0"]
+
+        [project.optional-dependencies]
+        async = ["sniffio; sys_platform == 'linux'"]
+    "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.tree().arg("--format").arg("json").arg("--outdated").arg("--universal"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "roots": [
+        2
+      ],
+      "nodes": [
+        {
+          "name": "anyio",
+          "version": "3.0.0",
+          "source": "registry+https://pypi.org/simple",
+          "latest": "4.3.0"
+        },
+        {
+          "name": "idna",
+          "version": "3.6",
+          "source": "registry+https://pypi.org/simple"
+        },
+        {
+          "name": "project",
+          "version": "0.1.0",
+          "source": "virtual+."
+        },
+        {
+          "name": "sniffio",
+          "version": "1.3.1",
+          "source": "registry+https://pypi.org/simple"
+        }
+      ],
+      "edges": [
+        {
+          "from": 0,
+          "to": 1
+        },
+        {
+          "from": 0,
+          "to": 3
+        },
+        {
+          "from": 2,
+          "to": 0
+        },
+        {
+          "from": 2,
+          "to": 3,
+          "extra": "async",
+          "marker": "sys_platform == 'linux'"
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    // Limit the depth, and invert the graph.
+    uv_snapshot!(context.filters(), context.tree().arg("--format").arg("json").arg("--invert").arg("--depth").arg("1").arg("--universal"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "roots": [
+        1,
+        3
+      ],
+      "nodes": [
+        {
+          "name": "anyio",
+          "version": "3.0.0",
+          "source": "registry+https://pypi.org/simple"
+        },
+        {
+          "name": "idna",
+          "version": "3.6",
+          "source": "registry+https://pypi.org/simple"
+        },
+        {
+          "name": "project",
+          "version": "0.1.0",
+          "source": "virtual+."
+        },
+        {
+          "name": "sniffio",
+          "version": "1.3.1",
+          "source": "registry+https://pypi.org/simple"
+        }
+      ],
+      "edges": [
+        {
+          "from": 1,
+          "to": 0
+        },
+        {
+          "from": 3,
+          "to": 0
+        },
+        {
+          "from": 3,
+          "to": 2,
+          "extra": "async",
+          "marker": "sys_platform == 'linux'"
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn platform_dependencies() -> Result<()> {
     let context = TestContext::new("3.12");
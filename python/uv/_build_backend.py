@@ -25,14 +25,18 @@ def warn_config_settings(config_settings: "dict | None" = None):
 
 
 def call(args: "list[str]", config_settings: "dict | None" = None) -> str:
-    """Invoke a uv subprocess and return the filename from stdout."""
+    """Invoke a uv subprocess and return the last line of stdout.
+
+    This is the filename for the build and metadata hooks, and a JSON list of
+    requirements for the `get_requires_for_build_*` hooks.
+    """
     import subprocess
     import sys
 
     from ._find_uv import find_uv_bin
 
     warn_config_settings(config_settings)
-    # Forward stderr, capture stdout for the filename
+    # Forward stderr, capture stdout for the result
     result = subprocess.run([find_uv_bin()] + args, stdout=subprocess.PIPE)
     if result.returncode != 0:
         sys.exit(result.returncode)
@@ -41,7 +45,7 @@ def call(args: "list[str]", config_settings: "dict | None" = None) -> str:
     sys.stdout.writelines(stdout[:-1])
     # Fail explicitly instead of an irrelevant stacktrace
     if not stdout:
-        print("uv subprocess did not return a filename on stdout", file=sys.stderr)
+        print("uv subprocess did not return a result on stdout", file=sys.stderr)
         sys.exit(1)
     return stdout[-1].strip()
 
@@ -66,14 +70,18 @@ def build_wheel(
 
 def get_requires_for_build_sdist(config_settings: "dict | None" = None):
     """PEP 517 hook `get_requires_for_build_sdist`."""
-    warn_config_settings(config_settings)
-    return []
+    import json
+
+    args = ["build-backend", "get-requires-for-build-sdist"]
+    return json.loads(call(args, config_settings))
 
 
 def get_requires_for_build_wheel(config_settings: "dict | None" = None):
     """PEP 517 hook `get_requires_for_build_wheel`."""
-    warn_config_settings(config_settings)
-    return []
+    import json
+
+    args = ["build-backend", "get-requires-for-build-wheel"]
+    return json.loads(call(args, config_settings))
 
 
 def prepare_metadata_for_build_wheel(
@@ -98,8 +106,10 @@ def build_editable(
 
 def get_requires_for_build_editable(config_settings: "dict | None" = None):
     """PEP 660 hook `get_requires_for_build_editable`."""
-    warn_config_settings(config_settings)
-    return []
+    import json
+
+    args = ["build-backend", "get-requires-for-build-editable"]
+    return json.loads(call(args, config_settings))
 
 
 def prepare_metadata_for_build_editable(